@@ -3,6 +3,10 @@ use anyhow::Result;
 use tracing::{debug, info};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use sqlite3_parser::ast::{Cmd, Stmt, CreateTableBody, ColumnConstraint, TableConstraint};
+use sqlite3_parser::lexer::sql::Parser as SqlParser;
+
+use super::pg_constraint::PgConstraintHandler;
 
 // Pre-compiled regex patterns for constraint parsing
 static PK_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -33,18 +37,35 @@ static DEFAULT_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"(?i)\b(\w+)\s+[^,\)]*\bDEFAULT\s+([^,\)]+)").unwrap()
 });
 
+// Trailing `ON DELETE/UPDATE <action>` and `MATCH <type>` clauses, in any
+// order, following a `REFERENCES table(cols)` - captured as one group so
+// `parse_fk_actions` can pull whichever of them are actually present.
+const FK_ACTION_TAIL: &str = r"((?:\s*(?:ON\s+(?:DELETE|UPDATE)\s+(?:CASCADE|SET\s+NULL|SET\s+DEFAULT|RESTRICT|NO\s+ACTION)|MATCH\s+(?:FULL|PARTIAL|SIMPLE)))*)";
+
 static FOREIGN_KEY_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?i)FOREIGN\s+KEY\s*\(\s*([^)]+)\s*\)\s+REFERENCES\s+(\w+)\s*\(\s*([^)]+)\s*\)").unwrap()
+    Regex::new(&format!(r"(?i)FOREIGN\s+KEY\s*\(\s*([^)]+)\s*\)\s+REFERENCES\s+(\w+)\s*\(\s*([^)]+)\s*\){FK_ACTION_TAIL}")).unwrap()
 });
 
 static INLINE_FOREIGN_KEY_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?i)\b(\w+)\s+[^,\)]*\bREFERENCES\s+(\w+)\s*\(\s*([^)]+)\s*\)").unwrap()
+    Regex::new(&format!(r"(?i)\b(\w+)\s+[^,\)]*\bREFERENCES\s+(\w+)\s*\(\s*([^)]+)\s*\){FK_ACTION_TAIL}")).unwrap()
 });
 
 static TABLE_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"(?i)CREATE\s+TABLE\s+[^(]+\(\s*(.+)\s*\)").unwrap()
 });
 
+// Matches a bare `REFERENCES table(cols) [actions]` clause on its own, with
+// no leading `FOREIGN KEY (cols)` - used to strip just the trailing
+// reference off an inline column definition (`col INTEGER REFERENCES
+// parent(id)`) while leaving the column's own type/NOT NULL/DEFAULT intact.
+static BARE_REFERENCES_CLAUSE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(r"(?i)REFERENCES\s+(\w+)\s*\(\s*[^)]+\s*\){FK_ACTION_TAIL}")).unwrap()
+});
+
+static CREATE_TABLE_NAME_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)^(CREATE\s+TABLE\s+(?:IF\s+NOT\s+EXISTS\s+)?)"?(\w+)"?"#).unwrap()
+});
+
 /// Populate PostgreSQL catalog tables with constraint information for a newly created table
 pub fn populate_constraints_for_table(conn: &Connection, table_name: &str) -> Result<()> {
     eprintln!("üéØ populate_constraints_for_table called for: {}", table_name);
@@ -55,7 +76,7 @@ pub fn populate_constraints_for_table(conn: &Connection, table_name: &str) -> Re
     debug!("CREATE TABLE SQL: {}", create_sql);
     
     // Generate table OID (consistent with pg_class view)
-    let table_oid = generate_table_oid(table_name);
+    let table_oid = generate_table_oid(conn, table_name)?;
 
     // Parse and populate constraints
     populate_table_constraints(conn, table_name, &create_sql, &table_oid)?;
@@ -63,16 +84,68 @@ pub fn populate_constraints_for_table(conn: &Connection, table_name: &str) -> Re
     // Parse and populate column defaults
     populate_column_defaults(conn, table_name, &create_sql, &table_oid)?;
 
+    // Record any GENERATED ALWAYS AS (...) columns for pg_attribute.attgenerated
+    populate_generated_columns(conn, table_name, &create_sql)?;
+
     // Populate indexes (including those created by UNIQUE constraints)
     populate_table_indexes(conn, table_name, &table_oid)?;
 
     // Populate dependencies (for Rails sequence ownership detection)
     populate_table_dependencies(conn, table_name, &table_oid)?;
 
+    // The cached pg_constraint snapshot is now stale for this table's rows.
+    PgConstraintHandler::invalidate_snapshot();
+
     info!("Successfully populated constraints for table: {}", table_name);
     Ok(())
 }
 
+/// Delete every `pg_constraint`/`pg_attrdef`/`pg_index`/`pg_depend` row keyed
+/// by a table's OID. Called standalone on `DROP TABLE`, and ahead of a fresh
+/// `populate_constraints_for_table` pass by [`refresh_constraints_for_table`]
+/// on `ALTER TABLE`, so re-population never leaves stale rows behind.
+pub fn remove_constraints_for_table(conn: &Connection, table_name: &str) -> Result<()> {
+    let table_oid = generate_table_oid(conn, table_name)?;
+
+    conn.execute("DELETE FROM pg_constraint WHERE conrelid = ?1", [&table_oid])?;
+    conn.execute("DELETE FROM pg_attrdef WHERE adrelid = ?1", [&table_oid])?;
+    conn.execute("DELETE FROM pg_index WHERE indrelid = ?1", [&table_oid])?;
+    conn.execute(
+        "DELETE FROM pg_depend WHERE objid = ?1 OR refobjid = ?1",
+        [&table_oid]
+    )?;
+    conn.execute(
+        "DELETE FROM __pgsqlite_generated_columns WHERE table_name = ?1",
+        [table_name]
+    )?;
+    conn.execute(
+        "DELETE FROM pg_sequence WHERE seqrelid IN (
+            SELECT seq_oid FROM __pgsqlite_sequences WHERE table_name = ?1
+        )",
+        [table_name]
+    )?;
+    conn.execute(
+        "DELETE FROM __pgsqlite_sequences WHERE table_name = ?1",
+        [table_name]
+    )?;
+
+    // The cached pg_constraint snapshot is now stale for this table's rows.
+    PgConstraintHandler::invalidate_snapshot();
+
+    info!("Removed catalog rows (oid {}) for table: {}", table_oid, table_name);
+    Ok(())
+}
+
+/// Re-derive `pg_constraint`/`pg_attrdef`/`pg_index`/`pg_depend` rows for a
+/// table after a schema change (`ALTER TABLE ... ADD/DROP COLUMN`,
+/// `ADD/DROP CONSTRAINT`, `CREATE INDEX`, ...), by discarding the rows from
+/// the previous shape and re-running the same population pass used at
+/// table-creation time.
+pub fn refresh_constraints_for_table(conn: &Connection, table_name: &str) -> Result<()> {
+    remove_constraints_for_table(conn, table_name)?;
+    populate_constraints_for_table(conn, table_name)
+}
+
 /// Get the CREATE TABLE statement for a table from sqlite_master
 fn get_create_table_sql(conn: &Connection, table_name: &str) -> Result<String> {
     let mut stmt = conn.prepare("SELECT sql FROM sqlite_master WHERE type = 'table' AND name = ?1")?;
@@ -80,50 +153,261 @@ fn get_create_table_sql(conn: &Connection, table_name: &str) -> Result<String> {
     Ok(sql)
 }
 
-/// Generate table OID using the same algorithm as the pg_class view
-fn generate_table_oid(name: &str) -> String {
-    // Must match the formula in pg_class view for JOIN compatibility:
-    // (unicode(substr(name, 1, 1)) * 1000000) +
-    // (unicode(substr(name || ' ', 2, 1)) * 10000) +
-    // (unicode(substr(name || '  ', 3, 1)) * 100) +
-    // (length(name) * 7)
-    let name_with_padding = format!("{}  ", name);
-    let chars: Vec<char> = name_with_padding.chars().collect();
-    let char1 = chars.get(0).copied().unwrap_or(' ') as u32;
-    let char2 = chars.get(1).copied().unwrap_or(' ') as u32;
-    let char3 = chars.get(2).copied().unwrap_or(' ') as u32;
-    let length = name.len() as u32;
+/// Get the CREATE INDEX statement for an index from sqlite_master. `None`
+/// for implicit indexes SQLite creates for inline PRIMARY KEY/UNIQUE
+/// constraints, which have a NULL `sql` column.
+fn get_create_index_sql(conn: &Connection, index_name: &str) -> Option<String> {
+    conn.query_row(
+        "SELECT sql FROM sqlite_master WHERE type = 'index' AND name = ?1",
+        [index_name],
+        |row| row.get(0),
+    ).ok()
+}
+
+/// Split a `CREATE INDEX ... ON table (<here>)` column/expression list on
+/// top-level commas, so expressions with their own nested parentheses
+/// (`upper(col)`, `(col1 || col2)`) aren't split in the middle.
+fn split_top_level_commas(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+/// Pull the indexed column/expression list and an optional `WHERE` predicate
+/// out of a `CREATE INDEX` statement. Best-effort: returns `(vec![], None)`
+/// on anything that doesn't look like the expected shape.
+fn parse_index_definition(create_index_sql: &str) -> (Vec<String>, Option<String>) {
+    static WHERE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)\bWHERE\s+(.+)$").unwrap());
+
+    let Some(open_idx) = create_index_sql.find('(') else { return (vec![], None) };
+    let bytes = create_index_sql.as_bytes();
+    let mut depth = 0i32;
+    let mut close_idx = None;
+    for (i, &b) in bytes.iter().enumerate().skip(open_idx) {
+        match b as char {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    close_idx = Some(i);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let Some(close_idx) = close_idx else { return (vec![], None) };
+
+    let columns = split_top_level_commas(&create_index_sql[open_idx + 1..close_idx])
+        .into_iter()
+        .map(|s| s.trim().to_string())
+        .collect();
+
+    let predicate = WHERE_RE.captures(&create_index_sql[close_idx + 1..])
+        .and_then(|c| c.get(1))
+        .map(|m| m.as_str().trim().trim_end_matches(';').trim().to_string());
+
+    (columns, predicate)
+}
 
-    let oid = ((char1 * 1000000) + (char2 * 10000) + (char3 * 100) + (length * 7)) % 1000000 + 16384;
-    oid.to_string()
+/// Generate a table's OID via the persisted, collision-free allocator so
+/// every catalog relation (pg_class, pg_depend, pg_attribute, ...) shares
+/// the same stable OID for a given table, restart over restart.
+pub(crate) fn generate_table_oid(conn: &Connection, name: &str) -> Result<String> {
+    use crate::utils::oid_registry::{allocate_oid, OidKind};
+    Ok(allocate_oid(conn, OidKind::Table, name)?.to_string())
 }
 
 /// Generate constraint OID with better collision avoidance
-fn generate_constraint_oid(name: &str, contype: &str) -> String {
-    use crate::utils::generate_oid;
+///
+/// This predates (and isn't yet migrated onto) the persisted allocator in
+/// `crate::utils::oid_registry` - it stays a pure hash of the constraint's
+/// name + type, offset into its own range to avoid colliding with a table
+/// OID. Threading a `Connection` through the many pure constraint-parsing
+/// call sites that build `ConstraintInfo` is a larger, separate change than
+/// fixing `generate_oid`/`pg_class`, so it's left alone here.
+pub(crate) fn generate_constraint_oid(name: &str, contype: &str) -> String {
+    use crate::utils::oid_generator::hash_seed;
     // Add the constraint type to the name to avoid collisions between different constraint types
     let unique_name = format!("{}_{}", name, contype);
     // Use a different offset range for constraints to avoid collision with tables
-    let base_oid = generate_oid(&unique_name);
+    let base_oid = hash_seed(&unique_name);
     // Offset by 500000 to put constraints in a different range
     let final_oid = base_oid + 500000;
-    eprintln!("  üîë OID generation: {} + {} -> base:{} final:{}", name, contype, base_oid, final_oid);
+    eprintln!("  [oid] {} + {} -> base:{} final:{}", name, contype, base_oid, final_oid);
     final_oid.to_string()
 }
 
-/// Extract referenced table name from foreign key definition and return its OID
-fn get_referenced_table_oid(_conn: &Connection, definition: &str) -> Result<String> {
-    // Extract table name from "FOREIGN KEY REFERENCES table_name(column)"
-    if let Some(cap) = Regex::new(r"(?i)REFERENCES\s+(\w+)").unwrap().captures(definition)
-        && let Some(table_name) = cap.get(1) {
-            // Use the same formula as pg_class view for consistency
-            return Ok(generate_table_oid(table_name.as_str()));
+/// Map a SQLite FK action keyword (`CASCADE`, `SET NULL`, ...) to PostgreSQL's
+/// single-char `confupdtype`/`confdeltype` code. Unrecognized/absent actions
+/// default to `a` (NO ACTION), matching SQLite's own default FK behavior.
+pub(crate) fn fk_action_code(action: &str) -> char {
+    let normalized: String = action.split_whitespace().collect::<Vec<_>>().join(" ").to_uppercase();
+    match normalized.as_str() {
+        "CASCADE" => 'c',
+        "SET NULL" => 'n',
+        "SET DEFAULT" => 'd',
+        "RESTRICT" => 'r',
+        _ => 'a',
+    }
+}
+
+/// Reverse of [`fk_action_code`]: map a `confupdtype`/`confdeltype` code back
+/// to the `information_schema.referential_constraints` rule name PostgreSQL reports.
+pub(crate) fn fk_action_name(code: char) -> &'static str {
+    match code {
+        'c' => "CASCADE",
+        'n' => "SET NULL",
+        'd' => "SET DEFAULT",
+        'r' => "RESTRICT",
+        _ => "NO ACTION",
+    }
+}
+
+/// Map a `MATCH` clause to PostgreSQL's single-char `confmatchtype` code.
+/// Absent/unrecognized match types default to `s` (SIMPLE), PostgreSQL's own default.
+pub(crate) fn fk_match_code(match_type: &str) -> char {
+    match match_type.trim().to_uppercase().as_str() {
+        "FULL" => 'f',
+        "PARTIAL" => 'p',
+        _ => 's',
+    }
+}
+
+/// Pull `ON UPDATE`/`ON DELETE`/`MATCH` out of the tail following a
+/// `REFERENCES table(cols)` clause and return `(confupdtype, confdeltype, confmatchtype)`.
+fn parse_fk_actions(tail: &str) -> (char, char, char) {
+    static ON_DELETE_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)ON\s+DELETE\s+(CASCADE|SET\s+NULL|SET\s+DEFAULT|RESTRICT|NO\s+ACTION)").unwrap()
+    });
+    static ON_UPDATE_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)ON\s+UPDATE\s+(CASCADE|SET\s+NULL|SET\s+DEFAULT|RESTRICT|NO\s+ACTION)").unwrap()
+    });
+    static MATCH_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)MATCH\s+(FULL|PARTIAL|SIMPLE)").unwrap());
+
+    let on_update = ON_UPDATE_RE.captures(tail).and_then(|c| c.get(1)).map(|m| fk_action_code(m.as_str())).unwrap_or('a');
+    let on_delete = ON_DELETE_RE.captures(tail).and_then(|c| c.get(1)).map(|m| fk_action_code(m.as_str())).unwrap_or('a');
+    let match_type = MATCH_RE.captures(tail).and_then(|c| c.get(1)).map(|m| fk_match_code(m.as_str())).unwrap_or('s');
+
+    (on_update, on_delete, match_type)
+}
+
+/// Same mapping as `parse_fk_actions`, but from the AST's own `RefArg`/`RefAct`
+/// clause nodes instead of a regex tail match.
+fn fk_actions_from_ref_args(args: &[sqlite3_parser::ast::RefArg]) -> (char, char, char) {
+    use sqlite3_parser::ast::RefArg;
+
+    let mut on_update = 'a';
+    let mut on_delete = 'a';
+    let mut match_type = 's';
+
+    for arg in args {
+        match arg {
+            RefArg::OnDelete(act) => on_delete = ref_act_code(act),
+            RefArg::OnUpdate(act) => on_update = ref_act_code(act),
+            RefArg::Match(name) => match_type = fk_match_code(&name.0),
         }
+    }
+
+    (on_update, on_delete, match_type)
+}
+
+fn ref_act_code(act: &sqlite3_parser::ast::RefAct) -> char {
+    use sqlite3_parser::ast::RefAct;
+
+    match act {
+        RefAct::Cascade => 'c',
+        RefAct::SetNull => 'n',
+        RefAct::SetDefault => 'd',
+        RefAct::Restrict => 'r',
+        RefAct::NoAction => 'a',
+    }
+}
+
+/// Pull the referenced table name and column list out of a constraint's
+/// `"FOREIGN KEY REFERENCES table(col1, col2)"` definition text.
+fn parse_references(definition: &str) -> Option<(String, Vec<String>)> {
+    static REFERENCES_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)REFERENCES\s+(\w+)\s*\(\s*([^)]*)\s*\)").unwrap()
+    });
+
+    let cap = REFERENCES_RE.captures(definition)?;
+    let table = cap.get(1)?.as_str().to_string();
+    let columns = cap.get(2)?.as_str()
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+    Some((table, columns))
+}
+
+/// Extract referenced table name from foreign key definition and return its OID
+fn get_referenced_table_oid(conn: &Connection, definition: &str) -> Result<String> {
+    if let Some((table_name, _)) = parse_references(definition) {
+        // Use the same persisted allocator as pg_class for consistency
+        return generate_table_oid(conn, &table_name);
+    }
 
     // Fallback to a default OID if parsing fails
     Ok("0".to_string())
 }
 
+/// Resolve the referenced columns captured in a FK's definition to their
+/// 1-based attnums in the referenced table, mirroring the `column_map` lookup
+/// `populate_table_indexes` does for the owning table. Falls back to `{1}`
+/// when the referenced table doesn't exist yet (e.g. not created yet in this
+/// session) or any referenced column can't be resolved.
+fn resolve_confkey(conn: &Connection, definition: &str) -> String {
+    let default = "{1}".to_string();
+
+    let Some((ref_table, ref_columns)) = parse_references(definition) else {
+        return default;
+    };
+    if ref_columns.is_empty() {
+        return default;
+    }
+
+    let query = format!("PRAGMA table_info({ref_table})");
+    let Ok(mut stmt) = conn.prepare(&query) else { return default };
+    let Ok(rows) = stmt.query_map([], |row| {
+        let cid: i32 = row.get(0)?;
+        let name: String = row.get(1)?;
+        Ok((name, cid + 1)) // 1-based attnum, matching PostgreSQL
+    }) else { return default };
+
+    let mut column_map = std::collections::HashMap::new();
+    for row in rows.flatten() {
+        column_map.insert(row.0, row.1);
+    }
+    if column_map.is_empty() {
+        // Referenced table doesn't exist (yet) - defer to the default rather
+        // than emitting a confkey that can't possibly be right.
+        return default;
+    }
+
+    let attnums: Option<Vec<String>> = ref_columns.iter()
+        .map(|col| column_map.get(col).map(|n: &i32| n.to_string()))
+        .collect();
+
+    match attnums {
+        Some(nums) => format!("{{{}}}", nums.join(",")),
+        None => default,
+    }
+}
+
 /// Populate pg_constraint table with constraint information
 fn populate_table_constraints(conn: &Connection, table_name: &str, create_sql: &str, table_oid: &str) -> Result<()> {
     let constraints = parse_table_constraints(table_name, create_sql);
@@ -138,6 +422,8 @@ fn populate_table_constraints(conn: &Connection, table_name: &str, create_sql: &
             info!("Found foreign key constraint: {} for column: {:?}", constraint.name, constraint.columns);
             let ref_table_oid = get_referenced_table_oid(conn, &constraint.definition)?;
             info!("Referenced table OID: {}", ref_table_oid);
+            let (confupdtype, confdeltype, confmatchtype) = constraint.fk_actions.unwrap_or(('a', 'a', 's'));
+            let confkey = resolve_confkey(conn, &constraint.definition);
 
             // Convert column names to column numbers for conkey
             let col_nums: Vec<String> = constraint.columns
@@ -157,7 +443,7 @@ fn populate_table_constraints(conn: &Connection, table_name: &str, create_sql: &
             }
 
             eprintln!("üíæ Inserting foreign key: oid={}, name={}, conrelid={}, confrelid={}, conkey={:?}, confkey={:?}",
-                     constraint.oid, constraint.name, table_oid, ref_table_oid, col_nums, "{1}");
+                     constraint.oid, constraint.name, table_oid, ref_table_oid, col_nums, confkey);
             let result = conn.execute(
                 "INSERT OR IGNORE INTO pg_constraint (
                     oid, conname, contype, conrelid, confrelid, conkey, confkey,
@@ -170,10 +456,10 @@ fn populate_table_constraints(conn: &Connection, table_name: &str, create_sql: &
                     table_oid,                               // conrelid as TEXT
                     ref_table_oid,                           // confrelid as TEXT (to match pg_class.oid)
                     format!("{{{}}}", col_nums.join(",")),   // Use column numbers instead of names
-                    "{1}".to_string(), // Default to column 1 of referenced table
-                    "a".to_string(),   // NO ACTION (default)
-                    "a".to_string(),   // NO ACTION (default)
-                    "s".to_string(),   // SIMPLE (default)
+                    confkey,
+                    confupdtype.to_string(),
+                    confdeltype.to_string(),
+                    confmatchtype.to_string(),
                     true,              // conislocal as boolean
                     true,              // convalidated as boolean
                 ]
@@ -268,7 +554,7 @@ fn populate_table_indexes(conn: &Connection, table_name: &str, table_oid: &str)
     })?;
 
     for index_result in index_rows {
-        let (_seq, index_name, is_unique, origin, _partial) = index_result?;
+        let (_seq, index_name, is_unique, origin, partial) = index_result?;
         let index_oid = generate_constraint_oid(&index_name, "i");
 
         // Skip auto-indexes created by SQLite for unique constraints
@@ -286,20 +572,46 @@ fn populate_table_indexes(conn: &Connection, table_name: &str, table_oid: &str)
             Ok((seqno, cid, name))
         })?;
 
-        let mut column_numbers = Vec::new();
-        let mut column_count = 0;
+        // For partial/expression indexes, SQLite doesn't surface the WHERE
+        // predicate or expression text via any PRAGMA - pull them from the
+        // originating CREATE INDEX SQL instead.
+        let create_index_sql = get_create_index_sql(conn, &index_name);
+        let (raw_columns, predicate) = create_index_sql.as_deref()
+            .map(parse_index_definition)
+            .unwrap_or_default();
+
+        let mut indkey_parts = Vec::new();
+        let mut expr_texts = Vec::new();
+        let mut indnkeyatts = 0;
+        let mut expr_count = 0;
 
         for info_result in info_rows {
-            let (_seqno, _cid, col_name_opt) = info_result?;
-            if let Some(col_name) = col_name_opt
-                && let Some(&attnum) = column_map.get(&col_name) {
-                column_numbers.push(attnum.to_string());
-                column_count += 1;
+            let (seqno, _cid, col_name_opt) = info_result?;
+            match col_name_opt {
+                Some(col_name) => {
+                    if let Some(&attnum) = column_map.get(&col_name) {
+                        indkey_parts.push(attnum.to_string());
+                        indnkeyatts += 1;
+                    }
+                }
+                None => {
+                    // Expression term - NULL column name, no attnum. Postgres
+                    // marks these `0` in indkey and stores the text in indexprs.
+                    let expr = raw_columns.get(seqno as usize)
+                        .map(|s| s.trim().to_string())
+                        .unwrap_or_else(|| format!("expr{seqno}"));
+                    indkey_parts.push("0".to_string());
+                    expr_texts.push(expr);
+                    expr_count += 1;
+                }
             }
         }
 
         // Build indkey field (space-separated column numbers, PostgreSQL format)
-        let indkey = column_numbers.join(" ");
+        let indkey = indkey_parts.join(" ");
+        let indnatts = indnkeyatts + expr_count;
+        let indexprs = expr_texts.join(", ");
+        let indpred = if partial { predicate.unwrap_or_default() } else { String::new() };
 
         // Determine if this is a primary key index
         let is_primary = origin == "pk" || index_name.contains("primary") || index_name.contains("pkey");
@@ -312,15 +624,17 @@ fn populate_table_indexes(conn: &Connection, table_name: &str, table_oid: &str)
                 indisvalid, indcheckxmin, indisready, indislive,
                 indisreplident, indcollation, indclass, indoption,
                 indexprs, indpred
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 0, 1, 0, 1, 0, 1, 1, 0, '', '', '', '', '')",
-            [
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 0, 1, 0, 1, 0, 1, 1, 0, '', '', '', ?8, ?9)",
+            rusqlite::params![
                 &index_oid,
                 table_oid,
-                &column_count.to_string(),
-                &column_count.to_string(), // For regular indexes, indnkeyatts = indnatts
+                &indnatts.to_string(),
+                &indnkeyatts.to_string(),
                 &(is_unique as i32).to_string(),
                 &(is_primary as i32).to_string(),
                 &indkey,
+                &indexprs,
+                &indpred,
             ]
         )?;
 
@@ -339,6 +653,9 @@ struct ConstraintInfo {
     contype: String,
     columns: Vec<String>,
     definition: String,
+    /// `(confupdtype, confdeltype, confmatchtype)` for `contype = 'f'` rows;
+    /// `None` for every other constraint kind.
+    fk_actions: Option<(char, char, char)>,
 }
 
 /// Information about a column default
@@ -349,8 +666,292 @@ struct DefaultInfo {
     default_expr: String,
 }
 
+/// Information about a `GENERATED ALWAYS AS (...)` column, destined for
+/// `__pgsqlite_generated_columns` rather than `pg_attrdef` since it isn't a
+/// plain default.
+#[derive(Debug)]
+struct GeneratedColumnInfo {
+    column_name: String,
+    expression: String,
+    /// `'s'` (STORED) or `'v'` (VIRTUAL), matching `pg_attribute.attgenerated`.
+    generation_type: char,
+}
+
+/// A single column pulled out of a `CREATE TABLE`'s AST, with its 1-based
+/// ordinal and whichever inline constraints were attached to it.
+struct ParsedColumn {
+    name: String,
+    ordinal: i16,
+    not_null: bool,
+    primary_key: bool,
+    unique: bool,
+    default_expr: Option<String>,
+    check_expr: Option<String>,
+    references: Option<(String, String)>,
+    /// `(confupdtype, confdeltype, confmatchtype)`, set only alongside `references`.
+    fk_actions: Option<(char, char, char)>,
+    /// `(generation expression, storage kind)` for `GENERATED ALWAYS AS (...)`
+    /// columns, where storage kind is `'s'` (STORED) or `'v'` (VIRTUAL) -
+    /// matching `pg_attribute.attgenerated`. `None` for ordinary columns.
+    generated: Option<(String, char)>,
+}
+
+/// A table-level constraint (`PRIMARY KEY (...)`, `FOREIGN KEY (...) REFERENCES ...`, etc.)
+struct ParsedTableConstraint {
+    kind: char, // 'p' | 'u' | 'c' | 'f', matching pg_constraint.contype
+    columns: Vec<String>,
+    check_expr: Option<String>,
+    references: Option<(String, Vec<String>)>,
+    /// `(confupdtype, confdeltype, confmatchtype)`, set only alongside `references`.
+    fk_actions: Option<(char, char, char)>,
+}
+
+struct ParsedCreateTable {
+    columns: Vec<ParsedColumn>,
+    table_constraints: Vec<ParsedTableConstraint>,
+}
+
+/// Parse a `CREATE TABLE` statement with `sqlite3-parser` and walk its
+/// `Stmt::CreateTable` column/constraint nodes into `ParsedCreateTable`.
+///
+/// Returns `None` on anything the parser chokes on (or doesn't recognize as
+/// a plain `CREATE TABLE (...)` body, e.g. `CREATE TABLE ... AS SELECT`) so
+/// callers can fall back to the regex-based parsing below.
+fn parse_create_table_ast(create_sql: &str) -> Option<ParsedCreateTable> {
+    let mut parser = SqlParser::new(create_sql.as_bytes());
+    let cmd = parser.next().ok().flatten()?;
+    let Cmd::Stmt(Stmt::CreateTable { body, .. }) = cmd else { return None };
+    let CreateTableBody::ColumnsAndConstraints { columns, constraints, .. } = body else { return None };
+
+    let mut parsed_columns = Vec::with_capacity(columns.len());
+    for (ordinal, (name, def)) in columns.into_iter().enumerate() {
+        let mut column = ParsedColumn {
+            name: name.0,
+            ordinal: (ordinal + 1) as i16,
+            not_null: false,
+            primary_key: false,
+            unique: false,
+            default_expr: None,
+            check_expr: None,
+            references: None,
+            fk_actions: None,
+            generated: None,
+        };
+
+        for named in &def.constraints {
+            match &named.constraint {
+                ColumnConstraint::PrimaryKey { .. } => column.primary_key = true,
+                ColumnConstraint::NotNull { .. } => column.not_null = true,
+                ColumnConstraint::Unique(..) => column.unique = true,
+                ColumnConstraint::Check(expr) => column.check_expr = Some(expr.to_string()),
+                ColumnConstraint::Default(expr) => column.default_expr = Some(expr.to_string()),
+                ColumnConstraint::ForeignKey { clause, .. } => {
+                    let ref_table = clause.tbl_name.0.clone();
+                    let ref_column = clause.columns.as_ref()
+                        .and_then(|cols| cols.first())
+                        .map(|c| c.col_name.0.clone())
+                        .unwrap_or_default();
+                    column.fk_actions = Some(fk_actions_from_ref_args(&clause.args));
+                    column.references = Some((ref_table, ref_column));
+                }
+                ColumnConstraint::Generated { expr, typ } => {
+                    // SQLite defaults an un-annotated `GENERATED ALWAYS AS (expr)`
+                    // column to VIRTUAL; only an explicit `STORED` keyword makes it 's'.
+                    let stored = typ.as_ref()
+                        .map(|id| id.0.eq_ignore_ascii_case("STORED"))
+                        .unwrap_or(false);
+                    column.generated = Some((expr.to_string(), if stored { 's' } else { 'v' }));
+                }
+                _ => {}
+            }
+        }
+
+        parsed_columns.push(column);
+    }
+
+    let mut table_constraints = Vec::new();
+    for named in constraints.into_iter().flatten() {
+        match named.constraint {
+            TableConstraint::PrimaryKey { columns, .. } => {
+                table_constraints.push(ParsedTableConstraint {
+                    kind: 'p',
+                    columns: columns.into_iter().map(|c| c.expr.to_string()).collect(),
+                    check_expr: None,
+                    references: None,
+                    fk_actions: None,
+                });
+            }
+            TableConstraint::Unique { columns, .. } => {
+                table_constraints.push(ParsedTableConstraint {
+                    kind: 'u',
+                    columns: columns.into_iter().map(|c| c.expr.to_string()).collect(),
+                    check_expr: None,
+                    references: None,
+                    fk_actions: None,
+                });
+            }
+            TableConstraint::Check(expr) => {
+                table_constraints.push(ParsedTableConstraint {
+                    kind: 'c',
+                    columns: vec![],
+                    check_expr: Some(expr.to_string()),
+                    references: None,
+                    fk_actions: None,
+                });
+            }
+            TableConstraint::ForeignKey { columns, clause, .. } => {
+                let fk_actions = fk_actions_from_ref_args(&clause.args);
+                let ref_columns = clause.columns.unwrap_or_default()
+                    .into_iter().map(|c| c.col_name.0).collect();
+                table_constraints.push(ParsedTableConstraint {
+                    kind: 'f',
+                    columns: columns.into_iter().map(|c| c.col_name.0).collect(),
+                    check_expr: None,
+                    references: Some((clause.tbl_name.0, ref_columns)),
+                    fk_actions: Some(fk_actions),
+                });
+            }
+        }
+    }
+
+    Some(ParsedCreateTable { columns: parsed_columns, table_constraints })
+}
+
+/// Build the same `ConstraintInfo` rows the regex path produces, but from a
+/// real AST: naming/oid scheme is kept identical so `populate_constraints_for_table`
+/// is unaffected by which path parsed the statement.
+fn build_constraint_infos_from_ast(table_name: &str, parsed: &ParsedCreateTable) -> Vec<ConstraintInfo> {
+    let mut constraints = Vec::new();
+    let mut pk_columns = Vec::new();
+    let mut check_count = 0;
+
+    for col in &parsed.columns {
+        if col.primary_key {
+            pk_columns.push(col.name.clone());
+        }
+        if col.unique {
+            constraints.push(ConstraintInfo {
+                oid: generate_constraint_oid(&format!("{}_{}_key", table_name, col.name), "u"),
+                name: format!("{}_{}_key", table_name, col.name),
+                contype: "u".to_string(),
+                columns: vec![col.name.clone()],
+                definition: "UNIQUE".to_string(),
+                fk_actions: None,
+            });
+        }
+        if let Some(check) = &col.check_expr {
+            check_count += 1;
+            let constraint_name = format!("{table_name}_check{check_count}");
+            constraints.push(ConstraintInfo {
+                oid: generate_constraint_oid(&constraint_name, "c"),
+                name: constraint_name,
+                contype: "c".to_string(),
+                columns: vec![],
+                definition: format!("CHECK ({check})"),
+                fk_actions: None,
+            });
+        }
+        if col.not_null {
+            let constraint_name = format!("{}_{}_not_null", table_name, col.name);
+            constraints.push(ConstraintInfo {
+                oid: generate_constraint_oid(&constraint_name, "c"),
+                name: constraint_name,
+                contype: "c".to_string(),
+                columns: vec![col.name.clone()],
+                definition: format!("{} IS NOT NULL", col.name),
+                fk_actions: None,
+            });
+        }
+        if let Some((ref_table, ref_column)) = &col.references {
+            let constraint_name = format!("{}_{}_fkey", table_name, col.name);
+            constraints.push(ConstraintInfo {
+                oid: generate_constraint_oid(&constraint_name, "f"),
+                name: constraint_name,
+                contype: "f".to_string(),
+                columns: vec![col.name.clone()],
+                definition: format!("FOREIGN KEY REFERENCES {ref_table}({ref_column})"),
+                fk_actions: col.fk_actions,
+            });
+        }
+    }
+
+    if !pk_columns.is_empty() {
+        constraints.push(ConstraintInfo {
+            oid: generate_constraint_oid(&format!("{table_name}_pkey"), "p"),
+            name: format!("{table_name}_pkey"),
+            contype: "p".to_string(),
+            columns: pk_columns,
+            definition: "PRIMARY KEY".to_string(),
+            fk_actions: None,
+        });
+    }
+
+    for tc in &parsed.table_constraints {
+        match tc.kind {
+            'p' => {
+                constraints.push(ConstraintInfo {
+                    oid: generate_constraint_oid(&format!("{table_name}_pkey"), "p"),
+                    name: format!("{table_name}_pkey"),
+                    contype: "p".to_string(),
+                    columns: tc.columns.clone(),
+                    definition: "PRIMARY KEY".to_string(),
+                    fk_actions: None,
+                });
+            }
+            'u' => {
+                let constraint_name = format!("{}_{}_key", table_name, tc.columns.join("_"));
+                constraints.push(ConstraintInfo {
+                    oid: generate_constraint_oid(&constraint_name, "u"),
+                    name: constraint_name,
+                    contype: "u".to_string(),
+                    columns: tc.columns.clone(),
+                    definition: "UNIQUE".to_string(),
+                    fk_actions: None,
+                });
+            }
+            'c' => {
+                check_count += 1;
+                let constraint_name = format!("{table_name}_check{check_count}");
+                constraints.push(ConstraintInfo {
+                    oid: generate_constraint_oid(&constraint_name, "c"),
+                    name: constraint_name,
+                    contype: "c".to_string(),
+                    columns: vec![],
+                    definition: format!("CHECK ({})", tc.check_expr.clone().unwrap_or_default()),
+                    fk_actions: None,
+                });
+            }
+            'f' => {
+                let constraint_name = format!("{}_{}_fkey", table_name, tc.columns.join("_"));
+                let (ref_table, ref_columns) = tc.references.clone().unwrap_or_default();
+                constraints.push(ConstraintInfo {
+                    oid: generate_constraint_oid(&constraint_name, "f"),
+                    name: constraint_name,
+                    contype: "f".to_string(),
+                    columns: tc.columns.clone(),
+                    definition: format!("FOREIGN KEY REFERENCES {}({})", ref_table, ref_columns.join(", ")),
+                    fk_actions: tc.fk_actions,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    constraints
+}
+
 /// Parse table constraints from CREATE TABLE statement
 fn parse_table_constraints(table_name: &str, create_sql: &str) -> Vec<ConstraintInfo> {
+    if let Some(parsed) = parse_create_table_ast(create_sql) {
+        return build_constraint_infos_from_ast(table_name, &parsed);
+    }
+    info!("Falling back to regex-based constraint parsing for table: {}", table_name);
+    parse_table_constraints_regex(table_name, create_sql)
+}
+
+/// Regex-based constraint parsing, kept only as a fallback for CREATE TABLE
+/// statements the AST parser rejects.
+fn parse_table_constraints_regex(table_name: &str, create_sql: &str) -> Vec<ConstraintInfo> {
     let mut constraints = Vec::new();
     info!("Parsing constraints for table: {} from SQL: {}", table_name, create_sql);
 
@@ -364,10 +965,11 @@ fn parse_table_constraints(table_name: &str, create_sql: &str) -> Vec<Constraint
                 contype: "p".to_string(),
                 columns: vec![column_name.as_str().to_string()],
                 definition: "PRIMARY KEY".to_string(),
+                fk_actions: None,
             });
         }
     }
-    
+
     // Parse table-level PRIMARY KEY constraints
     for cap in TABLE_PK_REGEX.captures_iter(create_sql) {
         if let Some(columns_str) = cap.get(1) {
@@ -381,10 +983,11 @@ fn parse_table_constraints(table_name: &str, create_sql: &str) -> Vec<Constraint
                 contype: "p".to_string(),
                 columns,
                 definition: "PRIMARY KEY".to_string(),
+                fk_actions: None,
             });
         }
     }
-    
+
     // Parse UNIQUE constraints
     for cap in UNIQUE_REGEX.captures_iter(create_sql) {
         if let Some(column_name) = cap.get(1) {
@@ -394,10 +997,11 @@ fn parse_table_constraints(table_name: &str, create_sql: &str) -> Vec<Constraint
                 contype: "u".to_string(),
                 columns: vec![column_name.as_str().to_string()],
                 definition: "UNIQUE".to_string(),
+                fk_actions: None,
             });
         }
     }
-    
+
     // Parse table-level UNIQUE constraints
     for cap in TABLE_UNIQUE_REGEX.captures_iter(create_sql) {
         if let Some(columns_str) = cap.get(1) {
@@ -412,10 +1016,11 @@ fn parse_table_constraints(table_name: &str, create_sql: &str) -> Vec<Constraint
                 contype: "u".to_string(),
                 columns,
                 definition: "UNIQUE".to_string(),
+                fk_actions: None,
             });
         }
     }
-    
+
     // Parse CHECK constraints
     for (i, cap) in CHECK_REGEX.captures_iter(create_sql).enumerate() {
         if let Some(check_expr) = cap.get(1) {
@@ -426,10 +1031,11 @@ fn parse_table_constraints(table_name: &str, create_sql: &str) -> Vec<Constraint
                 contype: "c".to_string(),
                 columns: vec![], // CHECK constraints don't have specific columns
                 definition: format!("CHECK ({})", check_expr.as_str()),
+                fk_actions: None,
             });
         }
     }
-    
+
     // Parse NOT NULL constraints (treated as check constraints in PostgreSQL)
     for cap in NOT_NULL_REGEX.captures_iter(create_sql) {
         if let Some(column_name) = cap.get(1) {
@@ -440,6 +1046,7 @@ fn parse_table_constraints(table_name: &str, create_sql: &str) -> Vec<Constraint
                 contype: "c".to_string(),
                 columns: vec![column_name.as_str().to_string()],
                 definition: format!("{} IS NOT NULL", column_name.as_str()),
+                fk_actions: None,
             });
         }
     }
@@ -456,6 +1063,7 @@ fn parse_table_constraints(table_name: &str, create_sql: &str) -> Vec<Constraint
                 .split(',')
                 .map(|s| s.trim().to_string())
                 .collect();
+            let fk_actions = parse_fk_actions(cap.get(4).map(|m| m.as_str()).unwrap_or(""));
 
             let constraint_name = format!("{}_{}_fkey", table_name, local_cols.join("_"));
             constraints.push(ConstraintInfo {
@@ -465,6 +1073,7 @@ fn parse_table_constraints(table_name: &str, create_sql: &str) -> Vec<Constraint
                 columns: local_cols,
                 definition: format!("FOREIGN KEY REFERENCES {}({})",
                                   ref_table.as_str(), ref_cols.join(", ")),
+                fk_actions: Some(fk_actions),
             });
         }
     }
@@ -476,6 +1085,7 @@ fn parse_table_constraints(table_name: &str, create_sql: &str) -> Vec<Constraint
             (cap.get(1), cap.get(2), cap.get(3)) {
             info!("Found inline foreign key: {} REFERENCES {}({})", column_name.as_str(), ref_table.as_str(), ref_column.as_str());
             let constraint_name = format!("{}_{}_fkey", table_name, column_name.as_str());
+            let fk_actions = parse_fk_actions(cap.get(4).map(|m| m.as_str()).unwrap_or(""));
             constraints.push(ConstraintInfo {
                 oid: generate_constraint_oid(&constraint_name, "f"),
                 name: constraint_name,
@@ -483,6 +1093,7 @@ fn parse_table_constraints(table_name: &str, create_sql: &str) -> Vec<Constraint
                 columns: vec![column_name.as_str().to_string()],
                 definition: format!("FOREIGN KEY REFERENCES {}({})",
                                   ref_table.as_str(), ref_column.as_str()),
+                fk_actions: Some(fk_actions),
             });
         }
     }
@@ -492,8 +1103,82 @@ fn parse_table_constraints(table_name: &str, create_sql: &str) -> Vec<Constraint
 
 /// Parse column defaults from CREATE TABLE statement
 fn parse_column_defaults(table_name: &str, create_sql: &str) -> Vec<DefaultInfo> {
+    if let Some(parsed) = parse_create_table_ast(create_sql) {
+        return parsed.columns.iter()
+            .filter_map(|col| {
+                col.default_expr.as_ref().map(|expr| DefaultInfo {
+                    oid: generate_constraint_oid(&format!("{}_{}_default", table_name, col.name), "d"),
+                    column_num: col.ordinal,
+                    default_expr: expr.trim().to_string(),
+                })
+            })
+            .collect();
+    }
+    parse_column_defaults_regex(table_name, create_sql)
+}
+
+static GENERATED_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(\w+)\s+[^,\)]*?\bGENERATED\s+ALWAYS\s+AS\s*\(([^)]+)\)\s*(STORED|VIRTUAL)?").unwrap()
+});
+
+/// Parse `GENERATED ALWAYS AS (expr) [STORED|VIRTUAL]` column definitions
+/// from a CREATE TABLE statement, independent of `parse_column_defaults`
+/// since a generated column never carries a plain `pg_attrdef` default.
+fn parse_generated_columns(create_sql: &str) -> Vec<GeneratedColumnInfo> {
+    if let Some(parsed) = parse_create_table_ast(create_sql) {
+        return parsed.columns.iter()
+            .filter_map(|col| col.generated.as_ref().map(|(expr, kind)| GeneratedColumnInfo {
+                column_name: col.name.clone(),
+                expression: expr.trim().to_string(),
+                generation_type: *kind,
+            }))
+            .collect();
+    }
+    parse_generated_columns_regex(create_sql)
+}
+
+/// Regex-based generated-column parsing, kept only as a fallback for CREATE
+/// TABLE statements the AST parser rejects.
+fn parse_generated_columns_regex(create_sql: &str) -> Vec<GeneratedColumnInfo> {
+    GENERATED_REGEX.captures_iter(create_sql)
+        .filter_map(|cap| {
+            let column_name = cap.get(1)?.as_str().to_string();
+            let expression = cap.get(2)?.as_str().trim().to_string();
+            let stored = cap.get(3).is_some_and(|m| m.as_str().eq_ignore_ascii_case("STORED"));
+            Some(GeneratedColumnInfo { column_name, expression, generation_type: if stored { 's' } else { 'v' } })
+        })
+        .collect()
+}
+
+/// Record each table's `GENERATED ALWAYS AS (...)` columns in
+/// `__pgsqlite_generated_columns`, so the `pg_attribute` view can surface
+/// `attgenerated` the way PostgreSQL does for real generated columns.
+fn populate_generated_columns(conn: &Connection, table_name: &str, create_sql: &str) -> Result<()> {
+    for generated in parse_generated_columns(create_sql) {
+        conn.execute(
+            "INSERT OR REPLACE INTO __pgsqlite_generated_columns (
+                table_name, column_name, expression, generation_type
+            ) VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![
+                table_name,
+                &generated.column_name,
+                &generated.expression,
+                &generated.generation_type.to_string(),
+            ]
+        )?;
+
+        debug!("Inserted generated column: {}.{} = '{}' ({})",
+               table_name, generated.column_name, generated.expression, generated.generation_type);
+    }
+
+    Ok(())
+}
+
+/// Regex-based column default parsing, kept only as a fallback for CREATE
+/// TABLE statements the AST parser rejects.
+fn parse_column_defaults_regex(table_name: &str, create_sql: &str) -> Vec<DefaultInfo> {
     let mut defaults = Vec::new();
-    
+
     // Parse DEFAULT clauses - look for column definitions with DEFAULT
     for cap in DEFAULT_REGEX.captures_iter(create_sql) {
         if let (Some(column_name), Some(default_value)) = (cap.get(1), cap.get(2)) {
@@ -513,6 +1198,17 @@ fn parse_column_defaults(table_name: &str, create_sql: &str) -> Vec<DefaultInfo>
 
 /// Get the column number (1-based) for a given column name in a CREATE TABLE statement
 fn get_column_number(create_sql: &str, target_column: &str) -> Option<i16> {
+    if let Some(parsed) = parse_create_table_ast(create_sql) {
+        return parsed.columns.iter()
+            .find(|c| c.name.eq_ignore_ascii_case(target_column))
+            .map(|c| c.ordinal);
+    }
+    get_column_number_regex(create_sql, target_column)
+}
+
+/// Regex/string-scanning column ordinal lookup, kept only as a fallback for
+/// CREATE TABLE statements the AST parser rejects.
+fn get_column_number_regex(create_sql: &str, target_column: &str) -> Option<i16> {
     // Extract the column definitions from CREATE TABLE
     if let Some(cap) = TABLE_REGEX.captures(create_sql)
         && let Some(columns_part) = cap.get(1) {
@@ -590,7 +1286,7 @@ fn populate_table_dependencies(conn: &Connection, table_name: &str, table_oid: &
             debug!("Found single INTEGER PRIMARY KEY column: {} in table {} at position {}", column_name, table_name, cid + 1);
 
             // Generate deterministic OIDs
-            let sequence_oid = generate_sequence_oid(table_name, column_name);
+            let sequence_oid = generate_sequence_oid(conn, table_name, column_name)?;
             let table_oid_str = table_oid; // table_oid is already a string
 
             // Insert dependency record into pg_depend table
@@ -611,6 +1307,39 @@ fn populate_table_dependencies(conn: &Connection, table_name: &str, table_oid: &
 
             debug!("Inserted pg_depend record: sequence {} depends on column {} of table {} (result: {})",
                    sequence_oid, column_name, table_name, result);
+
+            // Seed the sequence's counter state so nextval()/currval()/setval()
+            // have something to operate on, continuing from whatever rowid the
+            // table already reached rather than restarting at 1.
+            let sequence_name = format!("{table_name}_{column_name}_seq");
+            let current_max: i64 = conn.query_row(
+                &format!("SELECT COALESCE(MAX({column_name}), 0) FROM {table_name}"),
+                [],
+                |row| row.get(0),
+            ).unwrap_or(0);
+            conn.execute(
+                "INSERT OR IGNORE INTO __pgsqlite_sequences (
+                    seq_oid, sequence_name, table_name, column_name,
+                    last_value, start_value, increment_by, min_value, max_value, is_called
+                ) VALUES (?1, ?2, ?3, ?4, ?5, 1, 1, 1, 9223372036854775807, ?6)",
+                rusqlite::params![
+                    sequence_oid.to_string(),
+                    sequence_name,
+                    table_name,
+                    column_name,
+                    current_max.max(1),
+                    (current_max > 0) as i32,
+                ],
+            )?;
+
+            // Make the sequence introspectable through pg_sequence/pg_class,
+            // the way ORMs look up a serial column's backing sequence.
+            conn.execute(
+                "INSERT OR REPLACE INTO pg_sequence (
+                    seqrelid, seqtypid, seqstart, seqincrement, seqmax, seqmin, seqcache, seqcycle
+                ) VALUES (?1, 20, 1, 1, 9223372036854775807, 1, 1, 0)",
+                [sequence_oid.to_string()],
+            )?;
         }
     } else {
         debug!("Table {} has {} PK columns, skipping dependency creation", table_name, pk_columns.len());
@@ -619,10 +1348,353 @@ fn populate_table_dependencies(conn: &Connection, table_name: &str, table_oid: &
     Ok(())
 }
 
-/// Generate a deterministic OID for a sequence based on table and column name
-fn generate_sequence_oid(table_name: &str, column_name: &str) -> u32 {
-    use crate::utils::generate_oid;
+/// Generate a collision-free OID for a sequence based on table and column
+/// name, routed through the centralized `__pgsqlite_oid_registry` (see
+/// `crate::utils::oid_registry`) instead of a bare hash-plus-offset, so two
+/// sequences can never alias onto the same OID.
+pub(crate) fn generate_sequence_oid(conn: &Connection, table_name: &str, column_name: &str) -> Result<u32> {
+    use crate::utils::oid_registry::{allocate_oid, OidKind};
     let sequence_name = format!("{}_{}_seq", table_name, column_name);
-    // Use the standard OID generator but offset for sequences to avoid conflicts
-    generate_oid(&sequence_name) + 50000
+    Ok(allocate_oid(conn, OidKind::Sequence, &sequence_name)?.get())
+}
+
+static ALTER_SEQUENCE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^\s*ALTER\s+SEQUENCE\s+(?:IF\s+EXISTS\s+)?([\w.]+)\s+RESTART(?:\s+WITH\s+(-?\d+))?").unwrap()
+});
+
+/// Whether `query` is an `ALTER SEQUENCE ... RESTART [WITH n]` statement -
+/// SQLite has no such statement, so these need to be intercepted before they
+/// ever reach it. Other `ALTER SEQUENCE` forms (e.g. `INCREMENT BY`) do not match.
+pub fn is_alter_sequence(query: &str) -> bool {
+    ALTER_SEQUENCE_REGEX.is_match(query)
+}
+
+/// Rewrite `ALTER SEQUENCE <name> RESTART [WITH n]` into an update of the
+/// `__pgsqlite_sequences` counter state, so a later `nextval()` picks up
+/// right where the client asked it to restart. A bare `RESTART` (no `WITH`)
+/// resets to the sequence's own `start_value`, matching PostgreSQL.
+pub fn handle_alter_sequence(conn: &Connection, query: &str) -> Result<()> {
+    let cap = ALTER_SEQUENCE_REGEX.captures(query)
+        .ok_or_else(|| anyhow::anyhow!("not an ALTER SEQUENCE ... RESTART statement: {query}"))?;
+    let sequence_name = cap.get(1).unwrap().as_str().rsplit('.').next().unwrap();
+
+    match cap.get(2) {
+        Some(value) => {
+            conn.execute(
+                "UPDATE __pgsqlite_sequences SET last_value = ?1, is_called = 0 WHERE sequence_name = ?2",
+                rusqlite::params![value.as_str().parse::<i64>()?, sequence_name],
+            )?;
+        }
+        None => {
+            conn.execute(
+                "UPDATE __pgsqlite_sequences SET last_value = start_value, is_called = 0 WHERE sequence_name = ?1",
+                [sequence_name],
+            )?;
+        }
+    }
+
+    info!("Restarted sequence: {}", sequence_name);
+    Ok(())
+}
+
+static CREATE_SEQUENCE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?is)^\s*CREATE\s+SEQUENCE\s+(?:IF\s+NOT\s+EXISTS\s+)?([\w.]+)(.*)$").unwrap()
+});
+
+static DROP_SEQUENCE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?is)^\s*DROP\s+SEQUENCE\s+(?:IF\s+EXISTS\s+)?([\w.]+)").unwrap()
+});
+
+static SEQUENCE_OWNED_BY_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)OWNED\s+BY\s+([\w]+)\s*\.\s*(\w+)").unwrap()
+});
+
+/// Whether `query` is a standalone `CREATE SEQUENCE` statement - SQLite has
+/// no such statement, so these need to be intercepted before they ever
+/// reach it, the same way `is_alter_sequence` intercepts `ALTER SEQUENCE`.
+pub fn is_create_sequence(query: &str) -> bool {
+    CREATE_SEQUENCE_REGEX.is_match(query)
+}
+
+/// Whether `query` is a `DROP SEQUENCE` statement.
+pub fn is_drop_sequence(query: &str) -> bool {
+    DROP_SEQUENCE_REGEX.is_match(query)
+}
+
+/// Pull a single numeric option (e.g. `INCREMENT BY 5`, `MAXVALUE 100`) out
+/// of a `CREATE SEQUENCE` option list. Each clause is optional and can
+/// appear in any order, so this is matched independently rather than with
+/// one monolithic pattern. `keyword` is a regex fragment (e.g.
+/// `INCREMENT\s+BY`), so multi-word keywords match any run of whitespace.
+fn parse_sequence_option(options: &str, keyword: &str) -> Option<i64> {
+    let pattern = format!(r"(?i)\b{}\s+(-?\d+)", keyword);
+    Regex::new(&pattern).ok()?.captures(options)?.get(1)?.as_str().parse().ok()
+}
+
+/// Create a standalone sequence: insert its counter state into
+/// `__pgsqlite_sequences` and its catalog row into `pg_sequence` exactly
+/// like `populate_table_dependencies` does for an implicit serial-column
+/// sequence, but with no owning table/column unless `OWNED BY` is given -
+/// `__pgsqlite_sequences.table_name`/`column_name` are `NOT NULL`, so a
+/// standalone sequence stores empty strings there rather than widening that
+/// column to nullable for a case it otherwise never needs.
+pub fn handle_create_sequence(conn: &Connection, query: &str) -> Result<()> {
+    let cap = CREATE_SEQUENCE_REGEX.captures(query)
+        .ok_or_else(|| anyhow::anyhow!("not a CREATE SEQUENCE statement: {query}"))?;
+    let sequence_name = cap.get(1).unwrap().as_str().rsplit('.').next().unwrap().to_string();
+    let options = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+
+    let increment_by = parse_sequence_option(options, "INCREMENT\\s+BY")
+        .or_else(|| parse_sequence_option(options, "INCREMENT"))
+        .unwrap_or(1);
+    let start_value = parse_sequence_option(options, "START\\s+WITH")
+        .or_else(|| parse_sequence_option(options, "START"))
+        .unwrap_or(if increment_by < 0 { -1 } else { 1 });
+    let min_value = parse_sequence_option(options, "MINVALUE")
+        .unwrap_or(if increment_by < 0 { i64::MIN } else { 1 });
+    let max_value = parse_sequence_option(options, "MAXVALUE")
+        .unwrap_or(if increment_by < 0 { -1 } else { i64::MAX });
+    let is_cycle = Regex::new(r"(?i)\bCYCLE\b").unwrap().is_match(options)
+        && !Regex::new(r"(?i)\bNO\s+CYCLE\b").unwrap().is_match(options);
+
+    use crate::utils::oid_registry::{allocate_oid, OidKind};
+    let seq_oid = allocate_oid(conn, OidKind::Sequence, &sequence_name)?.get();
+
+    conn.execute(
+        "INSERT INTO __pgsqlite_sequences (
+            seq_oid, sequence_name, table_name, column_name,
+            last_value, start_value, increment_by, min_value, max_value, is_called
+        ) VALUES (?1, ?2, '', '', ?3, ?3, ?4, ?5, ?6, 0)",
+        rusqlite::params![seq_oid.to_string(), sequence_name, start_value, increment_by, min_value, max_value],
+    )?;
+
+    conn.execute(
+        "INSERT OR REPLACE INTO pg_sequence (
+            seqrelid, seqtypid, seqstart, seqincrement, seqmax, seqmin, seqcache, seqcycle
+        ) VALUES (?1, 20, ?2, ?3, ?4, ?5, 1, ?6)",
+        rusqlite::params![seq_oid.to_string(), start_value, increment_by, max_value, min_value, is_cycle as i32],
+    )?;
+
+    if let Some(owned_by) = SEQUENCE_OWNED_BY_REGEX.captures(options) {
+        let owner_table = owned_by.get(1).unwrap().as_str();
+        let owner_column = owned_by.get(2).unwrap().as_str();
+        let table_oid = generate_table_oid(conn, owner_table)?;
+        let col_cid: i32 = conn.query_row(
+            &format!("SELECT cid FROM pragma_table_info('{owner_table}') WHERE name = ?1"),
+            [owner_column],
+            |row| row.get(0),
+        ).unwrap_or(0);
+
+        conn.execute(
+            "INSERT OR REPLACE INTO pg_depend (classid, objid, objsubid, refclassid, refobjid, refobjsubid, deptype)
+             VALUES ('1259', ?1, 0, '1259', ?2, ?3, 'a')",
+            rusqlite::params![seq_oid.to_string(), table_oid, col_cid + 1],
+        )?;
+    }
+
+    info!("Created sequence: {}", sequence_name);
+    Ok(())
+}
+
+/// Drop a standalone sequence - removes its `__pgsqlite_sequences`,
+/// `pg_sequence`, and `pg_depend` rows. Nothing in this model ever depends
+/// *on* a sequence (dependencies only run sequence -> owning table column),
+/// so unlike `DROP TABLE` there's no CASCADE/RESTRICT distinction to make
+/// here; the clause is accepted but has no effect, matching how PostgreSQL
+/// itself treats `DROP SEQUENCE ... RESTRICT` on a sequence nothing depends on.
+pub fn handle_drop_sequence(conn: &Connection, query: &str) -> Result<()> {
+    let cap = DROP_SEQUENCE_REGEX.captures(query)
+        .ok_or_else(|| anyhow::anyhow!("not a DROP SEQUENCE statement: {query}"))?;
+    let sequence_name = cap.get(1).unwrap().as_str().rsplit('.').next().unwrap();
+
+    let seq_oid: Option<String> = conn.query_row(
+        "SELECT seq_oid FROM __pgsqlite_sequences WHERE sequence_name = ?1",
+        [sequence_name],
+        |row| row.get(0),
+    ).ok();
+
+    conn.execute("DELETE FROM __pgsqlite_sequences WHERE sequence_name = ?1", [sequence_name])?;
+    if let Some(seq_oid) = seq_oid {
+        conn.execute("DELETE FROM pg_sequence WHERE seqrelid = ?1", [&seq_oid])?;
+        conn.execute("DELETE FROM pg_depend WHERE objid = ?1 AND deptype IN ('a', 'i')", [&seq_oid])?;
+    }
+
+    info!("Dropped sequence: {}", sequence_name);
+    Ok(())
+}
+
+/// Whether `table_name` has any object depending on it through a *normal*
+/// ('n') dependency - the only deptype that requires `CASCADE` before
+/// PostgreSQL will drop the referenced relation. 'a' (automatic) and 'i'
+/// (internal) rows, such as the one `populate_table_dependencies` records
+/// for a single-column `INTEGER PRIMARY KEY`'s implicit serial sequence (or
+/// an explicit `CREATE SEQUENCE ... OWNED BY`), are dropped for free right
+/// along with the table - PostgreSQL never makes CASCADE a precondition for
+/// those, so a plain `DROP TABLE` must not either. Used to reject a plain
+/// `DROP TABLE` the way PostgreSQL does, before the drop is ever executed.
+///
+/// The stored `pg_depend` table only ever holds 'a'/'i' rows - a foreign
+/// key's dependency is never written there, since `pg_constraint`/`pg_depend`
+/// re-derive FK rows live from the dependent table's own `CREATE TABLE` text
+/// (see `populate_table_constraints`). So the 'n' case is checked directly
+/// against the schema instead, via [`has_foreign_key_dependents`].
+pub fn table_has_dependents(conn: &Connection, table_name: &str) -> Result<bool> {
+    let table_oid = generate_table_oid(conn, table_name)?;
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pg_depend WHERE refobjid = ?1 AND deptype = 'n'",
+        [&table_oid],
+        |row| row.get(0),
+    )?;
+    if count > 0 {
+        return Ok(true);
+    }
+
+    has_foreign_key_dependents(conn, table_name)
+}
+
+/// Whether any other table's `CREATE TABLE` text declares a foreign key
+/// referencing `table_name`. Shares its matching logic with
+/// [`strip_foreign_keys_referencing`], the function that actually removes
+/// such a foreign key for `DROP TABLE ... CASCADE`.
+fn has_foreign_key_dependents(conn: &Connection, table_name: &str) -> Result<bool> {
+    let mut stmt = conn.prepare(
+        "SELECT sql FROM sqlite_master WHERE type = 'table' AND name != ?1 AND sql IS NOT NULL",
+    )?;
+    let other_sqls: Vec<String> = stmt.query_map([table_name], |row| row.get::<_, String>(0))?
+        .flatten()
+        .collect();
+
+    Ok(other_sqls.iter().any(|create_sql| strip_foreign_keys_referencing(create_sql, table_name).is_some()))
+}
+
+/// Drop every sequence that depends on `table_name` via `pg_depend` (what
+/// `DROP TABLE ... CASCADE` pulls down with it), removing their
+/// `__pgsqlite_sequences`/`pg_sequence`/`pg_depend` rows. Called before the
+/// table itself is dropped, so the dependency rows referencing it still
+/// resolve when this looks them up.
+pub fn drop_dependent_sequences(conn: &Connection, table_name: &str) -> Result<()> {
+    let table_oid = generate_table_oid(conn, table_name)?;
+    let seq_oids: Vec<String> = {
+        let mut stmt = conn.prepare(
+            "SELECT objid FROM pg_depend WHERE refobjid = ?1 AND deptype IN ('a', 'i')",
+        )?;
+        let rows = stmt.query_map([&table_oid], |row| row.get::<_, String>(0))?;
+        rows.flatten().collect()
+    };
+
+    let dropped = seq_oids.len();
+    for seq_oid in seq_oids {
+        conn.execute("DELETE FROM __pgsqlite_sequences WHERE seq_oid = ?1", [&seq_oid])?;
+        conn.execute("DELETE FROM pg_sequence WHERE seqrelid = ?1", [&seq_oid])?;
+        conn.execute("DELETE FROM pg_depend WHERE objid = ?1 AND deptype IN ('a', 'i')", [&seq_oid])?;
+    }
+
+    info!("Dropped {} dependent sequence(s) for table {}", dropped, table_name);
+    Ok(())
+}
+
+/// Drop every foreign key that some other table declares against
+/// `table_name` (what `DROP TABLE ... CASCADE` must pull down with it for a
+/// genuine, `'n'`-deptype dependent), rewriting each dependent table's
+/// schema and rebuilding it in place.
+///
+/// Unlike the owned-sequence case, there is no `pg_depend` row to simply
+/// delete here - a foreign key's `pg_constraint`/`pg_depend` rows are
+/// re-derived from the dependent table's own `CREATE TABLE` text every time
+/// (see `populate_table_constraints`), and SQLite itself has no `ALTER
+/// TABLE ... DROP CONSTRAINT`. So the only way to actually detach the
+/// dependent is to strip its `REFERENCES table_name(...)` clause from its
+/// schema and rebuild the table, the way a manual SQLite migration would.
+/// Returns the names of the dependent tables that were rewritten.
+pub fn drop_foreign_key_dependents(conn: &Connection, table_name: &str) -> Result<Vec<String>> {
+    let other_tables: Vec<String> = {
+        let mut stmt = conn.prepare(
+            "SELECT name FROM sqlite_master WHERE type = 'table' AND name != ?1 AND sql IS NOT NULL",
+        )?;
+        let rows = stmt.query_map([table_name], |row| row.get::<_, String>(0))?;
+        rows.flatten().collect()
+    };
+
+    let mut rebuilt = Vec::new();
+    for child in other_tables {
+        let create_sql = get_create_table_sql(conn, &child)?;
+        if let Some(new_sql) = strip_foreign_keys_referencing(&create_sql, table_name) {
+            rebuild_table_with_sql(conn, &child, &new_sql)?;
+            refresh_constraints_for_table(conn, &child)?;
+            info!("CASCADE: dropped foreign key(s) referencing {} from table {}", table_name, child);
+            rebuilt.push(child);
+        }
+    }
+
+    Ok(rebuilt)
+}
+
+/// Remove any `FOREIGN KEY (...) REFERENCES <ref_table_name>(...)` or inline
+/// `col ... REFERENCES <ref_table_name>(...)` clause from a `CREATE TABLE`
+/// statement's column-def body. Returns `None` if the table declares no
+/// foreign key against `ref_table_name` (nothing to rewrite).
+fn strip_foreign_keys_referencing(create_sql: &str, ref_table_name: &str) -> Option<String> {
+    let body = TABLE_REGEX.captures(create_sql)?.get(1)?;
+    let (body_start, body_end) = (body.start(), body.end());
+    let prefix = &create_sql[..body_start];
+    let suffix = &create_sql[body_end..];
+
+    let mut changed = false;
+    let parts: Vec<String> = split_top_level_commas(body.as_str()).into_iter().filter_map(|part| {
+        let trimmed = part.trim();
+
+        if let Some(cap) = FOREIGN_KEY_REGEX.captures(trimmed)
+            && cap.get(2).is_some_and(|t| t.as_str().eq_ignore_ascii_case(ref_table_name)) {
+                changed = true;
+                return None;
+            }
+
+        if let Some(cap) = BARE_REFERENCES_CLAUSE_REGEX.captures(trimmed)
+            && cap.get(1).is_some_and(|t| t.as_str().eq_ignore_ascii_case(ref_table_name)) {
+                changed = true;
+                let stripped = BARE_REFERENCES_CLAUSE_REGEX.replace(trimmed, "").trim().to_string();
+                return Some(stripped);
+            }
+
+        Some(part.to_string())
+    }).collect();
+
+    if !changed || parts.is_empty() {
+        return None;
+    }
+
+    Some(format!("{prefix}{}{suffix}", parts.join(", ")))
+}
+
+/// Rebuild `table_name` using `new_sql` as its `CREATE TABLE` statement,
+/// following SQLite's standard procedure for changes its `ALTER TABLE`
+/// can't express directly: create the new shape under a scratch name, copy
+/// the data across, drop the old table, rename the scratch table into
+/// place, then recreate whatever indexes it had (SQLite drops them along
+/// with the table; only ones with their own stored `CREATE INDEX` text need
+/// recreating - implicit PK/UNIQUE indexes come back from `new_sql` itself).
+fn rebuild_table_with_sql(conn: &Connection, table_name: &str, new_sql: &str) -> Result<()> {
+    let scratch_name = format!("__pgsqlite_rebuild_{table_name}");
+    let scratch_sql = CREATE_TABLE_NAME_REGEX.replace(new_sql, |caps: &regex::Captures| {
+        format!("{}\"{}\"", &caps[1], scratch_name)
+    }).to_string();
+
+    let index_sqls: Vec<String> = {
+        let mut stmt = conn.prepare(
+            "SELECT sql FROM sqlite_master WHERE type = 'index' AND tbl_name = ?1 AND sql IS NOT NULL",
+        )?;
+        let rows = stmt.query_map([table_name], |row| row.get::<_, String>(0))?;
+        rows.flatten().collect()
+    };
+
+    conn.execute(&scratch_sql, [])?;
+    conn.execute(&format!("INSERT INTO \"{scratch_name}\" SELECT * FROM \"{table_name}\""), [])?;
+    conn.execute(&format!("DROP TABLE \"{table_name}\""), [])?;
+    conn.execute(&format!("ALTER TABLE \"{scratch_name}\" RENAME TO \"{table_name}\""), [])?;
+
+    for index_sql in index_sqls {
+        conn.execute(&index_sql, [])?;
+    }
+
+    Ok(())
 }
\ No newline at end of file
@@ -1,9 +1,17 @@
 use crate::session::db_handler::{DbHandler, DbResponse};
 use crate::PgSqliteError;
-use sqlparser::ast::{Select, SelectItem, Expr};
+use sqlparser::ast::{Select, SelectItem, Expr, Value};
 use tracing::debug;
 use std::collections::HashMap;
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
 use super::where_evaluator::WhereEvaluator;
+use super::constraint_populator;
+
+/// Cached `pg_constraint` introspection pass, rebuilt on first access after
+/// startup or after `invalidate_snapshot` is called, and reused by every
+/// query until then. See `PgConstraintHandler::build_constraint_snapshot`.
+static CONSTRAINT_SNAPSHOT: Lazy<RwLock<Option<ConstraintSnapshot>>> = Lazy::new(|| RwLock::new(None));
 
 pub struct PgConstraintHandler;
 
@@ -55,12 +63,18 @@ impl PgConstraintHandler {
         // Get constraints from SQLite
         let constraints = Self::get_sqlite_constraints(db).await?;
 
-        // Filter based on WHERE clause if present
-        let filtered_constraints = if let Some(ref where_clause) = select.selection {
+        // Filter based on WHERE clause if present. `conrelid`/`confrelid`
+        // are always the table's numeric OID, but ORM-generated queries
+        // filter with `'users'::regclass` - resolve those casts to the
+        // matching numeric literal first so the comparison isn't a string
+        // compare of "users" against "16483".
+        let filtered_constraints = if let Some(where_clause) = &select.selection {
+            let mut where_clause = where_clause.clone();
+            Self::resolve_regclass_casts(&mut where_clause, db);
             let column_mapping = HashMap::new(); // Empty mapping for now
             constraints.into_iter()
                 .filter(|constraint| {
-                    WhereEvaluator::evaluate(where_clause, &Self::constraint_to_map(constraint), &column_mapping)
+                    WhereEvaluator::evaluate(&where_clause, &Self::constraint_to_map(constraint), &column_mapping)
                 })
                 .collect()
         } else {
@@ -88,6 +102,43 @@ impl PgConstraintHandler {
         })
     }
 
+    /// Rewrite `'<name>'::regclass` / `'<name>'::oid` casts found anywhere in
+    /// a WHERE expression tree into the plain numeric OID literal, using the
+    /// same persisted allocator `conrelid`/`confrelid` are computed with, so
+    /// a filter like `conrelid = 'users'::regclass` compares OID to OID
+    /// instead of string to OID.
+    fn resolve_regclass_casts(expr: &mut Expr, db: &DbHandler) {
+        if let Expr::Cast { expr: inner, data_type, .. } = expr {
+            let type_name = data_type.to_string().to_lowercase();
+            if (type_name == "regclass" || type_name == "oid")
+                && let Expr::Value(Value::SingleQuotedString(name)) = inner.as_ref() {
+                    let table_name = name.rsplit('.').next().unwrap_or(name);
+                    let oid = Self::generate_table_oid(db, table_name);
+                    *expr = Expr::Value(Value::Number(oid.to_string(), false));
+                    return;
+                }
+            Self::resolve_regclass_casts(inner, db);
+            return;
+        }
+
+        match expr {
+            Expr::BinaryOp { left, right, .. } => {
+                Self::resolve_regclass_casts(left, db);
+                Self::resolve_regclass_casts(right, db);
+            }
+            Expr::UnaryOp { expr: inner, .. } | Expr::Nested(inner) => {
+                Self::resolve_regclass_casts(inner, db);
+            }
+            Expr::InList { expr: inner, list, .. } => {
+                Self::resolve_regclass_casts(inner, db);
+                for item in list {
+                    Self::resolve_regclass_casts(item, db);
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn extract_requested_columns(select: &Select, all_columns: &[String]) -> Vec<String> {
         // Check for wildcard
         if select.projection.len() == 1
@@ -125,7 +176,35 @@ impl PgConstraintHandler {
         }
     }
 
+    /// Return the cached constraint snapshot, building it once on first use.
+    /// The snapshot is invalidated by `constraint_populator`'s
+    /// populate/remove/refresh hooks whenever DDL changes a table, so this
+    /// is a cheap clone on every call after the first per schema version
+    /// instead of the O(tables × columns) PRAGMA storm `build_constraint_snapshot`
+    /// pays once.
     async fn get_sqlite_constraints(db: &DbHandler) -> Result<Vec<ConstraintInfo>, PgSqliteError> {
+        {
+            let cached = CONSTRAINT_SNAPSHOT.read();
+            if let Some(snapshot) = cached.as_ref() {
+                return Ok(snapshot.constraints.clone());
+            }
+        }
+
+        let snapshot = Self::build_constraint_snapshot(db).await?;
+        let constraints = snapshot.constraints.clone();
+        *CONSTRAINT_SNAPSHOT.write() = Some(snapshot);
+        Ok(constraints)
+    }
+
+    /// Drop the cached constraint snapshot so the next `pg_constraint` query
+    /// re-derives it from the (now-changed) schema. Called from
+    /// `constraint_populator`'s DDL hooks - same place `populate`/`remove`/
+    /// `refresh` already update the static `__pgsqlite_*` catalog tables.
+    pub(crate) fn invalidate_snapshot() {
+        *CONSTRAINT_SNAPSHOT.write() = None;
+    }
+
+    async fn build_constraint_snapshot(db: &DbHandler) -> Result<ConstraintSnapshot, PgSqliteError> {
         let mut constraints = Vec::new();
         let mut constraint_id = 1000; // Start with arbitrary OID
 
@@ -134,43 +213,67 @@ impl PgConstraintHandler {
             "SELECT name FROM sqlite_master WHERE type='table' AND name NOT LIKE 'sqlite_%' AND name NOT LIKE '__pgsqlite_%'"
         ).await?;
 
-
+        // One PRAGMA table_info per table up front, rather than one per FK
+        // column resolved later: this is what turns find_column_position's
+        // per-call round trip into an in-memory lookup against
+        // `column_positions`/`pk_columns_by_table` for the rest of this pass.
+        let mut column_positions: HashMap<(String, String), i32> = HashMap::new();
+        let mut pk_columns_by_table: HashMap<String, Vec<(i32, String)>> = HashMap::new();
         for table_row in &tables_response.rows {
             if let Some(Some(table_name_bytes)) = table_row.first() {
                 let table_name = String::from_utf8_lossy(table_name_bytes).to_string();
-
-                // Get table info to find primary keys and constraints
                 let table_info_response = db.query(&format!("PRAGMA table_info({})", table_name)).await?;
 
-                // Extract primary key constraints
-                let mut pk_columns = Vec::new();
                 for info_row in &table_info_response.rows {
                     if info_row.len() >= 6
                         && let (Some(Some(cid_bytes)), Some(Some(name_bytes)), Some(Some(pk_bytes))) =
                             (info_row.first(), info_row.get(1), info_row.get(5)) {
+                            let column_name = String::from_utf8_lossy(name_bytes).to_string();
+                            let cid = String::from_utf8_lossy(cid_bytes).parse::<i32>().unwrap_or(0);
+                            let position = cid + 1; // PostgreSQL uses 1-based indexing
+                            column_positions.insert((table_name.clone(), column_name.clone()), position);
+
                             let pk_flag = String::from_utf8_lossy(pk_bytes);
                             if pk_flag == "1" {
-                                let column_name = String::from_utf8_lossy(name_bytes).to_string();
-                                let cid = String::from_utf8_lossy(cid_bytes).parse::<i32>().unwrap_or(0);
-                                pk_columns.push((cid + 1, column_name)); // PostgreSQL uses 1-based indexing
+                                pk_columns_by_table.entry(table_name.clone()).or_default().push((position, column_name));
                             }
                         }
                 }
+            }
+        }
+
+        for table_row in &tables_response.rows {
+            if let Some(Some(table_name_bytes)) = table_row.first() {
+                let table_name = String::from_utf8_lossy(table_name_bytes).to_string();
+
+                // Fetch the table's own DDL once; CHECK-clause extraction and
+                // FK DEFERRABLE detection both scan it below.
+                let create_sql_response = db.query(&format!(
+                    "SELECT sql FROM sqlite_master WHERE type='table' AND name = '{}'",
+                    table_name
+                )).await?;
+                let create_sql: Option<String> = create_sql_response.rows.first()
+                    .and_then(|row| row.first())
+                    .and_then(|cell| cell.as_ref())
+                    .map(|sql_bytes| String::from_utf8_lossy(sql_bytes).to_string());
+
+                let mut pk_columns = pk_columns_by_table.get(&table_name).cloned().unwrap_or_default();
 
                 // Create primary key constraint if any columns found
                 if !pk_columns.is_empty() {
                     pk_columns.sort_by_key(|&(cid, _)| cid);
+                    let pkey_name = format!("{}_pkey", table_name);
                     let constraint = ConstraintInfo {
                         oid: constraint_id,
-                        conname: format!("{}_pkey", table_name),
+                        conname: pkey_name.clone(),
                         connamespace: 2200, // public schema OID
                         contype: 'p',
                         condeferrable: false,
                         condeferred: false,
                         convalidated: true,
-                        conrelid: Self::generate_table_oid(&table_name),
+                        conrelid: Self::generate_table_oid(db, &table_name),
                         contypid: 0,
-                        conindid: constraint_id + 1000, // Arbitrary index OID
+                        conindid: Self::generate_index_oid(db, &pkey_name),
                         conparentid: 0,
                         confrelid: 0,
                         confupdtype: ' ',
@@ -181,87 +284,393 @@ impl PgConstraintHandler {
                         connoinherit: true,
                         conkey: pk_columns.iter().map(|&(cid, _)| cid).collect(),
                         confkey: vec![],
+                        consrc: None,
                         table_name: table_name.clone(),
                     };
                     constraints.push(constraint);
                     constraint_id += 1;
                 }
 
-                // Get foreign key constraints
-                let fk_response = db.query(&format!("PRAGMA foreign_key_list({})", table_name)).await?;
+                // Get UNIQUE constraints (inline UNIQUE columns and table-level
+                // UNIQUE (...) clauses both surface here as indexes with
+                // origin='u'; a CREATE UNIQUE INDEX has origin='c' and is skipped)
+                let index_list_response = db.query(&format!("PRAGMA index_list({})", table_name)).await?;
 
-                for fk_row in &fk_response.rows {
-                    if fk_row.len() >= 8
-                        && let (Some(Some(id_bytes)), Some(Some(table_bytes)), Some(Some(from_bytes)), Some(Some(to_bytes))) =
-                            (fk_row.first(), fk_row.get(2), fk_row.get(3), fk_row.get(4)) {
+                for index_row in &index_list_response.rows {
+                    if index_row.len() >= 4
+                        && let (Some(Some(name_bytes)), Some(Some(origin_bytes))) =
+                            (index_row.get(1), index_row.get(3)) {
+                            let index_name = String::from_utf8_lossy(name_bytes).to_string();
+                            let origin = String::from_utf8_lossy(origin_bytes).to_string();
 
-                            let fk_id = String::from_utf8_lossy(id_bytes);
-                            let ref_table = String::from_utf8_lossy(table_bytes).to_string();
-                            let from_column = String::from_utf8_lossy(from_bytes).to_string();
-                            let to_column = String::from_utf8_lossy(to_bytes).to_string();
+                            if origin != "u" {
+                                continue;
+                            }
 
-                            // Find column position
-                            let from_col_pos = Self::find_column_position(db, &table_name, &from_column).await.unwrap_or(1);
-                            let to_col_pos = Self::find_column_position(db, &ref_table, &to_column).await.unwrap_or(1);
+                            let index_info_response = db.query(&format!("PRAGMA index_info({})", index_name)).await?;
+                            let mut conkey = Vec::new();
+                            let mut columns = Vec::new();
+                            for info_row in &index_info_response.rows {
+                                if info_row.len() >= 3
+                                    && let Some(Some(col_name_bytes)) = info_row.get(2) {
+                                        let column_name = String::from_utf8_lossy(col_name_bytes).to_string();
+                                        let col_pos = column_positions.get(&(table_name.clone(), column_name.clone())).copied().unwrap_or(1);
+                                        conkey.push(col_pos);
+                                        columns.push(column_name);
+                                    }
+                            }
+
+                            // SQLite names the implicit index for an inline UNIQUE
+                            // column "sqlite_autoindex_<table>_<n>"; fall back to the
+                            // repo's "{table}_{cols}_key" convention in that case so
+                            // conname still reads like a PostgreSQL-generated one.
+                            let conname = if index_name.starts_with("sqlite_autoindex_") {
+                                format!("{}_{}_key", table_name, columns.join("_"))
+                            } else {
+                                index_name.clone()
+                            };
 
                             let constraint = ConstraintInfo {
                                 oid: constraint_id,
-                                conname: format!("{}_{}_{}_fkey", table_name, from_column, fk_id),
+                                conname,
                                 connamespace: 2200, // public schema OID
-                                contype: 'f',
+                                contype: 'u',
                                 condeferrable: false,
                                 condeferred: false,
                                 convalidated: true,
-                                conrelid: Self::generate_table_oid(&table_name),
+                                conrelid: Self::generate_table_oid(db, &table_name),
                                 contypid: 0,
-                                conindid: 0,
+                                conindid: Self::generate_index_oid(db, &index_name),
                                 conparentid: 0,
-                                confrelid: Self::generate_table_oid(&ref_table),
-                                confupdtype: 'a', // NO ACTION (default)
-                                confdeltype: 'a', // NO ACTION (default)
-                                confmatchtype: 's', // SIMPLE (default)
+                                confrelid: 0,
+                                confupdtype: ' ',
+                                confdeltype: ' ',
+                                confmatchtype: ' ',
                                 conislocal: true,
                                 coninhcount: 0,
                                 connoinherit: true,
-                                conkey: vec![from_col_pos],
-                                confkey: vec![to_col_pos],
+                                conkey,
+                                confkey: vec![],
+                                consrc: None,
                                 table_name: table_name.clone(),
                             };
                             constraints.push(constraint);
                             constraint_id += 1;
                         }
                 }
+
+                // Get CHECK constraints by scanning the table's own DDL text,
+                // since SQLite doesn't expose them through any PRAGMA
+                if let Some(create_sql) = &create_sql {
+                    let mut used_check_names: HashMap<String, i32> = HashMap::new();
+                    for check in Self::parse_check_constraints(create_sql) {
+                        let conkey = if let Some(column_name) = &check.column_name {
+                            vec![column_positions.get(&(table_name.clone(), column_name.clone())).copied().unwrap_or(1)]
+                        } else {
+                            vec![]
+                        };
+
+                        // PostgreSQL names a single-column CHECK
+                        // `<table>_<col>_check` and a table-level (or
+                        // otherwise unattributed) one `<table>_check`,
+                        // appending a numeric suffix on a collision (e.g. a
+                        // second CHECK on the same column).
+                        let base_name = match &check.column_name {
+                            Some(column_name) => format!("{table_name}_{column_name}_check"),
+                            None => format!("{table_name}_check"),
+                        };
+                        let seen = used_check_names.entry(base_name.clone()).or_insert(0);
+                        *seen += 1;
+                        let conname = if *seen == 1 { base_name } else { format!("{base_name}{}", *seen - 1) };
+
+                        let constraint = ConstraintInfo {
+                            oid: constraint_id,
+                            conname,
+                            connamespace: 2200, // public schema OID
+                            contype: 'c',
+                            condeferrable: false,
+                            condeferred: false,
+                            convalidated: true,
+                            conrelid: Self::generate_table_oid(db, &table_name),
+                            contypid: 0,
+                            conindid: 0,
+                            conparentid: 0,
+                            confrelid: 0,
+                            confupdtype: ' ',
+                            confdeltype: ' ',
+                            confmatchtype: ' ',
+                            conislocal: true,
+                            coninhcount: 0,
+                            connoinherit: true,
+                            conkey,
+                            confkey: vec![],
+                            consrc: Some(check.expr),
+                            table_name: table_name.clone(),
+                        };
+                        constraints.push(constraint);
+                        constraint_id += 1;
+                    }
+                }
+
+                // Get foreign key constraints. A multi-column FK comes back
+                // from SQLite as multiple rows sharing one `id` (one per
+                // `seq`), so group them before building a ConstraintInfo.
+                let fk_response = db.query(&format!("PRAGMA foreign_key_list({})", table_name)).await?;
+
+                let mut fk_groups: Vec<ForeignKeyGroup> = Vec::new();
+                for fk_row in &fk_response.rows {
+                    if fk_row.len() >= 8
+                        && let (Some(Some(id_bytes)), Some(Some(table_bytes)), Some(Some(from_bytes)), Some(Some(to_bytes))) =
+                            (fk_row.first(), fk_row.get(2), fk_row.get(3), fk_row.get(4)) {
+
+                            let fk_id = String::from_utf8_lossy(id_bytes).to_string();
+                            let ref_table = String::from_utf8_lossy(table_bytes).to_string();
+                            let from_column = String::from_utf8_lossy(from_bytes).to_string();
+                            let to_column = String::from_utf8_lossy(to_bytes).to_string();
+                            let on_update = fk_row.get(5).and_then(|c| c.as_ref())
+                                .map(|b| String::from_utf8_lossy(b).to_string()).unwrap_or_default();
+                            let on_delete = fk_row.get(6).and_then(|c| c.as_ref())
+                                .map(|b| String::from_utf8_lossy(b).to_string()).unwrap_or_default();
+                            let match_type = fk_row.get(7).and_then(|c| c.as_ref())
+                                .map(|b| String::from_utf8_lossy(b).to_string()).unwrap_or_default();
+
+                            match fk_groups.iter_mut().find(|g| g.fk_id == fk_id) {
+                                Some(group) => {
+                                    group.from_columns.push(from_column);
+                                    group.to_columns.push(to_column);
+                                }
+                                None => fk_groups.push(ForeignKeyGroup {
+                                    fk_id,
+                                    ref_table,
+                                    from_columns: vec![from_column],
+                                    to_columns: vec![to_column],
+                                    on_update,
+                                    on_delete,
+                                    match_type,
+                                }),
+                            }
+                        }
+                }
+
+                for group in fk_groups {
+                    let mut conkey = Vec::new();
+                    for from_column in &group.from_columns {
+                        conkey.push(column_positions.get(&(table_name.clone(), from_column.clone())).copied().unwrap_or(1));
+                    }
+                    let mut confkey = Vec::new();
+                    for to_column in &group.to_columns {
+                        confkey.push(column_positions.get(&(group.ref_table.clone(), to_column.clone())).copied().unwrap_or(1));
+                    }
+
+                    let (condeferrable, condeferred) = create_sql.as_deref()
+                        .map(|sql| Self::parse_fk_deferrable(sql, &group.ref_table, &group.from_columns[0]))
+                        .unwrap_or((false, false));
+
+                    let constraint = ConstraintInfo {
+                        oid: constraint_id,
+                        conname: format!("{}_{}_{}_fkey", table_name, group.from_columns[0], group.fk_id),
+                        connamespace: 2200, // public schema OID
+                        contype: 'f',
+                        condeferrable,
+                        condeferred,
+                        convalidated: true,
+                        conrelid: Self::generate_table_oid(db, &table_name),
+                        contypid: 0,
+                        conindid: 0,
+                        conparentid: 0,
+                        confrelid: Self::generate_table_oid(db, &group.ref_table),
+                        confupdtype: constraint_populator::fk_action_code(&group.on_update),
+                        confdeltype: constraint_populator::fk_action_code(&group.on_delete),
+                        confmatchtype: constraint_populator::fk_match_code(&group.match_type),
+                        conislocal: true,
+                        coninhcount: 0,
+                        connoinherit: true,
+                        conkey,
+                        confkey,
+                        consrc: None,
+                        table_name: table_name.clone(),
+                    };
+                    constraints.push(constraint);
+                    constraint_id += 1;
+                }
             }
         }
 
-        Ok(constraints)
+        Ok(ConstraintSnapshot { constraints, column_positions })
+    }
+
+    /// Look up a column's 1-based PostgreSQL attnum from the cached
+    /// snapshot, for sibling catalog handlers (e.g. a future pg_attribute
+    /// handler) that need the same table/column -> position mapping this
+    /// module already builds. Triggers a snapshot build on first use, same
+    /// as `get_sqlite_constraints`.
+    #[allow(dead_code)]
+    pub(crate) async fn column_position(db: &DbHandler, table_name: &str, column_name: &str) -> Result<Option<i32>, PgSqliteError> {
+        {
+            let cached = CONSTRAINT_SNAPSHOT.read();
+            if let Some(snapshot) = cached.as_ref() {
+                return Ok(snapshot.column_positions.get(&(table_name.to_string(), column_name.to_string())).copied());
+            }
+        }
+
+        let snapshot = Self::build_constraint_snapshot(db).await?;
+        let position = snapshot.column_positions.get(&(table_name.to_string(), column_name.to_string())).copied();
+        *CONSTRAINT_SNAPSHOT.write() = Some(snapshot);
+        Ok(position)
+    }
+
+    /// Scan a `CREATE TABLE` statement's body for top-level `CHECK (...)`
+    /// clauses, balancing parentheses to capture the full expression rather
+    /// than stopping at the first `)`. A clause found inside a column
+    /// definition (the def doesn't start with a table-constraint keyword)
+    /// is attributed to that column; a clause on its own `CONSTRAINT`/`CHECK`
+    /// line is table-level and gets an empty `conkey`.
+    fn parse_check_constraints(create_sql: &str) -> Vec<CheckConstraint> {
+        let mut checks = Vec::new();
+
+        let Some(open) = create_sql.find('(') else { return checks };
+        let body = match Self::matching_paren(create_sql, open) {
+            Some(close) => &create_sql[open + 1..close],
+            None => return checks,
+        };
+
+        for item in Self::split_top_level(body) {
+            let trimmed = item.trim();
+            let Some(check_pos) = Self::find_keyword(trimmed, "CHECK") else { continue };
+
+            let Some(expr_open) = trimmed[check_pos..].find('(').map(|p| check_pos + p) else { continue };
+            let Some(expr_close) = Self::matching_paren(trimmed, expr_open) else { continue };
+            let expr = trimmed[expr_open + 1..expr_close].trim().to_string();
+
+            let is_table_level = ["CONSTRAINT", "CHECK", "UNIQUE", "PRIMARY", "FOREIGN"]
+                .iter()
+                .any(|kw| Self::find_keyword(trimmed, kw) == Some(0));
+
+            let column_name = if is_table_level {
+                None
+            } else {
+                trimmed.split_whitespace().next().map(|c| c.trim_matches(['"', '\'', '`', '[', ']']).to_string())
+            };
+
+            checks.push(CheckConstraint { column_name, expr });
+        }
+
+        checks
     }
 
-    async fn find_column_position(db: &DbHandler, table_name: &str, column_name: &str) -> Result<i32, PgSqliteError> {
-        let table_info = db.query(&format!("PRAGMA table_info({})", table_name)).await?;
+    /// Find the `FOREIGN KEY ... REFERENCES <ref_table>(...)` clause for
+    /// `from_column` in `create_sql` and report whether it carries
+    /// `DEFERRABLE` / `INITIALLY DEFERRED`. SQLite has no PRAGMA for this,
+    /// so - same as `parse_check_constraints` - it's read straight off the
+    /// table's own DDL text.
+    fn parse_fk_deferrable(create_sql: &str, ref_table: &str, from_column: &str) -> (bool, bool) {
+        let Some(open) = create_sql.find('(') else { return (false, false) };
+        let Some(close) = Self::matching_paren(create_sql, open) else { return (false, false) };
+        let body = &create_sql[open + 1..close];
+
+        for item in Self::split_top_level(body) {
+            let upper = item.to_uppercase();
+            if !upper.contains("REFERENCES") || !upper.contains(&ref_table.to_uppercase()) {
+                continue;
+            }
+            if Self::find_keyword(item, from_column).is_none() {
+                continue;
+            }
+
+            let deferrable = Self::find_keyword(&upper, "DEFERRABLE").is_some();
+            let not_deferrable = Self::find_keyword(&upper, "NOT DEFERRABLE").is_some();
+            let condeferrable = deferrable && !not_deferrable;
+            let condeferred = condeferrable && Self::find_keyword(&upper, "INITIALLY DEFERRED").is_some();
+            return (condeferrable, condeferred);
+        }
+
+        (false, false)
+    }
+
+    /// Split `s` on top-level commas only, skipping over commas nested
+    /// inside parentheses (e.g. the argument list of a `CHECK` expression).
+    fn split_top_level(s: &str) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut depth = 0i32;
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    parts.push(&s[start..i]);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        parts.push(&s[start..]);
+        parts
+    }
 
-        for (idx, row) in table_info.rows.iter().enumerate() {
-            if row.len() >= 2
-                && let Some(Some(name_bytes)) = row.get(1) {
-                    let name = String::from_utf8_lossy(name_bytes);
-                    if name == column_name {
-                        return Ok((idx + 1) as i32); // PostgreSQL uses 1-based indexing
+    /// Find the index of `open` in `s`'s matching closing parenthesis,
+    /// accounting for nesting.
+    fn matching_paren(s: &str, open: usize) -> Option<usize> {
+        let mut depth = 0i32;
+        for (i, c) in s.char_indices().skip_while(|&(i, _)| i < open) {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(i);
                     }
                 }
+                _ => {}
+            }
         }
+        None
+    }
 
-        Ok(1) // Default fallback
+    /// Find a case-insensitive, word-boundary match of `keyword` in `s`,
+    /// returning its byte offset.
+    fn find_keyword(s: &str, keyword: &str) -> Option<usize> {
+        let upper = s.to_uppercase();
+        let keyword = keyword.to_uppercase();
+        let mut search_from = 0;
+        while let Some(rel) = upper[search_from..].find(&keyword) {
+            let pos = search_from + rel;
+            let before_ok = pos == 0 || !upper.as_bytes()[pos - 1].is_ascii_alphanumeric();
+            let after = pos + keyword.len();
+            let after_ok = after >= upper.len() || !upper.as_bytes()[after].is_ascii_alphanumeric();
+            if before_ok && after_ok {
+                return Some(pos);
+            }
+            search_from = pos + keyword.len();
+        }
+        None
     }
 
-    fn generate_table_oid(table_name: &str) -> u32 {
-        // Generate deterministic OID from table name (same as pg_class handler)
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
+    /// Resolve a table's OID through the same persisted
+    /// `__pgsqlite_oid_registry` allocator `pg_class` does, so
+    /// `conrelid`/`confrelid` here actually join against it. This used to be
+    /// a local `DefaultHasher`-based hash that had no relation to the
+    /// formula the `pg_class` view recomputed in plain SQL, so the join
+    /// silently matched nothing.
+    fn generate_table_oid(db: &DbHandler, table_name: &str) -> u32 {
+        use crate::utils::oid_registry::{allocate_oid, OidKind};
+        match db.get_mut_connection() {
+            Ok(conn) => allocate_oid(&conn, OidKind::Table, table_name).map(|oid| oid.get()).unwrap_or(0),
+            Err(_) => 0,
+        }
+    }
 
-        let mut hasher = DefaultHasher::new();
-        table_name.hash(&mut hasher);
-        let hash = hasher.finish();
-        16384 + ((hash % 65536) as u32) // Keep OIDs in reasonable range
+    /// Resolve `name`'s OID through the centralized `__pgsqlite_oid_registry`
+    /// (`crate::utils::oid_registry`) in the `Index` range, so `conindid`
+    /// lands on a value stable and collision-free across tables, sequences,
+    /// and other indexes - unlike the old `constraint_id + 1000` arbitrary
+    /// offset, which matched nothing in any pg_index emulation.
+    fn generate_index_oid(db: &DbHandler, name: &str) -> u32 {
+        use crate::utils::oid_registry::{allocate_oid, OidKind};
+        match db.get_mut_connection() {
+            Ok(conn) => allocate_oid(&conn, OidKind::Index, name).map(|oid| oid.get()).unwrap_or(0),
+            Err(_) => 0,
+        }
     }
 
     fn constraint_to_row(constraint: &ConstraintInfo) -> Vec<Option<Vec<u8>>> {
@@ -291,7 +700,7 @@ impl PgConstraintHandler {
             None,                                                            // conffeqop
             None,                                                            // confdelsetcols
             None,                                                            // conexclop
-            None,                                                            // conbin
+            constraint.consrc.clone().map(|s| s.into_bytes()),                // conbin (CHECK expression text)
         ]
     }
 
@@ -315,6 +724,16 @@ impl PgConstraintHandler {
     }
 }
 
+/// The cached result of one full introspection pass: every constraint row,
+/// plus the (table, column) -> 1-based attnum map computed as a side effect
+/// of resolving PK/FK/UNIQUE column positions. Rebuilt wholesale on
+/// invalidation rather than patched incrementally, since a schema change can
+/// touch the numbering of any table.
+struct ConstraintSnapshot {
+    constraints: Vec<ConstraintInfo>,
+    column_positions: HashMap<(String, String), i32>,
+}
+
 #[derive(Clone)]
 struct ConstraintInfo {
     oid: u32,
@@ -337,6 +756,30 @@ struct ConstraintInfo {
     connoinherit: bool,
     conkey: Vec<i32>,
     confkey: Vec<i32>,
+    /// Raw `CHECK (...)` expression text, surfaced as `conbin`. `None` for
+    /// every other constraint type.
+    consrc: Option<String>,
     #[allow(dead_code)]
     table_name: String,
+}
+
+/// A single `CHECK` clause parsed out of a table's DDL: the raw expression
+/// text, and the column it's attached to when it's a column-level check
+/// (`None` for a table-level `CHECK`/`CONSTRAINT ... CHECK` clause).
+struct CheckConstraint {
+    column_name: Option<String>,
+    expr: String,
+}
+
+/// The columns of a single (possibly multi-column) foreign key, assembled
+/// from the one-row-per-column output of `PRAGMA foreign_key_list` by
+/// grouping on its `id` field.
+struct ForeignKeyGroup {
+    fk_id: String,
+    ref_table: String,
+    from_columns: Vec<String>,
+    to_columns: Vec<String>,
+    on_update: String,
+    on_delete: String,
+    match_type: String,
 }
\ No newline at end of file
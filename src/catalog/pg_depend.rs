@@ -1,16 +1,40 @@
 use crate::session::db_handler::{DbHandler, DbResponse};
 use crate::PgSqliteError;
-use sqlparser::ast::{Select, SelectItem, Expr};
+use sqlparser::ast::{Select, SelectItem, Expr, BinaryOperator};
+use sqlparser::tokenizer::{Location, Span};
 use tracing::debug;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use uuid::Uuid;
 use super::where_evaluator::WhereEvaluator;
+use super::constraint_populator::generate_constraint_oid;
 
 pub struct PgDependHandler;
 
+#[derive(Default)]
+struct DependPredicateHints {
+    classid: Option<Vec<u32>>,
+    objid: Option<Vec<u32>>,
+    refclassid: Option<Vec<u32>>,
+    refobjid: Option<Vec<u32>>,
+    deptype: Option<Vec<char>>,
+}
+
+/// A WHERE-clause leaf's value, typed the way pg_depend's own columns are
+/// (oid, int4, or char) rather than as a string, so comparisons like
+/// `objid > 10000` or `deptype != 'a'` run as the numeric/char comparison
+/// Postgres would do instead of a lexical string compare.
+#[derive(PartialEq, PartialOrd)]
+enum TypedValue {
+    Oid(u32),
+    Int(i32),
+    Char(char),
+}
+
 impl PgDependHandler {
     pub async fn handle_query(
         select: &Select,
         db: &DbHandler,
+        session_id: Option<Uuid>,
     ) -> Result<DbResponse, PgSqliteError> {
         debug!("Handling pg_depend query");
 
@@ -33,15 +57,47 @@ impl PgDependHandler {
             .filter_map(|col| all_columns.iter().position(|c| c == col))
             .collect();
 
-        // Get dependencies from pg_depend table
-        let dependencies = Self::get_dependencies_from_table(db).await?;
+        // Pull out whatever WHERE-clause shape we recognize (refobjid/objid/
+        // classid equality or IN-lists, deptype equality) so synthesis can
+        // skip whole categories or tables it already knows can't match,
+        // rather than always computing every dependency and filtering in
+        // memory afterward. Translation is purely a pruning hint - it only
+        // narrows, never decides a match - so an un-translatable WHERE
+        // clause (or one this pass under-constrains) is still handled
+        // correctly by the WhereEvaluator pass below.
+        let hints = select.selection.as_ref()
+            .map(Self::extract_predicate_hints)
+            .unwrap_or_default();
+
+        // Derive dependencies from the live SQLite schema rather than reading
+        // the static pg_depend table (that table still exists and is kept in
+        // sync for catalog JOINs, but a single-relation SELECT should reflect
+        // the schema as it stands right now, including changes made earlier
+        // in the same session that haven't round-tripped through a writer).
+        let dependencies = Self::synthesize_dependencies(db, session_id, &hints).await?;
+
+        // Filter based on WHERE clause if present. classid/objid/refclassid/
+        // refobjid/objsubid/refobjsubid are all numeric and deptype is a
+        // single char, but dependency_to_map stringifies everything for
+        // WhereEvaluator - fine for equality, wrong for `>`/`<` ("9" sorts
+        // after "10000") and for an ORM's `'users'::regclass` cast, which
+        // would otherwise compare a table name against a stringified OID
+        // and never match. Resolve regclass/oid casts to the OID they name
+        // and try the typed comparisons below first; only an expression
+        // shape neither recognizes still falls back to the plain string
+        // WhereEvaluator pass, unchanged from before.
+        let filtered_dependencies = if let Some(where_clause) = &select.selection {
+            let table_oids = Self::build_table_oid_map(db, session_id).await?;
+            let mut where_clause = where_clause.clone();
+            Self::resolve_regclass_casts(&mut where_clause, &table_oids);
 
-        // Filter based on WHERE clause if present
-        let filtered_dependencies = if let Some(ref where_clause) = select.selection {
             let column_mapping = HashMap::new(); // Empty mapping for now
             dependencies.into_iter()
                 .filter(|dependency| {
-                    WhereEvaluator::evaluate(where_clause, &Self::dependency_to_map(dependency), &column_mapping)
+                    match Self::evaluate_typed(dependency, &where_clause) {
+                        Some(matched) => matched,
+                        None => WhereEvaluator::evaluate(&where_clause, &Self::dependency_to_map(dependency), &column_mapping),
+                    }
                 })
                 .collect()
         } else {
@@ -106,33 +162,482 @@ impl PgDependHandler {
         }
     }
 
-    async fn get_dependencies_from_table(db: &DbHandler) -> Result<Vec<DependencyInfo>, PgSqliteError> {
-        let response = db.query("SELECT classid, objid, objsubid, refclassid, refobjid, refobjsubid, deptype FROM pg_depend").await?;
+    /// Pruning hints pulled out of a WHERE clause: a `None` field is
+    /// unconstrained (every value is possible); a `Some(values)` field means
+    /// only those values can match, found either from a plain equality or
+    /// an `IN (...)` list. Multiple conditions on the same column intersect.
+    /// This is deliberately conservative - an expression this translator
+    /// can't interpret (an OR, a computed comparison, ...) just leaves the
+    /// corresponding field unconstrained instead of guessing.
+    fn extract_predicate_hints(expr: &Expr) -> DependPredicateHints {
+        let mut leaves = Vec::new();
+        Self::collect_and_leaves(expr, &mut leaves);
 
-        let mut dependencies = Vec::new();
+        let mut hints = DependPredicateHints::default();
+        for leaf in leaves {
+            Self::apply_predicate_leaf(leaf, &mut hints);
+        }
+        hints
+    }
+
+    /// Walk a WHERE expression, descending through top-level ANDs (and
+    /// parens) and collecting everything else as an opaque leaf. An OR is
+    /// never descended into - since either side could independently match,
+    /// treating it as a leaf and leaving it unconstrained is the only safe
+    /// option for a pure pruning hint.
+    fn collect_and_leaves<'a>(expr: &'a Expr, out: &mut Vec<&'a Expr>) {
+        match expr {
+            Expr::BinaryOp { left, op: BinaryOperator::And, right } => {
+                Self::collect_and_leaves(left, out);
+                Self::collect_and_leaves(right, out);
+            }
+            Expr::Nested(inner) => Self::collect_and_leaves(inner, out),
+            _ => out.push(expr),
+        }
+    }
+
+    fn apply_predicate_leaf(expr: &Expr, hints: &mut DependPredicateHints) {
+        match expr {
+            Expr::BinaryOp { left, op: BinaryOperator::Eq, right } => {
+                if let Expr::Identifier(ident) = left.as_ref()
+                    && let Some(value) = Self::literal_str(right) {
+                        Self::merge_column_hint(hints, &ident.value.to_lowercase(), vec![value]);
+                    }
+            }
+            Expr::InList { expr: inner, list, negated: false } => {
+                if let Expr::Identifier(ident) = inner.as_ref() {
+                    let values: Vec<String> = list.iter().filter_map(Self::literal_str).collect();
+                    if values.len() == list.len() {
+                        Self::merge_column_hint(hints, &ident.value.to_lowercase(), values);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn literal_str(expr: &Expr) -> Option<String> {
+        if let Expr::Value(value_with_span) = expr {
+            match &value_with_span.value {
+                sqlparser::ast::Value::Number(n, _) => Some(n.clone()),
+                sqlparser::ast::Value::SingleQuotedString(s) => Some(s.clone()),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    fn merge_column_hint(hints: &mut DependPredicateHints, column: &str, values: Vec<String>) {
+        match column {
+            "deptype" => {
+                let chars: Vec<char> = values.iter().filter_map(|v| v.chars().next()).collect();
+                hints.deptype = Some(Self::intersect(hints.deptype.take(), chars));
+            }
+            "classid" => Self::merge_oid_hint(&mut hints.classid, &values),
+            "objid" => Self::merge_oid_hint(&mut hints.objid, &values),
+            "refclassid" => Self::merge_oid_hint(&mut hints.refclassid, &values),
+            "refobjid" => Self::merge_oid_hint(&mut hints.refobjid, &values),
+            _ => {}
+        }
+    }
+
+    fn merge_oid_hint(existing: &mut Option<Vec<u32>>, values: &[String]) {
+        let parsed: Vec<u32> = values.iter().filter_map(|v| v.parse::<u32>().ok()).collect();
+        if parsed.len() != values.len() {
+            return; // Non-numeric comparison against an OID column - not ours to narrow.
+        }
+        *existing = Some(Self::intersect(existing.take(), parsed));
+    }
+
+    fn intersect<T: PartialEq>(existing: Option<Vec<T>>, new_values: Vec<T>) -> Vec<T> {
+        match existing {
+            Some(old) => old.into_iter().filter(|v| new_values.contains(v)).collect(),
+            None => new_values,
+        }
+    }
+
+    /// Rewrite `'<name>'::regclass` / `'<name>'::oid` casts found anywhere
+    /// in a WHERE expression tree into the plain numeric OID literal the
+    /// name resolves to, the same transform `pg_constraint` applies to
+    /// `conrelid = 'users'::regclass` filters - otherwise the comparison is
+    /// a string compare of "users" against a stringified OID and never
+    /// matches.
+    fn resolve_regclass_casts(expr: &mut Expr, table_oids: &HashMap<String, u32>) {
+        if let Expr::Cast { expr: inner, data_type, .. } = expr {
+            let type_name = data_type.to_string().to_lowercase();
+            if (type_name == "regclass" || type_name == "oid")
+                && let Expr::Value(value_with_span) = inner.as_ref()
+                && let sqlparser::ast::Value::SingleQuotedString(name) = &value_with_span.value {
+                    let oid = Self::resolve_regclass_name(name, table_oids);
+                    *expr = Expr::Value(sqlparser::ast::ValueWithSpan {
+                        value: sqlparser::ast::Value::Number(oid.to_string(), false),
+                        span: Span { start: Location { line: 1, column: 1 }, end: Location { line: 1, column: 1 } },
+                    });
+                    return;
+                }
+            Self::resolve_regclass_casts(inner, table_oids);
+            return;
+        }
+
+        match expr {
+            Expr::BinaryOp { left, right, .. } => {
+                Self::resolve_regclass_casts(left, table_oids);
+                Self::resolve_regclass_casts(right, table_oids);
+            }
+            Expr::UnaryOp { expr: inner, .. } | Expr::Nested(inner) => {
+                Self::resolve_regclass_casts(inner, table_oids);
+            }
+            Expr::InList { expr: inner, list, .. } => {
+                Self::resolve_regclass_casts(inner, table_oids);
+                for item in list {
+                    Self::resolve_regclass_casts(item, table_oids);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Resolve a regclass name to the OID pgsqlite assigns it: the
+    /// well-known catalogs pg_depend's own classid/refclassid values
+    /// already point at, or failing that a user table via the same
+    /// registry `resolve_table_oid` reads.
+    fn resolve_regclass_name(name: &str, table_oids: &HashMap<String, u32>) -> u32 {
+        let unqualified = name.rsplit('.').next().unwrap_or(name);
+        match unqualified {
+            "pg_class" => 1259,
+            "pg_constraint" => 2606,
+            "pg_attribute" => 1249,
+            "pg_depend" => 2608,
+            other => table_oids.get(other).copied().unwrap_or(0),
+        }
+    }
+
+    fn typed_column_value(dependency: &DependencyInfo, column: &str) -> Option<TypedValue> {
+        match column {
+            "classid" => Some(TypedValue::Oid(dependency.classid)),
+            "objid" => Some(TypedValue::Oid(dependency.objid)),
+            "refclassid" => Some(TypedValue::Oid(dependency.refclassid)),
+            "refobjid" => Some(TypedValue::Oid(dependency.refobjid)),
+            "objsubid" => Some(TypedValue::Int(dependency.objsubid)),
+            "refobjsubid" => Some(TypedValue::Int(dependency.refobjsubid)),
+            "deptype" => Some(TypedValue::Char(dependency.deptype)),
+            _ => None,
+        }
+    }
+
+    /// Interpret a literal the way the column it's being compared against
+    /// is typed - an oid/int4 column expects a number (regclass casts are
+    /// already rewritten to one by `resolve_regclass_casts` before this
+    /// runs), deptype expects a single-character string.
+    fn typed_literal(column: &str, expr: &Expr) -> Option<TypedValue> {
+        match column {
+            "classid" | "objid" | "refclassid" | "refobjid" => {
+                Self::literal_str(expr)?.parse::<u32>().ok().map(TypedValue::Oid)
+            }
+            "objsubid" | "refobjsubid" => {
+                Self::literal_str(expr)?.parse::<i32>().ok().map(TypedValue::Int)
+            }
+            "deptype" => Self::literal_str(expr)?.chars().next().map(TypedValue::Char),
+            _ => None,
+        }
+    }
+
+    /// Evaluate a WHERE expression directly against a dependency's typed
+    /// fields, understanding AND/OR/NOT, equality, ordering comparisons,
+    /// and `IN` lists. Returns `None` as soon as any part of the tree isn't
+    /// one of those recognized shapes, so the caller can fall back to the
+    /// plain string-based `WhereEvaluator` for the whole expression rather
+    /// than guessing at a partial match.
+    fn evaluate_typed(dependency: &DependencyInfo, expr: &Expr) -> Option<bool> {
+        match expr {
+            Expr::Nested(inner) => Self::evaluate_typed(dependency, inner),
+            Expr::BinaryOp { left, op: BinaryOperator::And, right } => {
+                Some(Self::evaluate_typed(dependency, left)? && Self::evaluate_typed(dependency, right)?)
+            }
+            Expr::BinaryOp { left, op: BinaryOperator::Or, right } => {
+                Some(Self::evaluate_typed(dependency, left)? || Self::evaluate_typed(dependency, right)?)
+            }
+            Expr::UnaryOp { op: sqlparser::ast::UnaryOperator::Not, expr: inner } => {
+                Self::evaluate_typed(dependency, inner).map(|matched| !matched)
+            }
+            Expr::InList { expr: inner, list, negated } => {
+                let Expr::Identifier(ident) = inner.as_ref() else { return None };
+                let column = ident.value.to_lowercase();
+                let column_value = Self::typed_column_value(dependency, &column)?;
+                let mut found = false;
+                for item in list {
+                    if Self::typed_literal(&column, item)? == column_value {
+                        found = true;
+                    }
+                }
+                Some(found != *negated)
+            }
+            Expr::BinaryOp { left, op, right } => {
+                let (column, literal_expr, flipped) = match (left.as_ref(), right.as_ref()) {
+                    (Expr::Identifier(ident), _) => (ident.value.to_lowercase(), right.as_ref(), false),
+                    (_, Expr::Identifier(ident)) => (ident.value.to_lowercase(), left.as_ref(), true),
+                    _ => return None,
+                };
+                let column_value = Self::typed_column_value(dependency, &column)?;
+                let literal_value = Self::typed_literal(&column, literal_expr)?;
+                let (lhs, rhs) = if flipped { (literal_value, column_value) } else { (column_value, literal_value) };
+                match op {
+                    BinaryOperator::Eq => Some(lhs == rhs),
+                    BinaryOperator::NotEq => Some(lhs != rhs),
+                    BinaryOperator::Lt => Some(lhs < rhs),
+                    BinaryOperator::LtEq => Some(lhs <= rhs),
+                    BinaryOperator::Gt => Some(lhs > rhs),
+                    BinaryOperator::GtEq => Some(lhs >= rhs),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Run a catalog query against the requesting session's own connection
+    /// when one is available. This matters because things like ATTACHed
+    /// databases and uncommitted DDL are per-connection in SQLite - reading
+    /// through `DbHandler::query`'s temporary connection would miss them.
+    async fn run_query(db: &DbHandler, session_id: Option<Uuid>, sql: &str) -> Result<DbResponse, PgSqliteError> {
+        match session_id {
+            Some(id) => db.query_with_session(sql, &id).await,
+            None => Ok(db.query(sql).await?),
+        }
+    }
+
+    fn first_cell_string(response: &DbResponse) -> Option<String> {
+        response.rows.first()
+            .and_then(|row| row.first())
+            .and_then(|cell| cell.as_ref())
+            .map(|bytes| String::from_utf8_lossy(bytes).to_string())
+    }
+
+    /// List every user table's name alongside its pg_class oid, resolved the
+    /// same way `resolve_table_oid` resolves a single one. Shared by
+    /// synthesis (which needs the names to iterate) and the JOIN handler
+    /// (which needs the oid -> name direction to turn a dependency's
+    /// refobjid back into a relname).
+    async fn build_table_oid_map(db: &DbHandler, session_id: Option<Uuid>) -> Result<HashMap<String, u32>, PgSqliteError> {
+        let tables_response = Self::run_query(
+            db,
+            session_id,
+            "SELECT name FROM sqlite_master WHERE type='table' AND name NOT LIKE 'sqlite_%' AND name NOT LIKE '__pgsqlite_%'",
+        ).await?;
+
+        let table_names: Vec<String> = tables_response.rows.iter()
+            .filter_map(|row| row.first().and_then(|c| c.as_ref()))
+            .map(|bytes| String::from_utf8_lossy(bytes).to_string())
+            .collect();
+
+        let mut table_oids = HashMap::new();
+        for table_name in &table_names {
+            let oid = Self::resolve_table_oid(db, session_id, table_name).await?;
+            table_oids.insert(table_name.clone(), oid);
+        }
+        Ok(table_oids)
+    }
+
+    /// Resolve a table's pg_class oid via the persisted
+    /// `__pgsqlite_oid_registry` allocator, the same source pg_class itself
+    /// joins against. Only `object_kind = 'table'` entries are looked up, so
+    /// a sequence name resolves to 0. Falls back to 0 (InvalidOid) if the
+    /// table has no registry entry yet.
+    async fn resolve_table_oid(db: &DbHandler, session_id: Option<Uuid>, table_name: &str) -> Result<u32, PgSqliteError> {
+        let sql = format!(
+            "SELECT oid FROM __pgsqlite_oid_registry WHERE object_kind = 'table' AND object_name = '{table_name}'"
+        );
+        let response = Self::run_query(db, session_id, &sql).await?;
+        Ok(Self::first_cell_string(&response)
+            .and_then(|s| s.parse::<u32>().ok())
+            .unwrap_or(0))
+    }
+
+    /// Resolve a column's 1-based PostgreSQL attnum from SQLite's 0-based
+    /// `PRAGMA table_info` cid, matching the `cid + 1` convention already
+    /// used throughout constraint_populator.rs.
+    async fn column_attnum(db: &DbHandler, session_id: Option<Uuid>, table_name: &str, column_name: &str) -> Result<i32, PgSqliteError> {
+        let sql = format!("PRAGMA table_info({table_name})");
+        let response = Self::run_query(db, session_id, &sql).await?;
         for row in &response.rows {
-            if row.len() >= 7
-                && let (Some(Some(classid_bytes)), Some(Some(objid_bytes)), Some(Some(objsubid_bytes)),
-                        Some(Some(refclassid_bytes)), Some(Some(refobjid_bytes)), Some(Some(refobjsubid_bytes)),
-                        Some(Some(deptype_bytes))) =
-                    (row.first(), row.get(1), row.get(2), row.get(3), row.get(4), row.get(5), row.get(6)) {
-
-                    let classid = String::from_utf8_lossy(classid_bytes).parse::<u32>().unwrap_or(0);
-                    let objid = String::from_utf8_lossy(objid_bytes).parse::<u32>().unwrap_or(0);
-                    let objsubid = String::from_utf8_lossy(objsubid_bytes).parse::<i32>().unwrap_or(0);
-                    let refclassid = String::from_utf8_lossy(refclassid_bytes).parse::<u32>().unwrap_or(0);
-                    let refobjid = String::from_utf8_lossy(refobjid_bytes).parse::<u32>().unwrap_or(0);
-                    let refobjsubid = String::from_utf8_lossy(refobjsubid_bytes).parse::<i32>().unwrap_or(0);
-                    let deptype = String::from_utf8_lossy(deptype_bytes).chars().next().unwrap_or('a');
+            if let (Some(Some(cid_bytes)), Some(Some(name_bytes))) = (row.first(), row.get(1)) {
+                let name = String::from_utf8_lossy(name_bytes);
+                if name == column_name {
+                    let cid = String::from_utf8_lossy(cid_bytes).parse::<i32>().unwrap_or(0);
+                    return Ok(cid + 1);
+                }
+            }
+        }
+        Ok(0)
+    }
+
+    async fn synthesize_dependencies(db: &DbHandler, session_id: Option<Uuid>, hints: &DependPredicateHints) -> Result<Vec<DependencyInfo>, PgSqliteError> {
+        let mut dependencies = Vec::new();
+
+        // deptype/classid hints can rule out an entire category of
+        // dependency up front, skipping its PRAGMA calls across every table
+        // rather than computing it and filtering it out row by row.
+        let wants_deptype = |c: char| hints.deptype.as_ref().map_or(true, |allowed| allowed.contains(&c));
+        let wants_classid = |c: u32| hints.classid.as_ref().map_or(true, |allowed| allowed.contains(&c));
+        // Every dependency this synthesizer emits points back at pg_class
+        // (1259), so a refclassid hint that excludes it rules out everything.
+        let wants_refclassid = |c: u32| hints.refclassid.as_ref().map_or(true, |allowed| allowed.contains(&c));
+        let scan_fk = wants_deptype('n') && wants_classid(2606) && wants_refclassid(1259);
+        let scan_index_and_serial = (wants_deptype('a') || wants_deptype('i')) && wants_classid(1259) && wants_refclassid(1259);
+
+        if !scan_fk && !scan_index_and_serial {
+            return Ok(dependencies);
+        }
+
+        let mut table_oids = Self::build_table_oid_map(db, session_id).await?;
+        let table_names: Vec<String> = table_oids.keys().cloned().collect();
+
+        // Index and sequence dependencies have refobjid = the oid of the
+        // table being examined itself, so a `refobjid = N` (or `IN (...)`)
+        // predicate lets us skip every other table outright. Foreign keys
+        // can't use this shortcut: their refobjid is the *referenced*
+        // table, which could be pointed at from any source table, so FK
+        // discovery always has to scan the full table list.
+        let narrowed_for_refobjid: Option<Vec<String>> = hints.refobjid.as_ref().map(|oids| {
+            table_names.iter()
+                .filter(|name| table_oids.get(*name).is_some_and(|oid| oids.contains(oid)))
+                .cloned()
+                .collect()
+        });
+
+        for table_name in &table_names {
+            let table_oid = *table_oids.get(table_name).unwrap_or(&0);
 
+            // Foreign keys: a 'n' (normal) dependency from the constraint
+            // object to the table it references.
+            if scan_fk {
+                let fk_response = Self::run_query(db, session_id, &format!("PRAGMA foreign_key_list({table_name})")).await?;
+                let mut fk_groups: HashMap<i32, (String, Vec<String>)> = HashMap::new();
+                for row in &fk_response.rows {
+                    if let (Some(Some(id_bytes)), Some(Some(ref_table_bytes)), Some(Some(from_bytes))) =
+                        (row.first(), row.get(2), row.get(3)) {
+                            let id = String::from_utf8_lossy(id_bytes).parse::<i32>().unwrap_or(0);
+                            let ref_table = String::from_utf8_lossy(ref_table_bytes).to_string();
+                            let from_column = String::from_utf8_lossy(from_bytes).to_string();
+                            let entry = fk_groups.entry(id).or_insert_with(|| (ref_table, Vec::new()));
+                            entry.1.push(from_column);
+                        }
+                }
+                for (ref_table, columns) in fk_groups.into_values() {
+                    let ref_table_oid = match table_oids.get(&ref_table) {
+                        Some(oid) => *oid,
+                        None => {
+                            let oid = Self::resolve_table_oid(db, session_id, &ref_table).await?;
+                            table_oids.insert(ref_table.clone(), oid);
+                            oid
+                        }
+                    };
+                    let constraint_name = format!("{}_{}_fkey", table_name, columns.join("_"));
+                    let constraint_oid = generate_constraint_oid(&constraint_name, "f")
+                        .parse::<u32>()
+                        .unwrap_or(0);
+                    if hints.objid.as_ref().is_some_and(|allowed| !allowed.contains(&constraint_oid)) {
+                        continue;
+                    }
                     dependencies.push(DependencyInfo {
-                        classid,
-                        objid,
-                        objsubid,
-                        refclassid,
-                        refobjid,
-                        refobjsubid,
-                        deptype,
+                        classid: 2606, // pg_constraint
+                        objid: constraint_oid,
+                        objsubid: 0,
+                        refclassid: 1259, // pg_class
+                        refobjid: ref_table_oid,
+                        refobjsubid: 0,
+                        deptype: 'n',
+                    });
+                }
+            }
+
+            // Indexes: an 'a' (automatic) dependency from each index to the
+            // table column it's built over.
+            if scan_index_and_serial
+                && wants_deptype('a')
+                && narrowed_for_refobjid.as_ref().map_or(true, |names| names.contains(table_name)) {
+                let index_list_response = Self::run_query(db, session_id, &format!("PRAGMA index_list({table_name})")).await?;
+                for index_row in &index_list_response.rows {
+                    let Some(Some(index_name_bytes)) = index_row.get(1) else { continue };
+                    let index_name = String::from_utf8_lossy(index_name_bytes).to_string();
+                    if index_name.starts_with("sqlite_") {
+                        continue;
+                    }
+                    let index_oid = generate_constraint_oid(&index_name, "i").parse::<u32>().unwrap_or(0);
+                    if hints.objid.as_ref().is_some_and(|allowed| !allowed.contains(&index_oid)) {
+                        continue;
+                    }
+
+                    let index_info_response = Self::run_query(db, session_id, &format!("PRAGMA index_info({index_name})")).await?;
+                    for column_row in &index_info_response.rows {
+                        let Some(Some(column_name_bytes)) = column_row.get(2) else { continue };
+                        let column_name = String::from_utf8_lossy(column_name_bytes).to_string();
+                        let attnum = Self::column_attnum(db, session_id, table_name, &column_name).await?;
+                        dependencies.push(DependencyInfo {
+                            classid: 1259, // pg_class (the index itself)
+                            objid: index_oid,
+                            objsubid: 0,
+                            refclassid: 1259, // pg_class (the table)
+                            refobjid: table_oid,
+                            refobjsubid: attnum,
+                            deptype: 'a',
+                        });
+                    }
+                }
+            }
+        }
+
+        if !scan_index_and_serial || !wants_deptype('i') {
+            return Ok(dependencies);
+        }
+
+        // SERIAL columns: an 'i' (internal) dependency from the column's
+        // backing sequence to the table, read straight off
+        // __pgsqlite_sequences rather than re-deriving which columns are
+        // INTEGER PRIMARY KEY. objid/refobjid hints translate directly into
+        // a real SQL WHERE clause here since this source is already a
+        // regular table, not a PRAGMA.
+        let mut seq_conditions = Vec::new();
+        if let Some(oids) = &hints.objid {
+            let list = oids.iter().map(|o| o.to_string()).collect::<Vec<_>>().join(",");
+            seq_conditions.push(format!("seq_oid IN ({})", if list.is_empty() { "-1".to_string() } else { list }));
+        }
+        if let Some(names) = &narrowed_for_refobjid {
+            let list = names.iter().map(|n| format!("'{}'", n.replace('\'', "''"))).collect::<Vec<_>>().join(",");
+            seq_conditions.push(format!("table_name IN ({})", if list.is_empty() { "''".to_string() } else { list }));
+        }
+        let seq_where = if seq_conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", seq_conditions.join(" AND "))
+        };
+        let sequences_response = Self::run_query(
+            db,
+            session_id,
+            &format!("SELECT seq_oid, table_name, column_name FROM __pgsqlite_sequences{seq_where}"),
+        ).await?;
+        for row in &sequences_response.rows {
+            if let (Some(Some(seq_oid_bytes)), Some(Some(table_name_bytes)), Some(Some(column_name_bytes))) =
+                (row.first(), row.get(1), row.get(2)) {
+                    let seq_oid = String::from_utf8_lossy(seq_oid_bytes).parse::<u32>().unwrap_or(0);
+                    let seq_table_name = String::from_utf8_lossy(table_name_bytes).to_string();
+                    let column_name = String::from_utf8_lossy(column_name_bytes).to_string();
+
+                    let owning_table_oid = match table_oids.get(&seq_table_name) {
+                        Some(oid) => *oid,
+                        None => Self::resolve_table_oid(db, session_id, &seq_table_name).await?,
+                    };
+                    let attnum = Self::column_attnum(db, session_id, &seq_table_name, &column_name).await?;
+
+                    dependencies.push(DependencyInfo {
+                        classid: 1259, // pg_class (the sequence is classed as a relation)
+                        objid: seq_oid,
+                        objsubid: 0,
+                        refclassid: 1259, // pg_class (the owning table)
+                        refobjid: owning_table_oid,
+                        refobjsubid: attnum,
+                        deptype: 'i',
                     });
                 }
         }
@@ -140,6 +645,232 @@ impl PgDependHandler {
         Ok(dependencies)
     }
 
+    /// Compute the transitive closure of objects that depend on
+    /// `(classid, objid)`, the same closure `DROP ... CASCADE` needs to
+    /// decide what else has to go. Builds an adjacency map keyed by the
+    /// referenced object (`refclassid`, `refobjid`) and does a BFS outward
+    /// from the target, following each dependent to *its* dependents in
+    /// turn. Expansion stops at `deptype='p'` (pinned) entries - a pinned
+    /// object is never dropped as a side effect, so there's nothing further
+    /// to walk through it - and a visited set keeps cycles from looping
+    /// forever.
+    pub(crate) async fn find_dependents(
+        db: &DbHandler,
+        session_id: Option<Uuid>,
+        classid: u32,
+        objid: u32,
+    ) -> Result<Vec<DependencyInfo>, PgSqliteError> {
+        let all_dependencies = Self::synthesize_dependencies(db, session_id, &DependPredicateHints::default()).await?;
+
+        let mut adjacency: HashMap<(u32, u32), Vec<DependencyInfo>> = HashMap::new();
+        for dependency in &all_dependencies {
+            adjacency.entry((dependency.refclassid, dependency.refobjid))
+                .or_default()
+                .push(dependency.clone());
+        }
+
+        let mut visited: HashSet<(u32, u32)> = HashSet::new();
+        visited.insert((classid, objid));
+        let mut queue: VecDeque<(u32, u32)> = VecDeque::new();
+        queue.push_back((classid, objid));
+
+        let mut dependents = Vec::new();
+        while let Some(current) = queue.pop_front() {
+            let Some(edges) = adjacency.get(&current) else { continue };
+            for dependency in edges {
+                if dependency.deptype == 'p' {
+                    continue;
+                }
+                let key = (dependency.classid, dependency.objid);
+                if visited.insert(key) {
+                    dependents.push(dependency.clone());
+                    queue.push_back(key);
+                }
+            }
+        }
+
+        Ok(dependents)
+    }
+
+    /// Handle a query that joins `pg_depend` against `pg_class`,
+    /// `pg_constraint`, and/or `pg_attribute` - the standard way ORMs turn
+    /// the raw OIDs in a dependency row into names for "list foreign keys"
+    /// and `\d tablename` introspection. There's no real SQL join to run
+    /// here (the synthesized dependency set isn't backed by one table), so
+    /// this resolves the objid/refobjid <-> oid link for each dependency in
+    /// memory instead: `refobjid` always names the referenced relation,
+    /// `objid` names the constraint when `classid` is pg_constraint (2606),
+    /// and `refobjsubid` gives the referenced column's attnum when nonzero.
+    /// `joined_catalogs` is the set of catalog table names detected by the
+    /// caller from the query's FROM/JOIN clauses; only the lookups those
+    /// catalogs need get resolved.
+    pub async fn handle_join_query(
+        select: &Select,
+        joined_catalogs: &[&str],
+        db: &DbHandler,
+        session_id: Option<Uuid>,
+    ) -> Result<DbResponse, PgSqliteError> {
+        let dependencies = Self::synthesize_dependencies(db, session_id, &DependPredicateHints::default()).await?;
+
+        let table_oids = Self::build_table_oid_map(db, session_id).await?;
+        let oid_to_relname: HashMap<u32, String> = table_oids.iter()
+            .map(|(name, oid)| (*oid, name.clone()))
+            .collect();
+
+        let wants_relname = joined_catalogs.iter().any(|c| c.contains("pg_class"));
+        let wants_conname = joined_catalogs.iter().any(|c| c.contains("pg_constraint"));
+        let wants_attname = joined_catalogs.iter().any(|c| c.contains("pg_attribute"));
+
+        let conname_by_oid = if wants_conname {
+            Self::build_constraint_name_map(db, session_id, &table_oids).await?
+        } else {
+            HashMap::new()
+        };
+
+        let requested = Self::extract_join_requested_columns(select);
+
+        let mut rows = Vec::new();
+        for dependency in &dependencies {
+            let mut row_map = Self::dependency_to_map(dependency);
+
+            if wants_relname
+                && let Some(relname) = oid_to_relname.get(&dependency.refobjid) {
+                    row_map.insert("relname".to_string(), relname.clone());
+                }
+
+            if wants_conname
+                && dependency.classid == 2606
+                && let Some(conname) = conname_by_oid.get(&dependency.objid) {
+                    row_map.insert("conname".to_string(), conname.clone());
+                }
+
+            if wants_attname && dependency.refobjsubid > 0
+                && let Some(table_name) = oid_to_relname.get(&dependency.refobjid)
+                && let Some(attname) = Self::attname_for(db, session_id, table_name, dependency.refobjsubid).await? {
+                    row_map.insert("attname".to_string(), attname);
+                }
+
+            if let Some(where_clause) = &select.selection {
+                let column_mapping = HashMap::new();
+                if !WhereEvaluator::evaluate(where_clause, &row_map, &column_mapping) {
+                    continue;
+                }
+            }
+
+            rows.push(Self::project_join_row(&requested, &row_map));
+        }
+
+        let columns = requested.into_iter().map(|(display, _)| display).collect();
+        let rows_affected = rows.len();
+        Ok(DbResponse { columns, rows, rows_affected })
+    }
+
+    /// Reverse-derive every foreign key constraint's name and oid the same
+    /// way `synthesize_dependencies` generates them, so a dependency's
+    /// `objid` (when it names an FK constraint) can be mapped back to the
+    /// `conname` a JOIN against pg_constraint would expect.
+    async fn build_constraint_name_map(
+        db: &DbHandler,
+        session_id: Option<Uuid>,
+        table_oids: &HashMap<String, u32>,
+    ) -> Result<HashMap<u32, String>, PgSqliteError> {
+        let mut names = HashMap::new();
+        for table_name in table_oids.keys() {
+            let fk_response = Self::run_query(db, session_id, &format!("PRAGMA foreign_key_list({table_name})")).await?;
+            let mut fk_groups: HashMap<i32, Vec<String>> = HashMap::new();
+            for row in &fk_response.rows {
+                if let (Some(Some(id_bytes)), Some(Some(from_bytes))) = (row.first(), row.get(3)) {
+                    let id = String::from_utf8_lossy(id_bytes).parse::<i32>().unwrap_or(0);
+                    let from_column = String::from_utf8_lossy(from_bytes).to_string();
+                    fk_groups.entry(id).or_default().push(from_column);
+                }
+            }
+            for columns in fk_groups.into_values() {
+                let constraint_name = format!("{}_{}_fkey", table_name, columns.join("_"));
+                let constraint_oid = generate_constraint_oid(&constraint_name, "f").parse::<u32>().unwrap_or(0);
+                names.insert(constraint_oid, constraint_name);
+            }
+        }
+        Ok(names)
+    }
+
+    /// Resolve a column's name from its 1-based PostgreSQL attnum - the
+    /// inverse of `column_attnum`.
+    async fn attname_for(
+        db: &DbHandler,
+        session_id: Option<Uuid>,
+        table_name: &str,
+        attnum: i32,
+    ) -> Result<Option<String>, PgSqliteError> {
+        let sql = format!("PRAGMA table_info({table_name})");
+        let response = Self::run_query(db, session_id, &sql).await?;
+        for row in &response.rows {
+            if let (Some(Some(cid_bytes)), Some(Some(name_bytes))) = (row.first(), row.get(1)) {
+                let cid = String::from_utf8_lossy(cid_bytes).parse::<i32>().unwrap_or(-1);
+                if cid + 1 == attnum {
+                    return Ok(Some(String::from_utf8_lossy(name_bytes).to_string()));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Extract `(display_name, lookup_key)` pairs for a JOIN query's
+    /// projection. `display_name` is what the column is reported back as
+    /// (honoring an `AS` alias); `lookup_key` is the unqualified column name
+    /// used to find the value in the flattened per-row map, so `d.objid`,
+    /// `pg_depend.objid`, and a bare `objid` all resolve the same way. A
+    /// wildcard expands to the 7 pg_depend columns plus whichever resolved
+    /// columns this join can supply, in a fixed, predictable order.
+    fn extract_join_requested_columns(select: &Select) -> Vec<(String, String)> {
+        if select.projection.len() == 1
+            && let SelectItem::Wildcard(_) = &select.projection[0] {
+                return vec![
+                    ("classid".to_string(), "classid".to_string()),
+                    ("objid".to_string(), "objid".to_string()),
+                    ("objsubid".to_string(), "objsubid".to_string()),
+                    ("refclassid".to_string(), "refclassid".to_string()),
+                    ("refobjid".to_string(), "refobjid".to_string()),
+                    ("refobjsubid".to_string(), "refobjsubid".to_string()),
+                    ("deptype".to_string(), "deptype".to_string()),
+                    ("relname".to_string(), "relname".to_string()),
+                    ("conname".to_string(), "conname".to_string()),
+                    ("attname".to_string(), "attname".to_string()),
+                ];
+            }
+
+        let mut columns = Vec::new();
+        for item in &select.projection {
+            match item {
+                SelectItem::UnnamedExpr(Expr::Identifier(ident)) => {
+                    let key = ident.value.to_string();
+                    columns.push((key.clone(), key));
+                }
+                SelectItem::UnnamedExpr(Expr::CompoundIdentifier(parts)) => {
+                    if let Some(last) = parts.last() {
+                        let key = last.value.to_string();
+                        columns.push((key.clone(), key));
+                    }
+                }
+                SelectItem::ExprWithAlias { expr: Expr::Identifier(ident), alias } => {
+                    columns.push((alias.value.to_string(), ident.value.to_string()));
+                }
+                SelectItem::ExprWithAlias { expr: Expr::CompoundIdentifier(parts), alias } => {
+                    if let Some(last) = parts.last() {
+                        columns.push((alias.value.to_string(), last.value.to_string()));
+                    }
+                }
+                _ => {}
+            }
+        }
+        columns
+    }
+
+    fn project_join_row(requested: &[(String, String)], row_map: &HashMap<String, String>) -> Vec<Option<Vec<u8>>> {
+        requested.iter()
+            .map(|(_, key)| row_map.get(key).map(|value| value.clone().into_bytes()))
+            .collect()
+    }
 
     fn dependency_to_row(dependency: &DependencyInfo) -> Vec<Option<Vec<u8>>> {
         vec![
@@ -167,12 +898,12 @@ impl PgDependHandler {
 }
 
 #[derive(Clone)]
-struct DependencyInfo {
-    classid: u32,        // System catalog OID where dependent object is listed
-    objid: u32,          // OID of the dependent object
-    objsubid: i32,       // Column number for table dependencies, 0 otherwise
-    refclassid: u32,     // System catalog OID where referenced object is listed
-    refobjid: u32,       // OID of the referenced object
-    refobjsubid: i32,    // Column number for referenced object
-    deptype: char,       // Dependency type ('a' = automatic)
-}
\ No newline at end of file
+pub(crate) struct DependencyInfo {
+    pub(crate) classid: u32,        // System catalog OID where dependent object is listed
+    pub(crate) objid: u32,          // OID of the dependent object
+    pub(crate) objsubid: i32,       // Column number for table dependencies, 0 otherwise
+    pub(crate) refclassid: u32,     // System catalog OID where referenced object is listed
+    pub(crate) refobjid: u32,       // OID of the referenced object
+    pub(crate) refobjsubid: i32,    // Column number for referenced object
+    pub(crate) deptype: char,       // Dependency type ('n' = normal, 'a' = automatic, 'i' = internal)
+}
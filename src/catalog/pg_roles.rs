@@ -10,7 +10,7 @@ pub struct PgRolesHandler;
 impl PgRolesHandler {
     pub async fn handle_query(
         select: &Select,
-        _db: &DbHandler,
+        db: &DbHandler,
     ) -> Result<DbResponse, PgSqliteError> {
         debug!("Handling pg_roles query");
 
@@ -34,8 +34,10 @@ impl PgRolesHandler {
         // Determine which columns to return
         let selected_columns = Self::get_selected_columns(&select.projection, &all_columns);
 
-        // Build default roles (since SQLite doesn't have role management)
-        let roles = Self::get_default_roles();
+        // Roles now live in __pgsqlite_roles, populated by the default seed
+        // rows from migration v22 plus whatever CREATE/ALTER/DROP ROLE has
+        // done since (see `crate::ddl::RoleDdlHandler`).
+        let roles = Self::get_roles(db).await?;
 
         // Apply WHERE clause filtering if present
         let filtered_roles = if let Some(where_clause) = &select.selection {
@@ -96,61 +98,66 @@ impl PgRolesHandler {
         selected
     }
 
-    fn get_default_roles() -> Vec<HashMap<String, Vec<u8>>> {
+    async fn get_roles(db: &DbHandler) -> Result<Vec<HashMap<String, Vec<u8>>>, PgSqliteError> {
         let mut roles = Vec::new();
 
-        // Default superuser role (simulating PostgreSQL's postgres role)
-        let mut postgres_role = HashMap::new();
-        postgres_role.insert("oid".to_string(), b"10".to_vec()); // Standard postgres role OID
-        postgres_role.insert("rolname".to_string(), b"postgres".to_vec());
-        postgres_role.insert("rolsuper".to_string(), b"t".to_vec()); // true
-        postgres_role.insert("rolinherit".to_string(), b"t".to_vec()); // true
-        postgres_role.insert("rolcreaterole".to_string(), b"t".to_vec()); // true
-        postgres_role.insert("rolcreatedb".to_string(), b"t".to_vec()); // true
-        postgres_role.insert("rolcanlogin".to_string(), b"t".to_vec()); // true
-        postgres_role.insert("rolreplication".to_string(), b"t".to_vec()); // true
-        postgres_role.insert("rolconnlimit".to_string(), b"-1".to_vec()); // unlimited
-        postgres_role.insert("rolpassword".to_string(), b"********".to_vec()); // hidden
-        postgres_role.insert("rolvaliduntil".to_string(), b"".to_vec()); // NULL
-        postgres_role.insert("rolbypassrls".to_string(), b"t".to_vec()); // true
-        postgres_role.insert("rolconfig".to_string(), b"".to_vec()); // NULL
-        roles.push(postgres_role);
-
-        // Default public role (for compatibility)
-        let mut public_role = HashMap::new();
-        public_role.insert("oid".to_string(), b"0".to_vec()); // Public role OID
-        public_role.insert("rolname".to_string(), b"public".to_vec());
-        public_role.insert("rolsuper".to_string(), b"f".to_vec()); // false
-        public_role.insert("rolinherit".to_string(), b"t".to_vec()); // true
-        public_role.insert("rolcreaterole".to_string(), b"f".to_vec()); // false
-        public_role.insert("rolcreatedb".to_string(), b"f".to_vec()); // false
-        public_role.insert("rolcanlogin".to_string(), b"f".to_vec()); // false
-        public_role.insert("rolreplication".to_string(), b"f".to_vec()); // false
-        public_role.insert("rolconnlimit".to_string(), b"-1".to_vec()); // unlimited
-        public_role.insert("rolpassword".to_string(), b"".to_vec()); // NULL
-        public_role.insert("rolvaliduntil".to_string(), b"".to_vec()); // NULL
-        public_role.insert("rolbypassrls".to_string(), b"f".to_vec()); // false
-        public_role.insert("rolconfig".to_string(), b"".to_vec()); // NULL
-        roles.push(public_role);
-
-        // Default current user role (matches connection user)
-        let mut current_user_role = HashMap::new();
-        current_user_role.insert("oid".to_string(), b"100".to_vec()); // Default user OID
-        current_user_role.insert("rolname".to_string(), b"pgsqlite_user".to_vec());
-        current_user_role.insert("rolsuper".to_string(), b"t".to_vec()); // true for simplicity
-        current_user_role.insert("rolinherit".to_string(), b"t".to_vec()); // true
-        current_user_role.insert("rolcreaterole".to_string(), b"t".to_vec()); // true
-        current_user_role.insert("rolcreatedb".to_string(), b"t".to_vec()); // true
-        current_user_role.insert("rolcanlogin".to_string(), b"t".to_vec()); // true
-        current_user_role.insert("rolreplication".to_string(), b"f".to_vec()); // false
-        current_user_role.insert("rolconnlimit".to_string(), b"-1".to_vec()); // unlimited
-        current_user_role.insert("rolpassword".to_string(), b"********".to_vec()); // hidden
-        current_user_role.insert("rolvaliduntil".to_string(), b"".to_vec()); // NULL
-        current_user_role.insert("rolbypassrls".to_string(), b"t".to_vec()); // true
-        current_user_role.insert("rolconfig".to_string(), b"".to_vec()); // NULL
-        roles.push(current_user_role);
-
-        roles
+        let conn = rusqlite::Connection::open(&db.db_path).map_err(PgSqliteError::Sqlite)?;
+
+        let query = "SELECT oid, rolname, rolsuper, rolinherit, rolcreaterole, rolcreatedb, \
+                     rolcanlogin, rolreplication, rolconnlimit, rolpassword, rolvaliduntil, rolbypassrls \
+                     FROM __pgsqlite_roles";
+
+        let mut stmt = match conn.prepare(query) {
+            Ok(stmt) => stmt,
+            Err(e) => {
+                if e.to_string().contains("no such table") {
+                    debug!("__pgsqlite_roles table doesn't exist yet");
+                    return Ok(roles);
+                }
+                return Err(PgSqliteError::Sqlite(e));
+            }
+        };
+
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, String>(5)?,
+                row.get::<_, String>(6)?,
+                row.get::<_, String>(7)?,
+                row.get::<_, i64>(8)?,
+                row.get::<_, Option<String>>(9)?,
+                row.get::<_, Option<String>>(10)?,
+                row.get::<_, String>(11)?,
+            ))
+        }).map_err(PgSqliteError::Sqlite)?;
+
+        for row_result in rows.flatten() {
+            let (oid, rolname, rolsuper, rolinherit, rolcreaterole, rolcreatedb,
+                 rolcanlogin, rolreplication, rolconnlimit, rolpassword, rolvaliduntil, rolbypassrls) = row_result;
+
+            let mut role = HashMap::new();
+            role.insert("oid".to_string(), oid.to_string().into_bytes());
+            role.insert("rolname".to_string(), rolname.into_bytes());
+            role.insert("rolsuper".to_string(), rolsuper.into_bytes());
+            role.insert("rolinherit".to_string(), rolinherit.into_bytes());
+            role.insert("rolcreaterole".to_string(), rolcreaterole.into_bytes());
+            role.insert("rolcreatedb".to_string(), rolcreatedb.into_bytes());
+            role.insert("rolcanlogin".to_string(), rolcanlogin.into_bytes());
+            role.insert("rolreplication".to_string(), rolreplication.into_bytes());
+            role.insert("rolconnlimit".to_string(), rolconnlimit.to_string().into_bytes());
+            // The password itself is never surfaced, only whether one is set.
+            role.insert("rolpassword".to_string(), rolpassword.map(|_| b"********".to_vec()).unwrap_or_default());
+            role.insert("rolvaliduntil".to_string(), rolvaliduntil.map(|v| v.into_bytes()).unwrap_or_default());
+            role.insert("rolbypassrls".to_string(), rolbypassrls.into_bytes());
+            role.insert("rolconfig".to_string(), Vec::new());
+            roles.push(role);
+        }
+
+        Ok(roles)
     }
 
     fn apply_where_filter(
@@ -143,6 +143,44 @@ impl PgSequenceHandler {
             sequences.push(sequence);
         }
 
+        // Emulated serial sequences (single-column INTEGER PRIMARY KEY tables)
+        // get a real row in pg_sequence, written by
+        // constraint_populator::populate_table_dependencies - read those too
+        // so pg_get_serial_sequence()/ORMs see them the same way.
+        let pg_sequence_query = "SELECT seqrelid, seqtypid, seqstart, seqincrement, seqmax, seqmin, seqcache, seqcycle FROM pg_sequence";
+        if let Ok(mut stmt) = conn.prepare(pg_sequence_query) {
+            let rows = stmt.query_map([], |row| {
+                let seqrelid: String = row.get(0)?;
+                let seqtypid: i64 = row.get(1)?;
+                let seqstart: i64 = row.get(2)?;
+                let seqincrement: i64 = row.get(3)?;
+                let seqmax: i64 = row.get(4)?;
+                let seqmin: i64 = row.get(5)?;
+                let seqcache: i64 = row.get(6)?;
+                let seqcycle: i64 = row.get(7)?;
+                Ok((seqrelid, seqtypid, seqstart, seqincrement, seqmax, seqmin, seqcache, seqcycle))
+            }).map_err(PgSqliteError::Sqlite)?;
+
+            for row_result in rows.flatten() {
+                let (seqrelid, seqtypid, seqstart, seqincrement, seqmax, seqmin, seqcache, seqcycle) = row_result;
+
+                let mut sequence = HashMap::new();
+                sequence.insert("seqrelid".to_string(), seqrelid.clone().into_bytes());
+                sequence.insert("seqtypid".to_string(), seqtypid.to_string().into_bytes());
+                sequence.insert("seqstart".to_string(), seqstart.to_string().into_bytes());
+                sequence.insert("seqincrement".to_string(), seqincrement.to_string().into_bytes());
+                sequence.insert("seqmax".to_string(), seqmax.to_string().into_bytes());
+                sequence.insert("seqmin".to_string(), seqmin.to_string().into_bytes());
+                sequence.insert("seqcache".to_string(), seqcache.to_string().into_bytes());
+                sequence.insert("seqcycle".to_string(), if seqcycle != 0 { b"t".to_vec() } else { b"f".to_vec() });
+
+                debug!("Found emulated serial sequence {}", seqrelid);
+                sequences.push(sequence);
+            }
+        } else {
+            debug!("pg_sequence table doesn't exist yet (no emulated serial sequences)");
+        }
+
         Ok(sequences)
     }
 
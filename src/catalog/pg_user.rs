@@ -10,7 +10,7 @@ pub struct PgUserHandler;
 impl PgUserHandler {
     pub async fn handle_query(
         select: &Select,
-        _db: &DbHandler,
+        db: &DbHandler,
     ) -> Result<DbResponse, PgSqliteError> {
         debug!("Handling pg_user query");
 
@@ -30,8 +30,9 @@ impl PgUserHandler {
         // Determine which columns to return
         let selected_columns = Self::get_selected_columns(&select.projection, &all_columns);
 
-        // Build default users (since SQLite doesn't have user management)
-        let users = Self::get_default_users();
+        // Users are the subset of __pgsqlite_roles that can log in, matching
+        // PostgreSQL's own pg_user definition over pg_authid.
+        let users = Self::get_users(db).await?;
 
         // Apply WHERE clause filtering if present
         let filtered_users = if let Some(where_clause) = &select.selection {
@@ -92,36 +93,55 @@ impl PgUserHandler {
         selected
     }
 
-    fn get_default_users() -> Vec<HashMap<String, Vec<u8>>> {
+    async fn get_users(db: &DbHandler) -> Result<Vec<HashMap<String, Vec<u8>>>, PgSqliteError> {
         let mut users = Vec::new();
 
-        // Default superuser (corresponds to postgres role)
-        let mut postgres_user = HashMap::new();
-        postgres_user.insert("usename".to_string(), b"postgres".to_vec());
-        postgres_user.insert("usesysid".to_string(), b"10".to_vec()); // Standard postgres user OID
-        postgres_user.insert("usecreatedb".to_string(), b"t".to_vec()); // true
-        postgres_user.insert("usesuper".to_string(), b"t".to_vec()); // true
-        postgres_user.insert("userepl".to_string(), b"t".to_vec()); // true
-        postgres_user.insert("usebypassrls".to_string(), b"t".to_vec()); // true
-        postgres_user.insert("passwd".to_string(), b"********".to_vec()); // hidden
-        postgres_user.insert("valuntil".to_string(), b"".to_vec()); // NULL
-        postgres_user.insert("useconfig".to_string(), b"".to_vec()); // NULL
-        users.push(postgres_user);
-
-        // Default current user (corresponds to pgsqlite_user role)
-        let mut current_user = HashMap::new();
-        current_user.insert("usename".to_string(), b"pgsqlite_user".to_vec());
-        current_user.insert("usesysid".to_string(), b"100".to_vec()); // Default user OID
-        current_user.insert("usecreatedb".to_string(), b"t".to_vec()); // true
-        current_user.insert("usesuper".to_string(), b"t".to_vec()); // true for simplicity
-        current_user.insert("userepl".to_string(), b"f".to_vec()); // false
-        current_user.insert("usebypassrls".to_string(), b"t".to_vec()); // true
-        current_user.insert("passwd".to_string(), b"********".to_vec()); // hidden
-        current_user.insert("valuntil".to_string(), b"".to_vec()); // NULL
-        current_user.insert("useconfig".to_string(), b"".to_vec()); // NULL
-        users.push(current_user);
-
-        users
+        let conn = rusqlite::Connection::open(&db.db_path).map_err(PgSqliteError::Sqlite)?;
+
+        let query = "SELECT rolname, oid, rolcreatedb, rolsuper, rolreplication, rolbypassrls, rolpassword, rolvaliduntil \
+                     FROM __pgsqlite_roles WHERE rolcanlogin = 't'";
+
+        let mut stmt = match conn.prepare(query) {
+            Ok(stmt) => stmt,
+            Err(e) => {
+                if e.to_string().contains("no such table") {
+                    debug!("__pgsqlite_roles table doesn't exist yet");
+                    return Ok(users);
+                }
+                return Err(PgSqliteError::Sqlite(e));
+            }
+        };
+
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, String>(5)?,
+                row.get::<_, Option<String>>(6)?,
+                row.get::<_, Option<String>>(7)?,
+            ))
+        }).map_err(PgSqliteError::Sqlite)?;
+
+        for row_result in rows.flatten() {
+            let (usename, usesysid, usecreatedb, usesuper, userepl, usebypassrls, passwd, valuntil) = row_result;
+
+            let mut user = HashMap::new();
+            user.insert("usename".to_string(), usename.into_bytes());
+            user.insert("usesysid".to_string(), usesysid.to_string().into_bytes());
+            user.insert("usecreatedb".to_string(), usecreatedb.into_bytes());
+            user.insert("usesuper".to_string(), usesuper.into_bytes());
+            user.insert("userepl".to_string(), userepl.into_bytes());
+            user.insert("usebypassrls".to_string(), usebypassrls.into_bytes());
+            user.insert("passwd".to_string(), passwd.map(|_| b"********".to_vec()).unwrap_or_default());
+            user.insert("valuntil".to_string(), valuntil.map(|v| v.into_bytes()).unwrap_or_default());
+            user.insert("useconfig".to_string(), Vec::new());
+            users.push(user);
+        }
+
+        Ok(users)
     }
 
     fn apply_where_filter(
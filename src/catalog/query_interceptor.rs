@@ -8,7 +8,7 @@ use sqlparser::dialect::PostgreSqlDialect;
 use sqlparser::parser::Parser;
 use sqlparser::tokenizer::{Location, Span};
 use tracing::{debug, info};
-use super::{pg_class::PgClassHandler, pg_attribute::PgAttributeHandler, pg_constraint::PgConstraintHandler, pg_depend::PgDependHandler, pg_enum::PgEnumHandler, pg_description::PgDescriptionHandler, pg_roles::PgRolesHandler, pg_user::PgUserHandler, pg_stats::PgStatsHandler, pg_sequence::PgSequenceHandler, pg_trigger::PgTriggerHandler, pg_settings::PgSettingsHandler, system_functions::SystemFunctions, where_evaluator::WhereEvaluator};
+use super::{pg_class::PgClassHandler, pg_attribute::PgAttributeHandler, pg_constraint::PgConstraintHandler, pg_depend::PgDependHandler, pg_enum::PgEnumHandler, pg_description::PgDescriptionHandler, pg_roles::PgRolesHandler, pg_user::PgUserHandler, pg_stats::PgStatsHandler, pg_sequence::PgSequenceHandler, pg_trigger::PgTriggerHandler, pg_settings::PgSettingsHandler, system_functions::SystemFunctions, where_evaluator::WhereEvaluator, constraint_populator};
 use std::sync::Arc;
 use std::pin::Pin;
 use std::future::Future;
@@ -257,6 +257,7 @@ impl CatalogInterceptor {
                             query_str = query_str.replace("information_schema.table_constraints", "information_schema_table_constraints");
                             query_str = query_str.replace("information_schema.key_column_usage", "information_schema_key_column_usage");
                             query_str = query_str.replace("information_schema.referential_constraints", "information_schema_referential_constraints");
+                            query_str = query_str.replace("information_schema.constraint_column_usage", "information_schema_constraint_column_usage");
                             query_str = query_str.replace("information_schema.columns", "information_schema_columns");
                             query_str = query_str.replace("information_schema.tables", "information_schema_tables");
                             query_str = query_str.replace("information_schema.schemata", "information_schema_schemata");
@@ -379,6 +380,39 @@ impl CatalogInterceptor {
                     debug!("Unable to handle this specific system function query pattern");
                 } else {
                     debug!("In JOIN query else block (no system functions)");
+
+                    // Handle JOINs where pg_depend (driving or joined) is matched
+                    // against pg_class/pg_constraint/pg_attribute to turn its raw
+                    // OIDs into relname/conname/attname. This has to be resolved
+                    // in memory rather than passed through to SQLite: the static
+                    // pg_depend table only tracks SERIAL-to-sequence ownership,
+                    // while PgDependHandler's synthesized set also covers foreign
+                    // keys and indexes.
+                    let joined_table_names: Vec<String> = std::iter::once(&select.from[0].relation)
+                        .chain(select.from[0].joins.iter().map(|j| &j.relation))
+                        .filter_map(|factor| match factor {
+                            TableFactor::Table { name, .. } => Some(name.to_string().to_lowercase()),
+                            _ => None,
+                        })
+                        .collect();
+                    let has_pg_depend = joined_table_names.iter().any(|n| n.contains("pg_depend"));
+                    let other_catalogs: Vec<&str> = joined_table_names.iter()
+                        .filter(|n| n.contains("pg_class") || n.contains("pg_constraint") || n.contains("pg_attribute"))
+                        .map(|n| n.as_str())
+                        .collect();
+
+                    if has_pg_depend && !other_catalogs.is_empty() {
+                        debug!("Detected pg_depend JOIN against {:?}", other_catalogs);
+                        let session_id = session.as_ref().map(|s| s.id);
+                        return match PgDependHandler::handle_join_query(select, &other_catalogs, &db, session_id).await {
+                            Ok(response) => Some(response),
+                            Err(e) => {
+                                debug!("PgDependHandler::handle_join_query failed: {}", e);
+                                None
+                            }
+                        };
+                    }
+
                     // Check if this is a pg_attribute JOIN query that we should handle
                     if let TableFactor::Table { name, .. } = &select.from[0].relation {
                         let table_name = name.to_string().to_lowercase();
@@ -682,7 +716,8 @@ impl CatalogInterceptor {
             // Handle pg_depend queries
             if table_name.contains("pg_depend") || table_name.contains("pg_catalog.pg_depend") {
                 info!("Routing to PgDependHandler for table: {}", table_name);
-                return match PgDependHandler::handle_query(select, &db).await {
+                let session_id = session.as_ref().map(|s| s.id);
+                return match PgDependHandler::handle_query(select, &db, session_id).await {
                     Ok(response) => {
                         debug!("PgDependHandler returned {} rows", response.rows.len());
                         Some(Ok(response))
@@ -740,6 +775,15 @@ impl CatalogInterceptor {
                 }
             }
 
+            // Handle information_schema.constraint_column_usage queries
+            if table_name.contains("information_schema.constraint_column_usage") {
+                if let Some(ref session_state) = session {
+                    return Some(Self::handle_information_schema_constraint_column_usage_query(select, &db, &session_state.id).await);
+                } else {
+                    return None;
+                }
+            }
+
             // Handle information_schema.routines queries
             if table_name.contains("information_schema.routines") {
                 return Some(Self::handle_information_schema_routines_query(select, &db).await);
@@ -752,7 +796,8 @@ impl CatalogInterceptor {
 
             // Handle pg_database queries
             if table_name.contains("pg_database") || table_name.contains("pg_catalog.pg_database") {
-                return Some(Ok(Self::handle_pg_database_query(select, &db).await));
+                let session_id = session.as_ref().map(|s| s.id);
+                return Some(Ok(Self::handle_pg_database_query(select, &db, session_id).await));
             }
 
             // Handle pg_constraint queries
@@ -762,6 +807,12 @@ impl CatalogInterceptor {
 
             // Note: pg_index is a SQLite view that will be executed normally
             // It doesn't need special interception since it exists in the database
+
+            // Note: pg_authid is also a plain SQLite view (unlike pg_roles/pg_user,
+            // which mask rolpassword and need WhereEvaluator-based WHERE handling
+            // here) - PostgreSQL itself only grants it to superusers via GRANT, a
+            // restriction we don't enforce, so there's nothing for a Rust handler
+            // to add over the view SQLite already executes.
         }
         println!("INTERCEPT: Reached end of intercept_query, returning None");
         None
@@ -2501,7 +2552,7 @@ impl CatalogInterceptor {
         })
     }
 
-    async fn handle_pg_database_query(select: &Select, _db: &DbHandler) -> DbResponse {
+    async fn handle_pg_database_query(select: &Select, db: &DbHandler, session_id: Option<Uuid>) -> DbResponse {
         debug!("Handling pg_database query");
 
         // Define pg_database columns (PostgreSQL 17 compatible)
@@ -2529,37 +2580,75 @@ impl CatalogInterceptor {
         // Extract selected columns
         let (selected_columns, column_indices) = Self::extract_selected_columns(select, &all_columns);
 
-        // Single database entry representing the current SQLite database
-        let full_row: Vec<Option<Vec<u8>>> = vec![
-            Some("1".to_string().into_bytes()),                        // oid
-            Some("main".to_string().into_bytes()),                     // datname - the key field!
-            Some("10".to_string().into_bytes()),                       // datdba (owner)
-            Some("6".to_string().into_bytes()),                        // encoding (UTF8)
-            Some("d".to_string().into_bytes()),                        // datlocprovider (default)
-            Some("f".to_string().into_bytes()),                        // datistemplate (false) - PostgreSQL uses 'f'/'t' for bool
-            Some("t".to_string().into_bytes()),                        // datallowconn (true) - PostgreSQL uses 'f'/'t' for bool
-            Some("f".to_string().into_bytes()),                        // dathasloginevt (false) - PostgreSQL uses 'f'/'t' for bool
-            Some("-1".to_string().into_bytes()),                       // datconnlimit (no limit)
-            Some("1".to_string().into_bytes()),                        // datfrozenxid
-            Some("1".to_string().into_bytes()),                        // datminmxid
-            Some("1663".to_string().into_bytes()),                     // dattablespace (default)
-            Some("en_US.UTF-8".to_string().into_bytes()),              // datcollate
-            Some("en_US.UTF-8".to_string().into_bytes()),              // datctype
-            None,                                                      // datlocale
-            None,                                                      // daticurules
-            None,                                                      // datcollversion
-            None,                                                      // datacl
-        ];
+        // Each row of `PRAGMA database_list` (seq, name, file) is a database
+        // attached to this connection - the implicit "main" plus whatever
+        // ATTACH DATABASE has added since. One pg_database row per attached
+        // database, rather than a single hardcoded "main" entry, so clients
+        // that enumerate databases after ATTACH see them all.
+        // ATTACH is per-connection, so this must run on the requesting
+        // session's own connection rather than db.query()'s temporary one -
+        // otherwise a database the session just ATTACHed would never show up.
+        let database_list_result = match session_id {
+            Some(id) => db.query_with_session("PRAGMA database_list", &id).await
+                .map_err(|e| e.to_string()),
+            None => db.query("PRAGMA database_list").await.map_err(|e| e.to_string()),
+        };
 
-        // Project only requested columns
-        let projected_row: Vec<Option<Vec<u8>>> = column_indices.iter()
-            .map(|&idx| full_row[idx].clone())
-            .collect();
+        let databases = match database_list_result {
+            Ok(response) => response.rows.into_iter()
+                .filter_map(|row| row.into_iter().nth(1).flatten())
+                .filter_map(|name_bytes| String::from_utf8(name_bytes).ok())
+                .collect::<Vec<_>>(),
+            Err(e) => {
+                debug!("Failed to query PRAGMA database_list, falling back to 'main': {:?}", e);
+                vec!["main".to_string()]
+            }
+        };
 
+        let rows: Vec<HashMap<String, Vec<u8>>> = databases.iter().enumerate().map(|(idx, name)| {
+            let mut row = HashMap::new();
+            row.insert("oid".to_string(), (idx as i64 + 1).to_string().into_bytes());
+            row.insert("datname".to_string(), name.clone().into_bytes());
+            row.insert("datdba".to_string(), b"10".to_vec());
+            row.insert("encoding".to_string(), b"6".to_vec());
+            row.insert("datlocprovider".to_string(), b"d".to_vec());
+            row.insert("datistemplate".to_string(), b"f".to_vec());
+            row.insert("datallowconn".to_string(), b"t".to_vec());
+            row.insert("dathasloginevt".to_string(), b"f".to_vec());
+            row.insert("datconnlimit".to_string(), b"-1".to_vec());
+            row.insert("datfrozenxid".to_string(), b"1".to_vec());
+            row.insert("datminmxid".to_string(), b"1".to_vec());
+            row.insert("dattablespace".to_string(), b"1663".to_vec());
+            row.insert("datcollate".to_string(), b"en_US.UTF-8".to_vec());
+            row.insert("datctype".to_string(), b"en_US.UTF-8".to_vec());
+            row
+        }).collect();
+
+        let filtered_rows = if let Some(where_clause) = &select.selection {
+            let column_mapping = HashMap::new();
+            rows.into_iter()
+                .filter(|row| {
+                    let string_data: HashMap<String, String> = row.iter()
+                        .map(|(k, v)| (k.clone(), String::from_utf8_lossy(v).to_string()))
+                        .collect();
+                    WhereEvaluator::evaluate(where_clause, &string_data, &column_mapping)
+                })
+                .collect::<Vec<_>>()
+        } else {
+            rows
+        };
+
+        let projected_rows: Vec<Vec<Option<Vec<u8>>>> = filtered_rows.iter().map(|row| {
+            column_indices.iter()
+                .map(|&idx| row.get(&all_columns[idx]).cloned())
+                .collect()
+        }).collect();
+
+        let rows_affected = projected_rows.len();
         DbResponse {
             columns: selected_columns,
-            rows: vec![projected_row],
-            rows_affected: 1,
+            rows: projected_rows,
+            rows_affected,
         }
     }
 
@@ -3140,7 +3229,7 @@ impl CatalogInterceptor {
     async fn get_referential_constraints(db: &DbHandler) -> Result<Vec<HashMap<String, Vec<u8>>>, PgSqliteError> {
         let mut constraints = Vec::new();
         // Query pg_constraint for foreign key constraints only
-        let query = "SELECT conname, confrelid FROM pg_constraint WHERE contype = 'f'";
+        let query = "SELECT conname, confrelid, confupdtype, confdeltype FROM pg_constraint WHERE contype = 'f'";
         let constraints_response: Result<DbResponse, PgSqliteError> = match db.get_mut_connection() {
             Ok(conn) => {
                 let mut stmt = conn.prepare(query)?;
@@ -3149,10 +3238,17 @@ impl CatalogInterceptor {
                 while let Some(row) = query_rows.next()? {
                     let constraint_name: String = row.get(0)?;
                     let referenced_table_oid: String = row.get(1)?;
-                    rows.push(vec![Some(constraint_name.into_bytes()), Some(referenced_table_oid.into_bytes())]);
+                    let confupdtype: String = row.get(2)?;
+                    let confdeltype: String = row.get(3)?;
+                    rows.push(vec![
+                        Some(constraint_name.into_bytes()),
+                        Some(referenced_table_oid.into_bytes()),
+                        Some(confupdtype.into_bytes()),
+                        Some(confdeltype.into_bytes()),
+                    ]);
                 }
                 Ok(DbResponse {
-                    columns: vec!["conname".to_string(), "confrelid".to_string()],
+                    columns: vec!["conname".to_string(), "confrelid".to_string(), "confupdtype".to_string(), "confdeltype".to_string()],
                     rows,
                     rows_affected: 0,
                 })
@@ -3165,11 +3261,13 @@ impl CatalogInterceptor {
 
         // Process each foreign key constraint
         for constraint_row in &constraints_response?.rows {
-            if constraint_row.len() >= 2
-                && let (Some(Some(name_bytes)), Some(Some(ref_oid_bytes))) =
-                    (constraint_row.first(), constraint_row.get(1)) {
+            if constraint_row.len() >= 4
+                && let (Some(Some(name_bytes)), Some(Some(ref_oid_bytes)), Some(Some(confupdtype_bytes)), Some(Some(confdeltype_bytes))) =
+                    (constraint_row.first(), constraint_row.get(1), constraint_row.get(2), constraint_row.get(3)) {
                     let constraint_name = String::from_utf8_lossy(name_bytes).to_string();
                     let referenced_table_oid = String::from_utf8_lossy(ref_oid_bytes).to_string();
+                    let confupdtype = String::from_utf8_lossy(confupdtype_bytes).chars().next().unwrap_or('a');
+                    let confdeltype = String::from_utf8_lossy(confdeltype_bytes).chars().next().unwrap_or('a');
 
                     debug!("Processing referential constraint: {}", constraint_name);
                     let mut constraint = HashMap::new();
@@ -3188,10 +3286,10 @@ impl CatalogInterceptor {
                     constraint.insert("unique_constraint_schema".to_string(), b"public".to_vec());
                     constraint.insert("unique_constraint_name".to_string(), referenced_constraint_name.as_bytes().to_vec());
 
-                    // SQLite foreign key defaults (SQLite doesn't store these explicitly)
+                    // SQLite doesn't support MATCH clauses; PostgreSQL reports unset as NONE
                     constraint.insert("match_option".to_string(), b"NONE".to_vec());
-                    constraint.insert("update_rule".to_string(), b"NO ACTION".to_vec());
-                    constraint.insert("delete_rule".to_string(), b"NO ACTION".to_vec());
+                    constraint.insert("update_rule".to_string(), constraint_populator::fk_action_name(confupdtype).as_bytes().to_vec());
+                    constraint.insert("delete_rule".to_string(), constraint_populator::fk_action_name(confdeltype).as_bytes().to_vec());
 
                     constraints.push(constraint);
                 }
@@ -3302,17 +3400,24 @@ impl CatalogInterceptor {
         let mut constraints = Vec::new();
         // Use session connection to see constraints created in this session
         let constraints_response = match db.connection_manager().execute_with_session(session_id, |conn| {
-            let query = "SELECT conname, confrelid FROM pg_constraint WHERE contype = 'f'";
+            let query = "SELECT conname, confrelid, confupdtype, confdeltype FROM pg_constraint WHERE contype = 'f'";
             let mut stmt = conn.prepare(query)?;
             let mut rows = Vec::new();
             let mut query_rows = stmt.query([])?;
             while let Some(row) = query_rows.next()? {
                 let constraint_name: String = row.get(0)?;
                 let referenced_table_oid: String = row.get(1)?;  // OIDs are stored as TEXT in pg_constraint
-                rows.push(vec![Some(constraint_name.into_bytes()), Some(referenced_table_oid.into_bytes())]);
+                let confupdtype: String = row.get(2)?;
+                let confdeltype: String = row.get(3)?;
+                rows.push(vec![
+                    Some(constraint_name.into_bytes()),
+                    Some(referenced_table_oid.into_bytes()),
+                    Some(confupdtype.into_bytes()),
+                    Some(confdeltype.into_bytes()),
+                ]);
             }
             Ok(DbResponse {
-                columns: vec!["conname".to_string(), "confrelid".to_string()],
+                columns: vec!["conname".to_string(), "confrelid".to_string(), "confupdtype".to_string(), "confdeltype".to_string()],
                 rows,
                 rows_affected: 0,
             })
@@ -3326,11 +3431,13 @@ impl CatalogInterceptor {
 
         // Process each foreign key constraint
         for constraint_row in &constraints_response.rows {
-            if constraint_row.len() >= 2
-                && let (Some(Some(name_bytes)), Some(Some(ref_oid_bytes))) =
-                    (constraint_row.first(), constraint_row.get(1)) {
+            if constraint_row.len() >= 4
+                && let (Some(Some(name_bytes)), Some(Some(ref_oid_bytes)), Some(Some(confupdtype_bytes)), Some(Some(confdeltype_bytes))) =
+                    (constraint_row.first(), constraint_row.get(1), constraint_row.get(2), constraint_row.get(3)) {
                     let constraint_name = String::from_utf8_lossy(name_bytes).to_string();
                     let referenced_table_oid = String::from_utf8_lossy(ref_oid_bytes).to_string();
+                    let confupdtype = String::from_utf8_lossy(confupdtype_bytes).chars().next().unwrap_or('a');
+                    let confdeltype = String::from_utf8_lossy(confdeltype_bytes).chars().next().unwrap_or('a');
 
                     debug!("Processing referential constraint with session: {}", constraint_name);
                     let mut constraint = HashMap::new();
@@ -3349,10 +3456,10 @@ impl CatalogInterceptor {
                     constraint.insert("unique_constraint_schema".to_string(), b"public".to_vec());
                     constraint.insert("unique_constraint_name".to_string(), referenced_constraint_name.as_bytes().to_vec());
 
-                    // SQLite foreign key defaults (SQLite doesn't store these explicitly)
+                    // SQLite doesn't support MATCH clauses; PostgreSQL reports unset as NONE
                     constraint.insert("match_option".to_string(), b"NONE".to_vec());
-                    constraint.insert("update_rule".to_string(), b"NO ACTION".to_vec());
-                    constraint.insert("delete_rule".to_string(), b"NO ACTION".to_vec());
+                    constraint.insert("update_rule".to_string(), constraint_populator::fk_action_name(confupdtype).as_bytes().to_vec());
+                    constraint.insert("delete_rule".to_string(), constraint_populator::fk_action_name(confdeltype).as_bytes().to_vec());
 
                     constraints.push(constraint);
                 }
@@ -3379,6 +3486,81 @@ impl CatalogInterceptor {
         }
     }
 
+    pub async fn handle_information_schema_constraint_column_usage_query(select: &Select, db: &DbHandler, session_id: &Uuid) -> Result<DbResponse, PgSqliteError> {
+        debug!("Handling information_schema.constraint_column_usage query");
+
+        // Define information_schema.constraint_column_usage columns (PostgreSQL standard)
+        let all_columns = vec![
+            "table_catalog".to_string(),
+            "table_schema".to_string(),
+            "table_name".to_string(),
+            "column_name".to_string(),
+            "constraint_catalog".to_string(),
+            "constraint_schema".to_string(),
+            "constraint_name".to_string(),
+        ];
+
+        // Determine which columns are being selected
+        let (selected_columns, column_indices) = Self::extract_selected_columns(select, &all_columns);
+
+        // Extract table filter from WHERE clause if present
+        let table_filter = if let Some(ref where_clause) = select.selection {
+            Self::extract_table_name_filter(where_clause)
+        } else {
+            None
+        };
+
+        // information_schema_constraint_column_usage is a real SQLite view backed by
+        // pg_constraint/pg_class/pg_attribute, created in migration v27
+        let query = if let Some(table_name) = &table_filter {
+            format!("SELECT {} FROM information_schema_constraint_column_usage WHERE table_name = '{}'",
+                all_columns.join(", "), table_name.replace('\'', "''"))
+        } else {
+            format!("SELECT {} FROM information_schema_constraint_column_usage", all_columns.join(", "))
+        };
+
+        let response = match db.connection_manager().execute_with_session(session_id, |conn| {
+            let mut stmt = conn.prepare(&query)?;
+            let column_count = stmt.column_count();
+            let mut rows = Vec::new();
+            let mut query_rows = stmt.query([])?;
+            while let Some(row) = query_rows.next()? {
+                let mut values = Vec::new();
+                for i in 0..column_count {
+                    let value: Option<String> = row.get(i)?;
+                    values.push(value.map(|v| v.into_bytes()));
+                }
+                rows.push(values);
+            }
+            Ok(rows)
+        }) {
+            Ok(rows) => rows,
+            Err(e) => {
+                debug!("Failed to query information_schema_constraint_column_usage: {:?}", e);
+                return Ok(DbResponse {
+                    columns: selected_columns,
+                    rows: vec![],
+                    rows_affected: 0,
+                });
+            }
+        };
+
+        let mut rows = Vec::new();
+        for full_row in response {
+            let projected_row: Vec<Option<Vec<u8>>> = column_indices.iter()
+                .map(|&idx| full_row.get(idx).cloned().flatten())
+                .collect();
+            rows.push(projected_row);
+        }
+
+        let rows_affected = rows.len();
+        Ok(DbResponse {
+            columns: selected_columns,
+            rows,
+            rows_affected,
+        })
+    }
+
     pub async fn handle_information_schema_views_query_with_session(select: &Select, db: &DbHandler, session_id: &Uuid) -> Result<DbResponse, PgSqliteError> {
         debug!("Handling information_schema.views query with session");
         // Define information_schema.views columns (PostgreSQL standard)
@@ -0,0 +1,225 @@
+use rusqlite::Connection;
+use crate::PgSqliteError;
+use tracing::debug;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+// `GRANT priv[, ...] ON [TABLE|SCHEMA|SEQUENCE] name TO grantee[, ...] [WITH GRANT OPTION]`
+static GRANT_PRIVILEGE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)^GRANT\s+(.+?)\s+ON\s+(?:(TABLE|SCHEMA|SEQUENCE|DATABASE|FUNCTION)\s+)?(\S+)\s+TO\s+(.+?)(\s+WITH\s+GRANT\s+OPTION)?$"#).unwrap()
+});
+
+// `REVOKE [GRANT OPTION FOR] priv[, ...] ON [TABLE|SCHEMA|SEQUENCE] name FROM grantee[, ...]`
+static REVOKE_PRIVILEGE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)^REVOKE\s+(GRANT\s+OPTION\s+FOR\s+)?(.+?)\s+ON\s+(?:(TABLE|SCHEMA|SEQUENCE|DATABASE|FUNCTION)\s+)?(\S+)\s+FROM\s+(.+?)$"#).unwrap()
+});
+
+// `GRANT role[, ...] TO member[, ...] [WITH ADMIN OPTION]` - the role
+// membership form, distinguished from the privilege form above by having no
+// `ON` clause.
+static GRANT_ROLE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)^GRANT\s+(.+?)\s+TO\s+(.+?)(\s+WITH\s+ADMIN\s+OPTION)?$"#).unwrap()
+});
+
+// `REVOKE [ADMIN OPTION FOR] role[, ...] FROM member[, ...]`
+static REVOKE_ROLE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)^REVOKE\s+(ADMIN\s+OPTION\s+FOR\s+)?(.+?)\s+FROM\s+(.+?)$"#).unwrap()
+});
+
+fn bool_to_flag(value: bool) -> &'static str {
+    if value { "t" } else { "f" }
+}
+
+/// Split a comma-separated identifier list, trimming whitespace and
+/// surrounding double quotes from each entry. `PUBLIC` is lowercased to
+/// match the `public` pseudo-role row `__pgsqlite_roles` already carries.
+fn split_identifier_list(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(|s| s.trim().trim_matches('"').to_string())
+        .filter(|s| !s.is_empty())
+        .map(|s| if s.eq_ignore_ascii_case("PUBLIC") { "public".to_string() } else { s })
+        .collect()
+}
+
+/// Split a `GRANT`/`REVOKE` privilege list (`SELECT, INSERT` or `ALL
+/// [PRIVILEGES]`) into the individual privilege type strings stored in
+/// `__pgsqlite_privileges`. `ALL`/`ALL PRIVILEGES` is kept as the single
+/// literal `ALL`, which the `has_*_privilege()` functions treat as matching
+/// any requested privilege, rather than expanding it into the real
+/// object-specific privilege set.
+fn split_privilege_list(text: &str) -> Vec<String> {
+    let trimmed = text.trim();
+    if trimmed.eq_ignore_ascii_case("ALL") || trimmed.eq_ignore_ascii_case("ALL PRIVILEGES") {
+        return vec!["ALL".to_string()];
+    }
+
+    trimmed.split(',')
+        .map(|s| s.trim().to_uppercase())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn resolve_role_oid(conn: &Connection, rolname: &str) -> Result<i64, PgSqliteError> {
+    conn.query_row(
+        "SELECT oid FROM __pgsqlite_roles WHERE rolname = ?1",
+        rusqlite::params![rolname],
+        |row| row.get::<_, i64>(0),
+    ).map_err(|e| {
+        if matches!(e, rusqlite::Error::QueryReturnedNoRows) {
+            PgSqliteError::Protocol(format!("role \"{rolname}\" does not exist"))
+        } else {
+            PgSqliteError::Sqlite(e)
+        }
+    })
+}
+
+/// Parses and applies `GRANT`/`REVOKE` statements - both the
+/// privilege-on-object form and the role-membership form - against the
+/// `__pgsqlite_privileges` and `__pgsqlite_auth_members` catalog tables (see
+/// migration `persisted_privileges`). pgsqlite doesn't enforce any of this
+/// at execution time; it only needs to answer `has_table_privilege()` and
+/// friends (`crate::functions::system_functions`) the way a permission-aware
+/// ORM expects.
+pub struct GrantDdlHandler;
+
+impl GrantDdlHandler {
+    pub fn is_grant_ddl(query: &str) -> bool {
+        let trimmed = query.trim().to_uppercase();
+        trimmed.starts_with("GRANT") || trimmed.starts_with("REVOKE")
+    }
+
+    pub fn handle_grant_ddl(conn: &mut Connection, query: &str) -> Result<(), PgSqliteError> {
+        let trimmed = query.trim().trim_end_matches(';');
+        let is_grant = trimmed.to_uppercase().starts_with("GRANT");
+
+        // The privilege-on-object form always has an `ON` clause; the
+        // role-membership form never does.
+        let has_on_clause = GRANT_PRIVILEGE_REGEX.is_match(trimmed) || REVOKE_PRIVILEGE_REGEX.is_match(trimmed);
+
+        match (is_grant, has_on_clause) {
+            (true, true) => Self::handle_grant_privilege(conn, trimmed),
+            (true, false) => Self::handle_grant_role(conn, trimmed),
+            (false, true) => Self::handle_revoke_privilege(conn, trimmed),
+            (false, false) => Self::handle_revoke_role(conn, trimmed),
+        }
+    }
+
+    fn handle_grant_privilege(conn: &mut Connection, query: &str) -> Result<(), PgSqliteError> {
+        debug!("Parsing GRANT ... ON ...: {}", query);
+
+        let caps = GRANT_PRIVILEGE_REGEX
+            .captures(query)
+            .ok_or_else(|| PgSqliteError::Protocol("Invalid GRANT syntax".to_string()))?;
+
+        let privileges = split_privilege_list(&caps[1]);
+        let object_kind = caps.get(2).map(|m| m.as_str().to_lowercase()).unwrap_or_else(|| "table".to_string());
+        let object_name = caps[3].to_string();
+        let grantees = split_identifier_list(&caps[4]);
+        let grantable = bool_to_flag(caps.get(5).is_some());
+
+        for grantee in &grantees {
+            for privilege in &privileges {
+                conn.execute(
+                    "INSERT OR REPLACE INTO __pgsqlite_privileges (grantee, object_kind, object_name, privilege_type, grantable) \
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    rusqlite::params![grantee, object_kind, object_name, privilege, grantable],
+                ).map_err(PgSqliteError::Sqlite)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_revoke_privilege(conn: &mut Connection, query: &str) -> Result<(), PgSqliteError> {
+        debug!("Parsing REVOKE ... ON ...: {}", query);
+
+        let caps = REVOKE_PRIVILEGE_REGEX
+            .captures(query)
+            .ok_or_else(|| PgSqliteError::Protocol("Invalid REVOKE syntax".to_string()))?;
+
+        let grant_option_only = caps.get(1).is_some();
+        let privileges = split_privilege_list(&caps[2]);
+        let object_kind = caps.get(3).map(|m| m.as_str().to_lowercase()).unwrap_or_else(|| "table".to_string());
+        let object_name = caps[4].to_string();
+        let grantees = split_identifier_list(&caps[5]);
+
+        for grantee in &grantees {
+            for privilege in &privileges {
+                if grant_option_only {
+                    // `REVOKE GRANT OPTION FOR` only strips the re-grant
+                    // right, the privilege itself stays granted.
+                    conn.execute(
+                        "UPDATE __pgsqlite_privileges SET grantable = 'f' \
+                         WHERE grantee = ?1 AND object_kind = ?2 AND object_name = ?3 AND privilege_type = ?4",
+                        rusqlite::params![grantee, object_kind, object_name, privilege],
+                    ).map_err(PgSqliteError::Sqlite)?;
+                } else {
+                    conn.execute(
+                        "DELETE FROM __pgsqlite_privileges \
+                         WHERE grantee = ?1 AND object_kind = ?2 AND object_name = ?3 AND privilege_type = ?4",
+                        rusqlite::params![grantee, object_kind, object_name, privilege],
+                    ).map_err(PgSqliteError::Sqlite)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_grant_role(conn: &mut Connection, query: &str) -> Result<(), PgSqliteError> {
+        debug!("Parsing GRANT role TO role: {}", query);
+
+        let caps = GRANT_ROLE_REGEX
+            .captures(query)
+            .ok_or_else(|| PgSqliteError::Protocol("Invalid GRANT syntax".to_string()))?;
+
+        let roles = split_identifier_list(&caps[1]);
+        let members = split_identifier_list(&caps[2]);
+        let admin_option = bool_to_flag(caps.get(3).is_some());
+
+        for role in &roles {
+            let role_oid = resolve_role_oid(conn, role)?;
+            for member in &members {
+                let member_oid = resolve_role_oid(conn, member)?;
+                conn.execute(
+                    "INSERT OR REPLACE INTO __pgsqlite_auth_members (roleid, member, admin_option) VALUES (?1, ?2, ?3)",
+                    rusqlite::params![role_oid, member_oid, admin_option],
+                ).map_err(PgSqliteError::Sqlite)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_revoke_role(conn: &mut Connection, query: &str) -> Result<(), PgSqliteError> {
+        debug!("Parsing REVOKE role FROM role: {}", query);
+
+        let caps = REVOKE_ROLE_REGEX
+            .captures(query)
+            .ok_or_else(|| PgSqliteError::Protocol("Invalid REVOKE syntax".to_string()))?;
+
+        let admin_option_only = caps.get(1).is_some();
+        let roles = split_identifier_list(&caps[2]);
+        let members = split_identifier_list(&caps[3]);
+
+        for role in &roles {
+            let role_oid = resolve_role_oid(conn, role)?;
+            for member in &members {
+                let member_oid = resolve_role_oid(conn, member)?;
+                if admin_option_only {
+                    conn.execute(
+                        "UPDATE __pgsqlite_auth_members SET admin_option = 'f' WHERE roleid = ?1 AND member = ?2",
+                        rusqlite::params![role_oid, member_oid],
+                    ).map_err(PgSqliteError::Sqlite)?;
+                } else {
+                    conn.execute(
+                        "DELETE FROM __pgsqlite_auth_members WHERE roleid = ?1 AND member = ?2",
+                        rusqlite::params![role_oid, member_oid],
+                    ).map_err(PgSqliteError::Sqlite)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+// Module for non-table DDL statements that need custom handling
+pub mod comment_ddl_handler;
+pub mod role_ddl_handler;
+pub mod grant_ddl_handler;
+pub mod policy_ddl_handler;
+
+pub use comment_ddl_handler::CommentDdlHandler;
+pub use role_ddl_handler::RoleDdlHandler;
+pub use grant_ddl_handler::GrantDdlHandler;
+pub use policy_ddl_handler::PolicyDdlHandler;
@@ -0,0 +1,164 @@
+use rusqlite::Connection;
+use crate::PgSqliteError;
+use tracing::{debug, info};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+// Pre-compiled regex patterns for row-level-security DDL.
+static ALTER_TABLE_RLS_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)^ALTER\s+TABLE\s+(?:IF\s+EXISTS\s+)?"?(\w+)"?\s+(ENABLE|DISABLE|FORCE|NO\s+FORCE)\s+ROW\s+LEVEL\s+SECURITY"#).unwrap()
+});
+
+static CREATE_POLICY_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)^CREATE\s+POLICY\s+"?(\w+)"?\s+ON\s+"?(\w+)"?(.*)$"#).unwrap()
+});
+
+static DROP_POLICY_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)^DROP\s+POLICY\s+(IF\s+EXISTS\s+)?"?(\w+)"?\s+ON\s+"?(\w+)"?"#).unwrap()
+});
+
+/// Parses and applies `ALTER TABLE ... ENABLE/DISABLE/FORCE ROW LEVEL
+/// SECURITY` and `CREATE POLICY`/`DROP POLICY` against the
+/// `__pgsqlite_rls_tables`/`__pgsqlite_policies` catalog tables, so the
+/// resulting state is immediately visible through `pg_class.relrowsecurity`/
+/// `relforcerowsecurity` and the `pg_policy`/`pg_policies` relations (see
+/// `crate::migration::registry`'s `register_v26_row_level_security`).
+///
+/// This handler only covers the catalog/DDL side of RLS: parsing the
+/// statements and persisting the policy rows so introspection queries
+/// resolve. It does not rewrite SELECT/UPDATE/DELETE against a
+/// RLS-protected table to append the policy's USING/WITH CHECK predicate -
+/// that requires role-aware query rewriting in the translation layer for
+/// every DML statement, which is a much larger change than a catalog
+/// addition and is left for a follow-up.
+///
+/// Because that enforcement doesn't exist yet, `ENABLE ROW LEVEL SECURITY`
+/// and `CREATE POLICY` are rejected outright (see `handle_alter_table_rls`/
+/// `handle_create_policy`) rather than accepted and silently ignored -
+/// catalog state claiming protection is active with no predicate rewriting
+/// behind it would be worse than an error, since every row would still be
+/// visible to every query regardless of policy.
+pub struct PolicyDdlHandler;
+
+impl PolicyDdlHandler {
+    /// Check if a query is a row-level-security DDL statement this handler
+    /// covers.
+    pub fn is_policy_ddl(query: &str) -> bool {
+        let trimmed = query.trim();
+        ALTER_TABLE_RLS_REGEX.is_match(trimmed)
+            || CREATE_POLICY_REGEX.is_match(trimmed)
+            || DROP_POLICY_REGEX.is_match(trimmed)
+    }
+
+    /// Handle a row-level-security DDL statement.
+    pub fn handle_policy_ddl(conn: &mut Connection, query: &str) -> Result<(), PgSqliteError> {
+        let trimmed = query.trim().trim_end_matches(';');
+
+        if let Some(caps) = ALTER_TABLE_RLS_REGEX.captures(trimmed) {
+            return Self::handle_alter_table_rls(conn, &caps[1], &caps[2]);
+        }
+        if CREATE_POLICY_REGEX.is_match(trimmed) {
+            return Self::handle_create_policy(conn, trimmed);
+        }
+        if DROP_POLICY_REGEX.is_match(trimmed) {
+            return Self::handle_drop_policy(conn, trimmed);
+        }
+
+        Err(PgSqliteError::Protocol("Unsupported row-level-security DDL statement".to_string()))
+    }
+
+    /// Handle `ALTER TABLE ... ENABLE/DISABLE/FORCE/NO FORCE ROW LEVEL
+    /// SECURITY`, upserting the table's flags into `__pgsqlite_rls_tables`.
+    ///
+    /// `ENABLE` is rejected: nothing in the query executor rewrites
+    /// SELECT/UPDATE/DELETE to apply a policy's predicate, so flipping
+    /// `pg_class.relrowsecurity` to `t` here would tell clients rows are
+    /// being filtered when none are. `DISABLE` clears both flags, and
+    /// `FORCE`/`NO FORCE` set or clear only `forcerowsecurity`;
+    /// `rowsecurity` can therefore never become `t`.
+    fn handle_alter_table_rls(conn: &mut Connection, table_name: &str, mode: &str) -> Result<(), PgSqliteError> {
+        debug!("Parsing ALTER TABLE ... ROW LEVEL SECURITY for table {}: {}", table_name, mode);
+
+        if mode.eq_ignore_ascii_case("ENABLE") {
+            return Err(PgSqliteError::Protocol(format!(
+                "row-level security is not enforced: ENABLE ROW LEVEL SECURITY on \"{table_name}\" was rejected\nHINT: pgsqlite persists RLS catalog state (pg_class.relrowsecurity, pg_policies) but does not yet rewrite queries to apply policies; enabling it here would silently leave every row visible."
+            )));
+        }
+
+        conn.execute(
+            "INSERT INTO __pgsqlite_rls_tables (table_name, rowsecurity, forcerowsecurity)
+             VALUES (?1, 'f', 'f')
+             ON CONFLICT(table_name) DO NOTHING",
+            [table_name],
+        ).map_err(PgSqliteError::Sqlite)?;
+
+        match mode.to_uppercase().as_str() {
+            "DISABLE" => {
+                conn.execute(
+                    "UPDATE __pgsqlite_rls_tables SET rowsecurity = 'f', forcerowsecurity = 'f' WHERE table_name = ?1",
+                    [table_name],
+                ).map_err(PgSqliteError::Sqlite)?;
+            }
+            "FORCE" => {
+                conn.execute(
+                    "UPDATE __pgsqlite_rls_tables SET forcerowsecurity = 't' WHERE table_name = ?1",
+                    [table_name],
+                ).map_err(PgSqliteError::Sqlite)?;
+            }
+            _ => {
+                // "NO FORCE" - the only other form the regex's second group
+                // captures (as written, e.g. "NO  FORCE", hence no exact match).
+                conn.execute(
+                    "UPDATE __pgsqlite_rls_tables SET forcerowsecurity = 'f' WHERE table_name = ?1",
+                    [table_name],
+                ).map_err(PgSqliteError::Sqlite)?;
+            }
+        }
+
+        info!("Set row-level security ({}) on table {}", mode, table_name);
+        Ok(())
+    }
+
+    /// Handle `CREATE POLICY name ON table [AS PERMISSIVE|RESTRICTIVE] [FOR
+    /// cmd] [TO role[, ...]] [USING (expr)] [WITH CHECK (expr)]`.
+    ///
+    /// Rejected outright, for the same reason `ENABLE ROW LEVEL SECURITY`
+    /// is: persisting a policy nobody enforces would make `pg_policies`
+    /// claim protection that every query silently bypasses.
+    fn handle_create_policy(_conn: &mut Connection, query: &str) -> Result<(), PgSqliteError> {
+        let caps = CREATE_POLICY_REGEX.captures(query)
+            .ok_or_else(|| PgSqliteError::Protocol("Invalid CREATE POLICY syntax".to_string()))?;
+
+        let policy_name = caps[1].to_string();
+        let table_name = caps[2].to_string();
+
+        debug!("Rejecting CREATE POLICY {} ON {}: RLS enforcement is not implemented", policy_name, table_name);
+
+        Err(PgSqliteError::Protocol(format!(
+            "row-level security is not enforced: CREATE POLICY \"{policy_name}\" ON \"{table_name}\" was rejected\nHINT: pgsqlite does not yet rewrite queries to apply policy predicates; creating one here would silently leave every row visible."
+        )))
+    }
+
+    /// Handle `DROP POLICY [IF EXISTS] name ON table`.
+    fn handle_drop_policy(conn: &mut Connection, query: &str) -> Result<(), PgSqliteError> {
+        let caps = DROP_POLICY_REGEX.captures(query)
+            .ok_or_else(|| PgSqliteError::Protocol("Invalid DROP POLICY syntax".to_string()))?;
+
+        let if_exists = caps.get(1).is_some();
+        let policy_name = caps[2].to_string();
+        let table_name = caps[3].to_string();
+
+        let changed = conn.execute(
+            "DELETE FROM __pgsqlite_policies WHERE policy_name = ?1 AND table_name = ?2",
+            rusqlite::params![policy_name, table_name],
+        ).map_err(PgSqliteError::Sqlite)?;
+
+        if changed == 0 && !if_exists {
+            return Err(PgSqliteError::Protocol(format!(
+                "policy \"{policy_name}\" for table \"{table_name}\" does not exist"
+            )));
+        }
+
+        Ok(())
+    }
+}
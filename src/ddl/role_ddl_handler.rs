@@ -0,0 +1,312 @@
+use rusqlite::Connection;
+use crate::PgSqliteError;
+use crate::utils::oid_registry::{allocate_oid, OidKind};
+use tracing::{debug, info};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+// Pre-compiled regex patterns for CREATE/ALTER/DROP ROLE (and their USER aliases)
+static CREATE_ROLE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)^CREATE\s+(?:ROLE|USER)\s+"?(\w+)"?\s*(?:WITH)?(.*)$"#).unwrap()
+});
+
+static ALTER_ROLE_RENAME_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)^ALTER\s+(?:ROLE|USER)\s+"?(\w+)"?\s+RENAME\s+TO\s+"?(\w+)"?\s*;?\s*$"#).unwrap()
+});
+
+static ALTER_ROLE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)^ALTER\s+(?:ROLE|USER)\s+"?(\w+)"?\s*(?:WITH)?(.*)$"#).unwrap()
+});
+
+static DROP_ROLE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)^DROP\s+(?:ROLE|USER)\s+(IF\s+EXISTS\s+)?(.+?);?\s*$"#).unwrap()
+});
+
+static PASSWORD_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)(?:ENCRYPTED\s+)?PASSWORD\s+(?:'((?:''|[^'])*)'|NULL)"#).unwrap()
+});
+
+static VALID_UNTIL_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)VALID\s+UNTIL\s+'((?:''|[^'])*)'"#).unwrap()
+});
+
+static CONNECTION_LIMIT_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)CONNECTION\s+LIMIT\s+(-?\d+)"#).unwrap()
+});
+
+// Membership lists (`IN ROLE a, b`, `IN GROUP a`, `ROLE a`, `ADMIN a`, `USER
+// a`) are parsed (to keep them from being misread as boolean attribute
+// keywords below) and discarded rather than rejected outright: CREATE/ALTER
+// ROLE does not write them to `__pgsqlite_auth_members`; only `GRANT role TO
+// role` (see grant_ddl_handler.rs) records memberships there.
+static MEMBERSHIP_LIST_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)\b(?:IN\s+ROLE|IN\s+GROUP|ROLE|ADMIN|USER)\s+"?\w+"?(?:\s*,\s*"?\w+"?)*"#).unwrap()
+});
+
+static ROLE_FLAG_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)\b(NO)?(SUPERUSER|CREATEDB|CREATEROLE|INHERIT|LOGIN|REPLICATION|BYPASSRLS)\b"#).unwrap()
+});
+
+/// Parsed `CREATE`/`ALTER ROLE` attributes. Each field is `None` when the
+/// statement didn't mention it, so `ALTER ROLE` only touches the columns it
+/// was actually asked to change and `CREATE ROLE` can fall back to
+/// PostgreSQL's own defaults.
+#[derive(Default)]
+struct RoleAttributes {
+    rolsuper: Option<bool>,
+    rolinherit: Option<bool>,
+    rolcreaterole: Option<bool>,
+    rolcreatedb: Option<bool>,
+    rolcanlogin: Option<bool>,
+    rolreplication: Option<bool>,
+    rolbypassrls: Option<bool>,
+    rolconnlimit: Option<i32>,
+    rolpassword: Option<Option<String>>,
+    rolvaliduntil: Option<Option<String>>,
+}
+
+fn unescape_sql_string(raw: &str) -> String {
+    raw.replace("''", "'")
+}
+
+fn bool_to_flag(value: bool) -> &'static str {
+    if value { "t" } else { "f" }
+}
+
+fn apply_flag(attrs: &mut RoleAttributes, keyword: &str, negated: bool) {
+    let value = !negated;
+    match keyword.to_uppercase().as_str() {
+        "SUPERUSER" => attrs.rolsuper = Some(value),
+        "CREATEDB" => attrs.rolcreatedb = Some(value),
+        "CREATEROLE" => attrs.rolcreaterole = Some(value),
+        "INHERIT" => attrs.rolinherit = Some(value),
+        "LOGIN" => attrs.rolcanlogin = Some(value),
+        "REPLICATION" => attrs.rolreplication = Some(value),
+        "BYPASSRLS" => attrs.rolbypassrls = Some(value),
+        _ => {}
+    }
+}
+
+/// Parse the attribute list following a role name in `CREATE`/`ALTER ROLE`.
+fn parse_role_attributes(options_text: &str) -> RoleAttributes {
+    let mut attrs = RoleAttributes::default();
+    let mut remaining = options_text.to_string();
+
+    if let Some(caps) = PASSWORD_REGEX.captures(&remaining) {
+        let full_match = caps.get(0).unwrap().as_str().to_string();
+        attrs.rolpassword = Some(caps.get(1).map(|m| unescape_sql_string(m.as_str())));
+        remaining = remaining.replacen(&full_match, "", 1);
+    }
+
+    if let Some(caps) = VALID_UNTIL_REGEX.captures(&remaining) {
+        let full_match = caps.get(0).unwrap().as_str().to_string();
+        let raw = unescape_sql_string(&caps[1]);
+        attrs.rolvaliduntil = Some(if raw.eq_ignore_ascii_case("infinity") { None } else { Some(raw) });
+        remaining = remaining.replacen(&full_match, "", 1);
+    }
+
+    if let Some(caps) = CONNECTION_LIMIT_REGEX.captures(&remaining) {
+        let full_match = caps.get(0).unwrap().as_str().to_string();
+        attrs.rolconnlimit = caps[1].parse::<i32>().ok();
+        remaining = remaining.replacen(&full_match, "", 1);
+    }
+
+    remaining = MEMBERSHIP_LIST_REGEX.replace_all(&remaining, "").to_string();
+
+    for caps in ROLE_FLAG_REGEX.captures_iter(&remaining) {
+        let negated = caps.get(1).is_some();
+        let keyword = &caps[2];
+        apply_flag(&mut attrs, keyword, negated);
+    }
+
+    attrs
+}
+
+/// Parses and applies `CREATE ROLE`/`CREATE USER`, `ALTER ROLE`/`ALTER
+/// USER`, and `DROP ROLE`/`DROP USER` statements against the
+/// `__pgsqlite_roles` catalog table, so ORM bootstrap scripts that issue
+/// these statements succeed and the new/updated rows immediately appear in
+/// `pg_roles`/`pg_user` (see `crate::catalog::pg_roles`,
+/// `crate::catalog::pg_user`).
+pub struct RoleDdlHandler;
+
+impl RoleDdlHandler {
+    /// Check if a query is a role-management DDL statement.
+    pub fn is_role_ddl(query: &str) -> bool {
+        let trimmed = query.trim().to_uppercase();
+        trimmed.starts_with("CREATE ROLE") || trimmed.starts_with("CREATE USER") ||
+        trimmed.starts_with("ALTER ROLE") || trimmed.starts_with("ALTER USER") ||
+        trimmed.starts_with("DROP ROLE") || trimmed.starts_with("DROP USER")
+    }
+
+    /// Handle a role-management DDL statement.
+    pub fn handle_role_ddl(conn: &mut Connection, query: &str) -> Result<(), PgSqliteError> {
+        let trimmed = query.trim().to_uppercase();
+
+        if trimmed.starts_with("CREATE ROLE") || trimmed.starts_with("CREATE USER") {
+            Self::handle_create_role(conn, query)
+        } else if trimmed.starts_with("ALTER ROLE") || trimmed.starts_with("ALTER USER") {
+            Self::handle_alter_role(conn, query)
+        } else if trimmed.starts_with("DROP ROLE") || trimmed.starts_with("DROP USER") {
+            Self::handle_drop_role(conn, query)
+        } else {
+            Err(PgSqliteError::Protocol("Unsupported role DDL statement".to_string()))
+        }
+    }
+
+    /// Handle `CREATE ROLE`/`CREATE USER`. `CREATE USER` differs from
+    /// `CREATE ROLE` only in that it defaults `LOGIN` to true instead of
+    /// false, matching PostgreSQL.
+    fn handle_create_role(conn: &mut Connection, query: &str) -> Result<(), PgSqliteError> {
+        debug!("Parsing CREATE ROLE/USER: {}", query);
+
+        let trimmed = query.trim().trim_end_matches(';');
+        let caps = CREATE_ROLE_REGEX
+            .captures(trimmed)
+            .ok_or_else(|| PgSqliteError::Protocol("Invalid CREATE ROLE/USER syntax".to_string()))?;
+
+        let is_user = trimmed.to_uppercase().starts_with("CREATE USER");
+        let rolname = caps[1].to_string();
+        let attrs = parse_role_attributes(&caps[2]);
+
+        info!("Creating role '{}'", rolname);
+
+        let oid = allocate_oid(conn, OidKind::Role, &rolname).map_err(PgSqliteError::Sqlite)?;
+
+        conn.execute(
+            "INSERT INTO __pgsqlite_roles \
+                (oid, rolname, rolsuper, rolinherit, rolcreaterole, rolcreatedb, rolcanlogin, rolreplication, rolbypassrls, rolconnlimit, rolpassword, rolvaliduntil) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+            rusqlite::params![
+                oid.get(),
+                rolname,
+                bool_to_flag(attrs.rolsuper.unwrap_or(false)),
+                bool_to_flag(attrs.rolinherit.unwrap_or(true)),
+                bool_to_flag(attrs.rolcreaterole.unwrap_or(false)),
+                bool_to_flag(attrs.rolcreatedb.unwrap_or(false)),
+                bool_to_flag(attrs.rolcanlogin.unwrap_or(is_user)),
+                bool_to_flag(attrs.rolreplication.unwrap_or(false)),
+                bool_to_flag(attrs.rolbypassrls.unwrap_or(false)),
+                attrs.rolconnlimit.unwrap_or(-1),
+                attrs.rolpassword.flatten(),
+                attrs.rolvaliduntil.flatten(),
+            ],
+        ).map_err(|e| {
+            if e.to_string().contains("UNIQUE") {
+                PgSqliteError::Protocol(format!("role \"{rolname}\" already exists"))
+            } else {
+                PgSqliteError::Sqlite(e)
+            }
+        })?;
+
+        Ok(())
+    }
+
+    /// Handle `ALTER ROLE`/`ALTER USER`, including the `RENAME TO` form.
+    fn handle_alter_role(conn: &mut Connection, query: &str) -> Result<(), PgSqliteError> {
+        debug!("Parsing ALTER ROLE/USER: {}", query);
+
+        let trimmed = query.trim().trim_end_matches(';');
+
+        if let Some(caps) = ALTER_ROLE_RENAME_REGEX.captures(trimmed) {
+            let old_name = caps[1].to_string();
+            let new_name = caps[2].to_string();
+
+            let changed = conn.execute(
+                "UPDATE __pgsqlite_roles SET rolname = ?1 WHERE rolname = ?2",
+                rusqlite::params![new_name, old_name],
+            ).map_err(PgSqliteError::Sqlite)?;
+
+            if changed == 0 {
+                return Err(PgSqliteError::Protocol(format!("role \"{old_name}\" does not exist")));
+            }
+
+            return Ok(());
+        }
+
+        let caps = ALTER_ROLE_REGEX
+            .captures(trimmed)
+            .ok_or_else(|| PgSqliteError::Protocol("Invalid ALTER ROLE/USER syntax".to_string()))?;
+
+        let rolname = caps[1].to_string();
+        let attrs = parse_role_attributes(&caps[2]);
+
+        let mut assignments = Vec::new();
+        let mut params: Vec<rusqlite::types::Value> = Vec::new();
+
+        macro_rules! set_column {
+            ($column:literal, $value:expr) => {
+                assignments.push(format!("{} = ?{}", $column, params.len() + 1));
+                params.push($value);
+            };
+        }
+
+        if let Some(v) = attrs.rolsuper { set_column!("rolsuper", rusqlite::types::Value::Text(bool_to_flag(v).to_string())); }
+        if let Some(v) = attrs.rolinherit { set_column!("rolinherit", rusqlite::types::Value::Text(bool_to_flag(v).to_string())); }
+        if let Some(v) = attrs.rolcreaterole { set_column!("rolcreaterole", rusqlite::types::Value::Text(bool_to_flag(v).to_string())); }
+        if let Some(v) = attrs.rolcreatedb { set_column!("rolcreatedb", rusqlite::types::Value::Text(bool_to_flag(v).to_string())); }
+        if let Some(v) = attrs.rolcanlogin { set_column!("rolcanlogin", rusqlite::types::Value::Text(bool_to_flag(v).to_string())); }
+        if let Some(v) = attrs.rolreplication { set_column!("rolreplication", rusqlite::types::Value::Text(bool_to_flag(v).to_string())); }
+        if let Some(v) = attrs.rolbypassrls { set_column!("rolbypassrls", rusqlite::types::Value::Text(bool_to_flag(v).to_string())); }
+        if let Some(v) = attrs.rolconnlimit { set_column!("rolconnlimit", rusqlite::types::Value::Integer(v as i64)); }
+        if let Some(pw) = attrs.rolpassword {
+            set_column!("rolpassword", pw.map(rusqlite::types::Value::Text).unwrap_or(rusqlite::types::Value::Null));
+        }
+        if let Some(vu) = attrs.rolvaliduntil {
+            set_column!("rolvaliduntil", vu.map(rusqlite::types::Value::Text).unwrap_or(rusqlite::types::Value::Null));
+        }
+
+        if assignments.is_empty() {
+            // `ALTER ROLE name;` with no recognized options - nothing to do.
+            return Ok(());
+        }
+
+        params.push(rusqlite::types::Value::Text(rolname.clone()));
+        let sql = format!(
+            "UPDATE __pgsqlite_roles SET {} WHERE rolname = ?{}",
+            assignments.join(", "),
+            params.len()
+        );
+
+        let changed = conn.execute(&sql, rusqlite::params_from_iter(params.iter()))
+            .map_err(PgSqliteError::Sqlite)?;
+
+        if changed == 0 {
+            return Err(PgSqliteError::Protocol(format!("role \"{rolname}\" does not exist")));
+        }
+
+        Ok(())
+    }
+
+    /// Handle `DROP ROLE`/`DROP USER`, including a comma-separated list of
+    /// role names and `IF EXISTS`.
+    fn handle_drop_role(conn: &mut Connection, query: &str) -> Result<(), PgSqliteError> {
+        debug!("Parsing DROP ROLE/USER: {}", query);
+
+        let trimmed = query.trim();
+        let caps = DROP_ROLE_REGEX
+            .captures(trimmed)
+            .ok_or_else(|| PgSqliteError::Protocol("Invalid DROP ROLE/USER syntax".to_string()))?;
+
+        let if_exists = caps.get(1).is_some();
+        let names = caps[2].to_string();
+
+        for raw_name in names.split(',') {
+            let rolname = raw_name.trim().trim_matches('"');
+            if rolname.is_empty() {
+                continue;
+            }
+
+            let changed = conn.execute(
+                "DELETE FROM __pgsqlite_roles WHERE rolname = ?1",
+                rusqlite::params![rolname],
+            ).map_err(PgSqliteError::Sqlite)?;
+
+            if changed == 0 && !if_exists {
+                return Err(PgSqliteError::Protocol(format!("role \"{rolname}\" does not exist")));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -23,12 +23,23 @@ pub fn register_array_functions(conn: &Connection) -> Result<()> {
     
     // Array utility functions
     register_array_slice(conn)?;
+    register_array_subscript(conn)?;
     register_array_position(conn)?;
     register_array_positions(conn)?;
     
-    // Array aggregate function
+    // Array aggregate functions
     register_array_agg(conn)?;
-    
+    register_array_agg_ordered(conn)?;
+    register_array_agg_distinct(conn)?;
+
+    // JSONPath query functions
+    register_array_query(conn)?;
+    register_array_path_exists(conn)?;
+
+    // Binary intermediate encoding bridges
+    register_array_to_binary(conn)?;
+    register_array_from_binary(conn)?;
+
     Ok(())
 }
 
@@ -154,33 +165,37 @@ fn register_array_ndims(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
-/// array_append(array, element) - Append element to array
+/// array_append(array, element) - Append element to array. `array` may be
+/// either JSON TEXT or a binary-encoded BLOB (see [`read_array_argument`]);
+/// the result is re-encoded in whichever form it arrived in, so a chain of
+/// array calls never round-trips through JSON between steps.
 fn register_array_append(conn: &Connection) -> Result<()> {
     conn.create_scalar_function(
         "array_append",
         2,
         FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
         |ctx| {
-            let array_json: String = ctx.get(0)?;
+            let Some((value, binary)) = read_array_argument(ctx, 0) else { return Ok(None) };
             let element: String = ctx.get(1)?;
-            
-            match serde_json::from_str::<JsonValue>(&array_json) {
-                Ok(JsonValue::Array(mut arr)) => {
+
+            match value {
+                JsonValue::Array(mut arr) => {
                     // Parse element as JSON if possible, otherwise as string
                     let elem_value = serde_json::from_str::<JsonValue>(&element)
                         .unwrap_or_else(|_| JsonValue::String(element));
                     arr.push(elem_value);
-                    Ok(serde_json::to_string(&arr).ok())
+                    Ok(Some(array_value_to_sql(&JsonValue::Array(arr), binary)))
                 }
                 _ => Ok(None),
             }
         },
     )?;
-    
+
     Ok(())
 }
 
-/// array_prepend(element, array) - Prepend element to array
+/// array_prepend(element, array) - Prepend element to array. See
+/// [`register_array_append`] for the binary/text dual-format handling.
 fn register_array_prepend(conn: &Connection) -> Result<()> {
     conn.create_scalar_function(
         "array_prepend",
@@ -188,76 +203,76 @@ fn register_array_prepend(conn: &Connection) -> Result<()> {
         FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
         |ctx| {
             let element: String = ctx.get(0)?;
-            let array_json: String = ctx.get(1)?;
-            
-            match serde_json::from_str::<JsonValue>(&array_json) {
-                Ok(JsonValue::Array(mut arr)) => {
+            let Some((value, binary)) = read_array_argument(ctx, 1) else { return Ok(None) };
+
+            match value {
+                JsonValue::Array(mut arr) => {
                     // Parse element as JSON if possible, otherwise as string
                     let elem_value = serde_json::from_str::<JsonValue>(&element)
                         .unwrap_or_else(|_| JsonValue::String(element));
                     arr.insert(0, elem_value);
-                    Ok(serde_json::to_string(&arr).ok())
+                    Ok(Some(array_value_to_sql(&JsonValue::Array(arr), binary)))
                 }
                 _ => Ok(None),
             }
         },
     )?;
-    
+
     Ok(())
 }
 
-/// array_cat(array1, array2) - Concatenate two arrays
+/// array_cat(array1, array2) - Concatenate two arrays. The result is encoded
+/// in whichever format `array1` arrived in; see
+/// [`register_array_append`].
 fn register_array_cat(conn: &Connection) -> Result<()> {
     conn.create_scalar_function(
         "array_cat",
         2,
         FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
         |ctx| {
-            let array1_json: String = ctx.get(0)?;
-            let array2_json: String = ctx.get(1)?;
-            
-            match (
-                serde_json::from_str::<JsonValue>(&array1_json),
-                serde_json::from_str::<JsonValue>(&array2_json),
-            ) {
-                (Ok(JsonValue::Array(mut arr1)), Ok(JsonValue::Array(arr2))) => {
+            let Some((value1, binary)) = read_array_argument(ctx, 0) else { return Ok(None) };
+            let Some((value2, _)) = read_array_argument(ctx, 1) else { return Ok(None) };
+
+            match (value1, value2) {
+                (JsonValue::Array(mut arr1), JsonValue::Array(arr2)) => {
                     arr1.extend(arr2);
-                    Ok(serde_json::to_string(&arr1).ok())
+                    Ok(Some(array_value_to_sql(&JsonValue::Array(arr1), binary)))
                 }
                 _ => Ok(None),
             }
         },
     )?;
-    
+
     Ok(())
 }
 
-/// array_remove(array, element) - Remove all occurrences of element
+/// array_remove(array, element) - Remove all occurrences of element. See
+/// [`register_array_append`] for the binary/text dual-format handling.
 fn register_array_remove(conn: &Connection) -> Result<()> {
     conn.create_scalar_function(
         "array_remove",
         2,
         FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
         |ctx| {
-            let array_json: String = ctx.get(0)?;
+            let Some((value, binary)) = read_array_argument(ctx, 0) else { return Ok(None) };
             let element: String = ctx.get(1)?;
-            
-            match serde_json::from_str::<JsonValue>(&array_json) {
-                Ok(JsonValue::Array(arr)) => {
+
+            match value {
+                JsonValue::Array(arr) => {
                     let elem_value = serde_json::from_str::<JsonValue>(&element)
                         .unwrap_or_else(|_| JsonValue::String(element.clone()));
-                    
+
                     let filtered: Vec<JsonValue> = arr.into_iter()
                         .filter(|v| v != &elem_value)
                         .collect();
-                    
-                    Ok(serde_json::to_string(&filtered).ok())
+
+                    Ok(Some(array_value_to_sql(&JsonValue::Array(filtered), binary)))
                 }
                 _ => Ok(None),
             }
         },
     )?;
-    
+
     Ok(())
 }
 
@@ -381,31 +396,99 @@ fn register_array_slice(conn: &Connection) -> Result<()> {
         3,
         FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
         |ctx| {
-            let array_json: String = ctx.get(0)?;
+            let Some((value, binary)) = read_array_argument(ctx, 0) else { return Ok(None) };
             let start: i32 = ctx.get(1)?;
             let end: i32 = ctx.get(2)?;
-            
-            match serde_json::from_str::<JsonValue>(&array_json) {
-                Ok(JsonValue::Array(arr)) => {
-                    // Convert 1-based PostgreSQL indices to 0-based
-                    let start_idx = (start - 1).max(0) as usize;
-                    let end_idx = end.min(arr.len() as i32) as usize;
-                    
-                    if start_idx < arr.len() && start_idx < end_idx {
-                        let slice: Vec<JsonValue> = arr[start_idx..end_idx].to_vec();
-                        Ok(serde_json::to_string(&slice).ok())
+
+            match value {
+                JsonValue::Array(arr) => {
+                    let total = arr.len() as i32;
+                    // `start`/`end` are both 1-based and inclusive; negative
+                    // values count from the end (-1 is the last element).
+                    let start_idx = subscript_to_zero_based(start, total).clamp(0, total);
+                    let end_idx = (subscript_to_zero_based(end, total) + 1).clamp(0, total);
+
+                    let slice = if start_idx < end_idx {
+                        arr[start_idx as usize..end_idx as usize].to_vec()
                     } else {
-                        Ok(Some("[]".to_string()))
+                        vec![]
+                    };
+                    Ok(Some(array_value_to_sql(&JsonValue::Array(slice), binary)))
+                }
+                _ => Ok(None),
+            }
+        },
+    )?;
+
+    Ok(())
+}
+
+/// array_subscript(array, i) - Element accessor with the same 1-based,
+/// negative-from-end indexing as `array_slice`. An index outside the
+/// array's bounds returns NULL, matching PostgreSQL's out-of-range
+/// subscript behavior rather than erroring.
+fn register_array_subscript(conn: &Connection) -> Result<()> {
+    conn.create_scalar_function(
+        "array_subscript",
+        2,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let Some((value, _binary)) = read_array_argument(ctx, 0) else { return Ok(None) };
+            let index: i32 = ctx.get(1)?;
+
+            match value {
+                JsonValue::Array(arr) => {
+                    let total = arr.len() as i32;
+                    let pos = subscript_to_zero_based(index, total);
+                    if pos >= 0 && pos < total {
+                        Ok(Some(json_scalar_to_sql(&arr[pos as usize])))
+                    } else {
+                        Ok(None)
                     }
                 }
                 _ => Ok(None),
             }
         },
     )?;
-    
+
     Ok(())
 }
 
+/// Normalize a 1-based PostgreSQL array subscript to a 0-based position:
+/// `i - 1` when positive, `total + i` when negative (so `-1` is the last
+/// element, `-2` the second-to-last, ...). The caller bounds-checks the
+/// result - an index of `0`, or one past either end, normalizes to a
+/// position outside `[0, total)`.
+fn subscript_to_zero_based(index: i32, total: i32) -> i32 {
+    if index > 0 {
+        index - 1
+    } else {
+        total + index
+    }
+}
+
+/// Unwrap a JSON scalar into the native SQLite value PostgreSQL clients
+/// expect back from an array element access, rather than a JSON-quoted
+/// string. Composite elements (nested arrays/objects) still go out as JSON
+/// text, since SQLite has no richer type to carry them in.
+fn json_scalar_to_sql(value: &JsonValue) -> rusqlite::types::Value {
+    match value {
+        JsonValue::Null => rusqlite::types::Value::Null,
+        JsonValue::Bool(b) => rusqlite::types::Value::Integer(if *b { 1 } else { 0 }),
+        JsonValue::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                rusqlite::types::Value::Integer(i)
+            } else {
+                rusqlite::types::Value::Real(n.as_f64().unwrap_or(0.0))
+            }
+        }
+        JsonValue::String(s) => rusqlite::types::Value::Text(s.clone()),
+        JsonValue::Array(_) | JsonValue::Object(_) => {
+            rusqlite::types::Value::Text(serde_json::to_string(value).unwrap_or_else(|_| "null".to_string()))
+        }
+    }
+}
+
 /// array_position(array, element) - Find position of element (1-based)
 fn register_array_position(conn: &Connection) -> Result<()> {
     conn.create_scalar_function(
@@ -477,49 +560,636 @@ fn register_array_positions(conn: &Connection) -> Result<()> {
 /// array_agg aggregate function
 fn register_array_agg(conn: &Connection) -> Result<()> {
     use rusqlite::functions::Aggregate;
-    
+
     #[derive(Default)]
     struct ArrayAgg;
-    
+
     impl Aggregate<Vec<JsonValue>, Option<String>> for ArrayAgg {
         fn init(&self, _: &mut rusqlite::functions::Context<'_>) -> Result<Vec<JsonValue>> {
             Ok(Vec::new())
         }
-        
+
         fn step(&self, ctx: &mut rusqlite::functions::Context<'_>, agg: &mut Vec<JsonValue>) -> Result<()> {
-            let value = ctx.get_raw(0);
-            
-            let json_value = match value {
-                rusqlite::types::ValueRef::Null => JsonValue::Null,
-                rusqlite::types::ValueRef::Integer(i) => json!(i),
-                rusqlite::types::ValueRef::Real(f) => json!(f),
-                rusqlite::types::ValueRef::Text(s) => {
-                    // Try to parse as JSON first, otherwise use as string
-                    let text = std::str::from_utf8(s).unwrap_or("");
-                    serde_json::from_str(text)
-                        .unwrap_or_else(|_| JsonValue::String(text.to_string()))
-                }
-                rusqlite::types::ValueRef::Blob(b) => {
-                    JsonValue::String(format!("\\x{}", hex::encode(b)))
-                }
-            };
-            
-            agg.push(json_value);
+            agg.push(raw_value_to_json(ctx.get_raw(0)));
             Ok(())
         }
-        
+
         fn finalize(&self, _: &mut rusqlite::functions::Context<'_>, agg: Option<Vec<JsonValue>>) -> Result<Option<String>> {
             Ok(agg.map(|values| serde_json::to_string(&values).unwrap_or_else(|_| "[]".to_string())))
         }
     }
-    
+
     conn.create_aggregate_function(
         "array_agg",
         1,
         FunctionFlags::SQLITE_UTF8,
         ArrayAgg,
     )?;
-    
+
+    Ok(())
+}
+
+/// array_agg_ordered(value, sortkey) - `array_agg(expr ORDER BY key)`'s
+/// companion aggregate. SQLite's aggregate API has no concept of an
+/// aggregate-level `ORDER BY`, so the query rewriter is expected to target
+/// this function directly, passing the `ORDER BY` expression as the second
+/// argument; `step` stashes `(sortkey, value)` pairs and `finalize` sorts by
+/// the key before projecting the values back out.
+fn register_array_agg_ordered(conn: &Connection) -> Result<()> {
+    use rusqlite::functions::Aggregate;
+
+    #[derive(Default)]
+    struct ArrayAggOrdered;
+
+    impl Aggregate<Vec<(JsonValue, JsonValue)>, Option<String>> for ArrayAggOrdered {
+        fn init(&self, _: &mut rusqlite::functions::Context<'_>) -> Result<Vec<(JsonValue, JsonValue)>> {
+            Ok(Vec::new())
+        }
+
+        fn step(&self, ctx: &mut rusqlite::functions::Context<'_>, agg: &mut Vec<(JsonValue, JsonValue)>) -> Result<()> {
+            let value = raw_value_to_json(ctx.get_raw(0));
+            let sortkey = raw_value_to_json(ctx.get_raw(1));
+            agg.push((sortkey, value));
+            Ok(())
+        }
+
+        fn finalize(&self, _: &mut rusqlite::functions::Context<'_>, agg: Option<Vec<(JsonValue, JsonValue)>>) -> Result<Option<String>> {
+            Ok(agg.map(|mut pairs| {
+                pairs.sort_by(|(a, _), (b, _)| compare_pg_sort_value(a, b));
+                let values: Vec<JsonValue> = pairs.into_iter().map(|(_, value)| value).collect();
+                serde_json::to_string(&values).unwrap_or_else(|_| "[]".to_string())
+            }))
+        }
+    }
+
+    conn.create_aggregate_function(
+        "array_agg_ordered",
+        2,
+        FunctionFlags::SQLITE_UTF8,
+        ArrayAggOrdered,
+    )?;
+
+    Ok(())
+}
+
+/// array_agg_distinct(value) - `array_agg(DISTINCT expr)`'s companion
+/// aggregate: the query rewriter targets this directly when it sees
+/// `DISTINCT` inside an `array_agg` call. `step` tracks every value's
+/// serialized form in a set so a duplicate is dropped the first time it
+/// would otherwise be pushed, preserving first-seen order.
+fn register_array_agg_distinct(conn: &Connection) -> Result<()> {
+    use rusqlite::functions::Aggregate;
+    use std::collections::HashSet;
+
+    #[derive(Default)]
+    struct ArrayAggDistinct;
+
+    impl Aggregate<(Vec<JsonValue>, HashSet<String>), Option<String>> for ArrayAggDistinct {
+        fn init(&self, _: &mut rusqlite::functions::Context<'_>) -> Result<(Vec<JsonValue>, HashSet<String>)> {
+            Ok((Vec::new(), HashSet::new()))
+        }
+
+        fn step(&self, ctx: &mut rusqlite::functions::Context<'_>, agg: &mut (Vec<JsonValue>, HashSet<String>)) -> Result<()> {
+            let value = raw_value_to_json(ctx.get_raw(0));
+            let key = serde_json::to_string(&value).unwrap_or_default();
+            if agg.1.insert(key) {
+                agg.0.push(value);
+            }
+            Ok(())
+        }
+
+        fn finalize(&self, _: &mut rusqlite::functions::Context<'_>, agg: Option<(Vec<JsonValue>, HashSet<String>)>) -> Result<Option<String>> {
+            Ok(agg.map(|(values, _seen)| serde_json::to_string(&values).unwrap_or_else(|_| "[]".to_string())))
+        }
+    }
+
+    conn.create_aggregate_function(
+        "array_agg_distinct",
+        1,
+        FunctionFlags::SQLITE_UTF8,
+        ArrayAggDistinct,
+    )?;
+
+    Ok(())
+}
+
+/// Decode a raw SQLite aggregate-step value into JSON, parsing TEXT as JSON
+/// when possible (so an already-JSON element, e.g. from a nested array
+/// column, doesn't get double-encoded as a string) and falling back to a
+/// `\x`-prefixed hex string for BLOBs.
+fn raw_value_to_json(value: rusqlite::types::ValueRef) -> JsonValue {
+    match value {
+        rusqlite::types::ValueRef::Null => JsonValue::Null,
+        rusqlite::types::ValueRef::Integer(i) => json!(i),
+        rusqlite::types::ValueRef::Real(f) => json!(f),
+        rusqlite::types::ValueRef::Text(s) => {
+            let text = std::str::from_utf8(s).unwrap_or("");
+            serde_json::from_str(text).unwrap_or_else(|_| JsonValue::String(text.to_string()))
+        }
+        rusqlite::types::ValueRef::Blob(b) => JsonValue::String(format!("\\x{}", hex::encode(b))),
+    }
+}
+
+/// Order two sort keys the way PostgreSQL orders an `ORDER BY` clause by
+/// default: NULLs sort last, numbers compare numerically, strings compare
+/// lexically. A comparison across genuinely different non-null types has no
+/// well-defined PostgreSQL behavior here (the real ORDER BY would have
+/// required matching types), so it falls back to comparing the JSON text
+/// form rather than panicking.
+fn compare_pg_sort_value(a: &JsonValue, b: &JsonValue) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    match (a, b) {
+        (JsonValue::Null, JsonValue::Null) => Ordering::Equal,
+        (JsonValue::Null, _) => Ordering::Greater,
+        (_, JsonValue::Null) => Ordering::Less,
+        (JsonValue::Number(x), JsonValue::Number(y)) => x
+            .as_f64()
+            .unwrap_or(0.0)
+            .partial_cmp(&y.as_f64().unwrap_or(0.0))
+            .unwrap_or(Ordering::Equal),
+        (JsonValue::String(x), JsonValue::String(y)) => x.cmp(y),
+        _ => a.to_string().cmp(&b.to_string()),
+    }
+}
+
+/// array_query(array_json, path) - Evaluate a JSONPath expression against a
+/// stored JSON array/object and return the matching elements as a JSON
+/// array. A path that resolves to no nodes yields `[]`, not NULL, so callers
+/// can always treat the result as an array.
+fn register_array_query(conn: &Connection) -> Result<()> {
+    conn.create_scalar_function(
+        "array_query",
+        2,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let array_json: String = ctx.get(0)?;
+            let path: String = ctx.get(1)?;
+
+            match serde_json::from_str::<JsonValue>(&array_json) {
+                Ok(value) => {
+                    let matches: Vec<JsonValue> = evaluate_json_path(&value, &path)
+                        .into_iter()
+                        .cloned()
+                        .collect();
+                    Ok(serde_json::to_string(&matches).ok())
+                }
+                _ => Ok(None),
+            }
+        },
+    )?;
+
+    Ok(())
+}
+
+/// array_path_exists(array_json, path) - Whether a JSONPath expression
+/// matches at least one node in the stored JSON array/object.
+fn register_array_path_exists(conn: &Connection) -> Result<()> {
+    conn.create_scalar_function(
+        "array_path_exists",
+        2,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let array_json: String = ctx.get(0)?;
+            let path: String = ctx.get(1)?;
+
+            match serde_json::from_str::<JsonValue>(&array_json) {
+                Ok(value) => Ok(!evaluate_json_path(&value, &path).is_empty()),
+                _ => Ok(false),
+            }
+        },
+    )?;
+
+    Ok(())
+}
+
+/// One step of a parsed JSONPath expression.
+enum JsonPathStep {
+    /// `.name` - descend into an object field
+    Child(String),
+    /// `[*]` / `.*` - expand every array element or object value
+    Wildcard,
+    /// `[n]` - a single array index; negative counts from the end
+    Index(i64),
+    /// `[start:end]` - an array slice; either bound may be omitted
+    Slice(Option<i64>, Option<i64>),
+    /// `[?(@.field <op> literal)]` - keep array elements whose field compares
+    /// true against a literal. An empty field name means the element itself.
+    Filter(String, JsonPathOp, JsonValue),
+}
+
+#[derive(Clone, Copy)]
+enum JsonPathOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// Tokenize a JSONPath expression (`$.a[0].b[*]`, `$[?(@.x >= 1)]`, ...) into
+/// a sequence of steps. Unrecognized bracket contents are treated as a
+/// wildcard rather than reported as an error; a path never fails to parse.
+fn parse_json_path(path: &str) -> Vec<JsonPathStep> {
+    let chars: Vec<char> = path.strip_prefix('$').unwrap_or(path).chars().collect();
+    let mut steps = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '.' => {
+                i += 1;
+                if chars.get(i) == Some(&'*') {
+                    steps.push(JsonPathStep::Wildcard);
+                    i += 1;
+                } else {
+                    let start = i;
+                    while i < chars.len() && chars[i] != '.' && chars[i] != '[' {
+                        i += 1;
+                    }
+                    let name: String = chars[start..i].iter().collect();
+                    if !name.is_empty() {
+                        steps.push(JsonPathStep::Child(name));
+                    }
+                }
+            }
+            '[' => {
+                let start = i + 1;
+                let mut depth = 1;
+                let mut end = start;
+                while end < chars.len() && depth > 0 {
+                    match chars[end] {
+                        '[' => depth += 1,
+                        ']' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                    end += 1;
+                }
+                let content: String = chars[start..end.min(chars.len())].iter().collect();
+                steps.push(parse_bracket_step(content.trim()));
+                i = end + 1;
+            }
+            _ => i += 1,
+        }
+    }
+
+    steps
+}
+
+fn parse_bracket_step(content: &str) -> JsonPathStep {
+    if content == "*" {
+        return JsonPathStep::Wildcard;
+    }
+    if let Some(filter) = content.strip_prefix("?(").and_then(|s| s.strip_suffix(')')) {
+        return parse_filter_step(filter.trim());
+    }
+    if let Some((start, end)) = content.split_once(':') {
+        return JsonPathStep::Slice(
+            start.trim().parse::<i64>().ok(),
+            end.trim().parse::<i64>().ok(),
+        );
+    }
+    if let Ok(index) = content.parse::<i64>() {
+        return JsonPathStep::Index(index);
+    }
+    JsonPathStep::Wildcard
+}
+
+fn parse_filter_step(expr: &str) -> JsonPathStep {
+    for (op_str, op) in [
+        ("==", JsonPathOp::Eq),
+        ("!=", JsonPathOp::Ne),
+        ("<=", JsonPathOp::Le),
+        (">=", JsonPathOp::Ge),
+        ("<", JsonPathOp::Lt),
+        (">", JsonPathOp::Gt),
+    ] {
+        if let Some(idx) = expr.find(op_str) {
+            let field = expr[..idx].trim().trim_start_matches('@').trim_start_matches('.');
+            let literal = parse_json_path_literal(expr[idx + op_str.len()..].trim());
+            return JsonPathStep::Filter(field.to_string(), op, literal);
+        }
+    }
+    JsonPathStep::Wildcard
+}
+
+fn parse_json_path_literal(raw: &str) -> JsonValue {
+    if let Some(unquoted) = raw.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')) {
+        return JsonValue::String(unquoted.to_string());
+    }
+    if let Some(unquoted) = raw.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        return JsonValue::String(unquoted.to_string());
+    }
+    match raw {
+        "true" => JsonValue::Bool(true),
+        "false" => JsonValue::Bool(false),
+        "null" => JsonValue::Null,
+        _ => raw.parse::<f64>().map(|n| json!(n)).unwrap_or_else(|_| JsonValue::String(raw.to_string())),
+    }
+}
+
+/// Evaluate a parsed JSONPath against `root`, returning the matching nodes
+/// (borrowed from `root`, so no cloning happens until the caller decides to
+/// serialize them).
+fn evaluate_json_path<'a>(root: &'a JsonValue, path: &str) -> Vec<&'a JsonValue> {
+    let steps = parse_json_path(path);
+    let mut nodes = vec![root];
+    for step in &steps {
+        nodes = apply_json_path_step(nodes, step);
+    }
+    nodes
+}
+
+fn apply_json_path_step<'a>(nodes: Vec<&'a JsonValue>, step: &JsonPathStep) -> Vec<&'a JsonValue> {
+    match step {
+        JsonPathStep::Child(name) => nodes
+            .into_iter()
+            .filter_map(|node| node.as_object().and_then(|obj| obj.get(name)))
+            .collect(),
+        JsonPathStep::Wildcard => nodes
+            .into_iter()
+            .flat_map(|node| -> Vec<&JsonValue> {
+                match node {
+                    JsonValue::Array(arr) => arr.iter().collect(),
+                    JsonValue::Object(obj) => obj.values().collect(),
+                    _ => vec![],
+                }
+            })
+            .collect(),
+        JsonPathStep::Index(index) => nodes
+            .into_iter()
+            .filter_map(|node| {
+                let arr = node.as_array()?;
+                resolve_json_path_index(*index, arr.len()).and_then(|i| arr.get(i))
+            })
+            .collect(),
+        JsonPathStep::Slice(start, end) => nodes
+            .into_iter()
+            .flat_map(|node| -> Vec<&JsonValue> {
+                let Some(arr) = node.as_array() else { return vec![] };
+                let len = arr.len();
+                let start = start.map(|s| resolve_json_path_bound(s, len)).unwrap_or(0);
+                let end = end.map(|e| resolve_json_path_bound(e, len)).unwrap_or(len);
+                if start < end {
+                    arr[start..end].iter().collect()
+                } else {
+                    vec![]
+                }
+            })
+            .collect(),
+        JsonPathStep::Filter(field, op, literal) => nodes
+            .into_iter()
+            .flat_map(|node| -> Vec<&JsonValue> {
+                let Some(arr) = node.as_array() else { return vec![] };
+                arr.iter()
+                    .filter(|element| {
+                        let field_value = if field.is_empty() {
+                            Some(*element)
+                        } else {
+                            element.as_object().and_then(|obj| obj.get(field.as_str()))
+                        };
+                        field_value.is_some_and(|v| compare_json_path_values(v, literal, *op))
+                    })
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+/// Resolve a (possibly negative, from-end) JSONPath index against an array
+/// of length `len`, returning `None` if it falls outside the array.
+fn resolve_json_path_index(index: i64, len: usize) -> Option<usize> {
+    let len = len as i64;
+    let real_index = if index < 0 { len + index } else { index };
+    (real_index >= 0 && real_index < len).then_some(real_index as usize)
+}
+
+/// Resolve a (possibly negative, from-end) JSONPath slice bound, clamped to
+/// `[0, len]` the way Python/PostgreSQL slicing clamps out-of-range bounds.
+fn resolve_json_path_bound(bound: i64, len: usize) -> usize {
+    let len = len as i64;
+    let resolved = if bound < 0 { len + bound } else { bound };
+    resolved.clamp(0, len) as usize
+}
+
+/// Compare a JSON value against a filter literal. Falls back to `false`
+/// (rather than erroring) when the types don't support ordering, e.g. a
+/// numeric field compared with `<` against a string literal.
+fn compare_json_path_values(value: &JsonValue, literal: &JsonValue, op: JsonPathOp) -> bool {
+    use std::cmp::Ordering;
+
+    let ordering = match (value, literal) {
+        (JsonValue::Number(a), JsonValue::Number(b)) => {
+            a.as_f64().zip(b.as_f64()).and_then(|(a, b)| a.partial_cmp(&b))
+        }
+        (JsonValue::String(a), JsonValue::String(b)) => Some(a.cmp(b)),
+        (JsonValue::Bool(a), JsonValue::Bool(b)) => Some(a.cmp(b)),
+        _ => None,
+    };
+
+    match ordering {
+        Some(Ordering::Equal) => matches!(op, JsonPathOp::Eq | JsonPathOp::Le | JsonPathOp::Ge),
+        Some(Ordering::Less) => matches!(op, JsonPathOp::Ne | JsonPathOp::Lt | JsonPathOp::Le),
+        Some(Ordering::Greater) => matches!(op, JsonPathOp::Ne | JsonPathOp::Gt | JsonPathOp::Ge),
+        None => match op {
+            JsonPathOp::Eq => value == literal,
+            JsonPathOp::Ne => value != literal,
+            _ => false,
+        },
+    }
+}
+
+/// Magic byte identifying our binary array encoding, so a BLOB argument can
+/// be told apart from an opaque bytea value that happens to also be a BLOB.
+const ARRAY_BINARY_MAGIC: u8 = 0xB1;
+
+const TAG_NULL: u8 = 0;
+const TAG_FALSE: u8 = 1;
+const TAG_TRUE: u8 = 2;
+const TAG_INT: u8 = 3;
+const TAG_FLOAT: u8 = 4;
+const TAG_STR: u8 = 5;
+const TAG_ARRAY: u8 = 6;
+const TAG_OBJECT: u8 = 7;
+
+/// Encode `value` as a compact, length-prefixed, tagged blob instead of
+/// JSON. Used to carry array values between calls in a chained expression
+/// (`array_append(array_cat(a, b), x)`) without reparsing/re-serializing
+/// JSON text at every nesting level.
+fn encode_array_binary(value: &JsonValue) -> Vec<u8> {
+    let mut out = vec![ARRAY_BINARY_MAGIC];
+    encode_array_value(value, &mut out);
+    out
+}
+
+fn encode_array_value(value: &JsonValue, out: &mut Vec<u8>) {
+    match value {
+        JsonValue::Null => out.push(TAG_NULL),
+        JsonValue::Bool(false) => out.push(TAG_FALSE),
+        JsonValue::Bool(true) => out.push(TAG_TRUE),
+        JsonValue::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                out.push(TAG_INT);
+                out.extend_from_slice(&i.to_le_bytes());
+            } else {
+                out.push(TAG_FLOAT);
+                out.extend_from_slice(&n.as_f64().unwrap_or(0.0).to_le_bytes());
+            }
+        }
+        JsonValue::String(s) => {
+            out.push(TAG_STR);
+            out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+            out.extend_from_slice(s.as_bytes());
+        }
+        JsonValue::Array(arr) => {
+            out.push(TAG_ARRAY);
+            out.extend_from_slice(&(arr.len() as u32).to_le_bytes());
+            for item in arr {
+                encode_array_value(item, out);
+            }
+        }
+        JsonValue::Object(obj) => {
+            out.push(TAG_OBJECT);
+            out.extend_from_slice(&(obj.len() as u32).to_le_bytes());
+            for (k, v) in obj {
+                out.extend_from_slice(&(k.len() as u32).to_le_bytes());
+                out.extend_from_slice(k.as_bytes());
+                encode_array_value(v, out);
+            }
+        }
+    }
+}
+
+/// Decode a blob produced by [`encode_array_binary`]. Returns `None` if the
+/// magic byte doesn't match or the bytes are truncated/malformed, so callers
+/// can fall back to treating the value as opaque.
+fn decode_array_binary(blob: &[u8]) -> Option<JsonValue> {
+    if blob.first() != Some(&ARRAY_BINARY_MAGIC) {
+        return None;
+    }
+    let mut pos = 1;
+    decode_array_value(blob, &mut pos)
+}
+
+fn decode_array_value(bytes: &[u8], pos: &mut usize) -> Option<JsonValue> {
+    let tag = *bytes.get(*pos)?;
+    *pos += 1;
+    match tag {
+        TAG_NULL => Some(JsonValue::Null),
+        TAG_FALSE => Some(JsonValue::Bool(false)),
+        TAG_TRUE => Some(JsonValue::Bool(true)),
+        TAG_INT => {
+            let raw: [u8; 8] = bytes.get(*pos..*pos + 8)?.try_into().ok()?;
+            *pos += 8;
+            Some(json!(i64::from_le_bytes(raw)))
+        }
+        TAG_FLOAT => {
+            let raw: [u8; 8] = bytes.get(*pos..*pos + 8)?.try_into().ok()?;
+            *pos += 8;
+            Some(json!(f64::from_le_bytes(raw)))
+        }
+        TAG_STR => {
+            let len = read_u32_len(bytes, pos)?;
+            let s = std::str::from_utf8(bytes.get(*pos..*pos + len)?).ok()?.to_string();
+            *pos += len;
+            Some(JsonValue::String(s))
+        }
+        TAG_ARRAY => {
+            let count = read_u32_len(bytes, pos)?;
+            let mut arr = Vec::with_capacity(count);
+            for _ in 0..count {
+                arr.push(decode_array_value(bytes, pos)?);
+            }
+            Some(JsonValue::Array(arr))
+        }
+        TAG_OBJECT => {
+            let count = read_u32_len(bytes, pos)?;
+            let mut obj = serde_json::Map::with_capacity(count);
+            for _ in 0..count {
+                let key_len = read_u32_len(bytes, pos)?;
+                let key = std::str::from_utf8(bytes.get(*pos..*pos + key_len)?).ok()?.to_string();
+                *pos += key_len;
+                let value = decode_array_value(bytes, pos)?;
+                obj.insert(key, value);
+            }
+            Some(JsonValue::Object(obj))
+        }
+        _ => None,
+    }
+}
+
+fn read_u32_len(bytes: &[u8], pos: &mut usize) -> Option<usize> {
+    let raw: [u8; 4] = bytes.get(*pos..*pos + 4)?.try_into().ok()?;
+    *pos += 4;
+    Some(u32::from_le_bytes(raw) as usize)
+}
+
+/// Read an array-pipeline argument regardless of whether it arrived as our
+/// binary encoding (BLOB) or as JSON (TEXT), reporting which it was so the
+/// caller can re-emit the same format. Returns `None` for anything else
+/// (NULL, a plain integer, a BLOB that isn't our format, ...).
+fn read_array_argument(ctx: &rusqlite::functions::Context, idx: usize) -> Option<(JsonValue, bool)> {
+    match ctx.get_raw(idx) {
+        rusqlite::types::ValueRef::Blob(b) => decode_array_binary(b).map(|v| (v, true)),
+        rusqlite::types::ValueRef::Text(s) => std::str::from_utf8(s).ok()
+            .and_then(|text| serde_json::from_str::<JsonValue>(text).ok())
+            .map(|v| (v, false)),
+        _ => None,
+    }
+}
+
+/// Encode an array pipeline's result in whichever format (`binary`) its
+/// input arrived in.
+fn array_value_to_sql(value: &JsonValue, binary: bool) -> rusqlite::types::Value {
+    if binary {
+        rusqlite::types::Value::Blob(encode_array_binary(value))
+    } else {
+        rusqlite::types::Value::Text(serde_json::to_string(value).unwrap_or_else(|_| "[]".to_string()))
+    }
+}
+
+/// array_to_binary(text) - Bridge a JSON TEXT array into the binary
+/// encoding, so the storage/wire layer can choose to keep arrays out of JSON
+/// entirely between calls.
+fn register_array_to_binary(conn: &Connection) -> Result<()> {
+    conn.create_scalar_function(
+        "array_to_binary",
+        1,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let array_json: String = ctx.get(0)?;
+            match serde_json::from_str::<JsonValue>(&array_json) {
+                Ok(value) => Ok(Some(encode_array_binary(&value))),
+                _ => Ok(None),
+            }
+        },
+    )?;
+
+    Ok(())
+}
+
+/// array_from_binary(blob) - The reverse of [`register_array_to_binary`]:
+/// decode our binary encoding back into JSON TEXT.
+fn register_array_from_binary(conn: &Connection) -> Result<()> {
+    conn.create_scalar_function(
+        "array_from_binary",
+        1,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let blob: Vec<u8> = ctx.get(0)?;
+            match decode_array_binary(&blob) {
+                Some(value) => Ok(serde_json::to_string(&value).ok()),
+                None => Ok(None),
+            }
+        },
+    )?;
+
     Ok(())
 }
 
@@ -589,4 +1259,150 @@ mod tests {
         ).unwrap();
         assert!(overlap);
     }
+
+    #[test]
+    fn test_array_query_jsonpath() {
+        let conn = Connection::open_in_memory().unwrap();
+        register_array_functions(&conn).unwrap();
+
+        // Wildcard over an array of objects
+        let result: String = conn.query_row(
+            "SELECT array_query('[{\"x\":1},{\"x\":2},{\"x\":3}]', '$[*].x')",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert_eq!(result, "[1,2,3]");
+
+        // Filter expression
+        let filtered: String = conn.query_row(
+            "SELECT array_query('[{\"x\":1},{\"x\":2},{\"x\":3}]', '$[?(@.x>=2)]')",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert_eq!(filtered, "[{\"x\":2},{\"x\":3}]");
+
+        // No match yields an empty array, not NULL
+        let empty: String = conn.query_row(
+            "SELECT array_query('[1,2,3]', '$[?(@==99)]')",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert_eq!(empty, "[]");
+
+        // array_path_exists
+        let exists: bool = conn.query_row(
+            "SELECT array_path_exists('[1,2,3]', '$[?(@==2)]')",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert!(exists);
+
+        let missing: bool = conn.query_row(
+            "SELECT array_path_exists('[1,2,3]', '$[?(@==99)]')",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert!(!missing);
+    }
+
+    #[test]
+    fn test_array_binary_encoding_round_trip() {
+        let conn = Connection::open_in_memory().unwrap();
+        register_array_functions(&conn).unwrap();
+
+        // JSON -> binary -> JSON round-trips
+        let back: String = conn.query_row(
+            "SELECT array_from_binary(array_to_binary('[1,2,3]'))",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert_eq!(back, "[1,2,3]");
+
+        // array_append/array_cat stay in binary form across a chained call
+        // and only decode to JSON at the edge via array_from_binary
+        let chained: String = conn.query_row(
+            "SELECT array_from_binary(array_append(array_cat(array_to_binary('[1,2]'), array_to_binary('[3]')), '4'))",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert_eq!(chained, "[1,2,3,4]");
+    }
+
+    #[test]
+    fn test_array_slice_negative_indices() {
+        let conn = Connection::open_in_memory().unwrap();
+        register_array_functions(&conn).unwrap();
+
+        // Tail slice via negative indices
+        let tail: String = conn.query_row(
+            "SELECT array_slice('[1,2,3,4,5]', -3, -1)",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert_eq!(tail, "[3,4,5]");
+
+        // start > end yields an empty array
+        let empty: String = conn.query_row(
+            "SELECT array_slice('[1,2,3]', 3, 1)",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert_eq!(empty, "[]");
+    }
+
+    #[test]
+    fn test_array_subscript() {
+        let conn = Connection::open_in_memory().unwrap();
+        register_array_functions(&conn).unwrap();
+
+        // Positive 1-based index
+        let first: i32 = conn.query_row(
+            "SELECT array_subscript('[10,20,30]', 1)",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert_eq!(first, 10);
+
+        // Negative index counts from the end
+        let last: i32 = conn.query_row(
+            "SELECT array_subscript('[10,20,30]', -1)",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert_eq!(last, 30);
+
+        // Out-of-range subscript returns NULL, not an error
+        let out_of_range: Option<i32> = conn.query_row(
+            "SELECT array_subscript('[10,20,30]', 99)",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert_eq!(out_of_range, None);
+    }
+
+    #[test]
+    fn test_array_agg_ordered_and_distinct() {
+        let conn = Connection::open_in_memory().unwrap();
+        register_array_functions(&conn).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE t (value INTEGER, sortkey INTEGER);
+             INSERT INTO t (value, sortkey) VALUES (3, 30), (1, 10), (2, 20), (1, 10);"
+        ).unwrap();
+
+        // Ordered aggregate projects values sorted by the sort key
+        let ordered: String = conn.query_row(
+            "SELECT array_agg_ordered(value, sortkey) FROM t",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert_eq!(ordered, "[1,1,2,3]");
+
+        // Distinct aggregate drops duplicates, keeping first-seen order
+        let distinct: String = conn.query_row(
+            "SELECT array_agg_distinct(value) FROM t",
+            [],
+            |row| row.get(0)
+        ).unwrap();
+        assert_eq!(distinct, "[3,1,2]");
+    }
 }
\ No newline at end of file
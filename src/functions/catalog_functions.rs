@@ -31,21 +31,88 @@ pub fn register_catalog_functions(conn: &Connection) -> Result<()> {
     // Note: SQLite doesn't support schema-qualified function names,
     // so we handle pg_catalog.pg_table_is_visible through query rewriting
     
-    // regclass type cast function
+    // regclass type cast function: 'name' -> oid. A schema-qualified sequence
+    // name (e.g. 'public.widgets_id_seq') resolves through __pgsqlite_sequences
+    // - the same seq_oid generate_sequence_oid feeds into pg_class/pg_sequence -
+    // so setval(pg_get_serial_sequence(...)::regclass, ...) round-trips.
     conn.create_scalar_function(
         "regclass",
         1,
-        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        FunctionFlags::SQLITE_UTF8,
         |ctx| {
-            let table_name: String = ctx.get(0)?;
-            
-            // Look up table OID from pg_class view
-            // For now, just generate a consistent OID
-            let oid = generate_table_oid(&table_name);
-            Ok(oid)
+            let name: String = ctx.get(0)?;
+            let unqualified = name.rsplit('.').next().unwrap_or(&name);
+
+            if let Ok(conn) = unsafe { ctx.get_connection() } {
+                let seq_oid: Option<String> = conn.query_row(
+                    "SELECT seq_oid FROM __pgsqlite_sequences WHERE sequence_name = ?1",
+                    [unqualified],
+                    |row| row.get(0),
+                ).ok();
+                if let Some(seq_oid) = seq_oid {
+                    return Ok(seq_oid.parse::<i64>().unwrap_or(0));
+                }
+                let table_oid: Option<i64> = conn.query_row(
+                    "SELECT oid FROM __pgsqlite_oid_registry WHERE object_kind = 'table' AND object_name = ?1",
+                    [unqualified],
+                    |row| row.get(0),
+                ).ok();
+                if let Some(table_oid) = table_oid {
+                    return Ok(table_oid);
+                }
+            }
+
+            Ok(generate_table_oid(unqualified) as i64)
         },
     )?;
-    
+
+    // regclass_name(oid) - the reverse of regclass: an OID back to its
+    // relation name, consulting the same sequence/pg_class OID mapping.
+    conn.create_scalar_function(
+        "regclass_name",
+        1,
+        FunctionFlags::SQLITE_UTF8,
+        |ctx| {
+            let oid: i64 = ctx.get(0)?;
+            let conn = unsafe { ctx.get_connection()? };
+
+            let sequence_name: Option<String> = conn.query_row(
+                "SELECT sequence_name FROM __pgsqlite_sequences WHERE seq_oid = ?1",
+                [oid.to_string()],
+                |row| row.get(0),
+            ).ok();
+            if let Some(name) = sequence_name {
+                return Ok(rusqlite::types::Value::Text(name));
+            }
+
+            let registered_name: Option<String> = conn.query_row(
+                "SELECT object_name FROM __pgsqlite_oid_registry WHERE object_kind = 'table' AND oid = ?1",
+                [oid],
+                |row| row.get(0),
+            ).ok();
+            if let Some(name) = registered_name {
+                return Ok(rusqlite::types::Value::Text(name));
+            }
+
+            let table_name: Option<String> = conn.query_row(
+                "SELECT name FROM sqlite_master WHERE type = 'table' AND CAST(? AS TEXT) = (
+                    SELECT CAST(
+                        (
+                            (unicode(substr(name, 1, 1)) * 1000000) +
+                            (unicode(substr(name || ' ', 2, 1)) * 10000) +
+                            (unicode(substr(name || '  ', 3, 1)) * 100) +
+                            (length(name) * 7)
+                        ) % 1000000 + 16384
+                    AS TEXT)
+                )",
+                [oid.to_string()],
+                |row| row.get(0),
+            ).ok();
+
+            Ok(table_name.map(rusqlite::types::Value::Text).unwrap_or(rusqlite::types::Value::Null))
+        },
+    )?;
+
     // to_regtype(typename) - converts type name to OID, returns NULL for non-existent types
     conn.create_scalar_function(
         "to_regtype",
@@ -177,4 +244,28 @@ mod tests {
             .unwrap();
         assert_eq!(oid, oid2);
     }
+
+    #[test]
+    fn test_regclass_round_trips_sequence_name_through_oid() {
+        let conn = Connection::open_in_memory().unwrap();
+        register_catalog_functions(&conn).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE __pgsqlite_sequences (
+                seq_oid TEXT PRIMARY KEY, sequence_name TEXT NOT NULL UNIQUE,
+                table_name TEXT NOT NULL, column_name TEXT NOT NULL
+             );
+             INSERT INTO __pgsqlite_sequences (seq_oid, sequence_name, table_name, column_name)
+             VALUES ('54321', 'widgets_id_seq', 'widgets', 'id');"
+        ).unwrap();
+
+        let oid: i64 = conn
+            .query_row("SELECT regclass('public.widgets_id_seq')", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(oid, 54321);
+
+        let name: String = conn
+            .query_row("SELECT regclass_name(54321)", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(name, "widgets_id_seq");
+    }
 }
\ No newline at end of file
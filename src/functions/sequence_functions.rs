@@ -0,0 +1,365 @@
+use rusqlite::{Connection, Result, functions::FunctionFlags};
+use tracing::debug;
+
+/// Register PostgreSQL sequence-manipulation functions (`nextval`, `currval`,
+/// `setval`) backed by the `__pgsqlite_sequences` catalog table that
+/// `constraint_populator::populate_table_dependencies` seeds for every
+/// single-column `INTEGER PRIMARY KEY`.
+pub fn register_sequence_functions(conn: &Connection) -> Result<()> {
+    debug!("Registering sequence functions");
+
+    // nextval(sequence_name) - advance and return the sequence's counter.
+    // Not deterministic: the whole point is that repeated calls differ.
+    conn.create_scalar_function(
+        "nextval",
+        1,
+        FunctionFlags::SQLITE_UTF8,
+        |ctx| {
+            let sequence_name: String = ctx.get(0)?;
+            let conn = unsafe { ctx.get_connection()? };
+            nextval(&conn, &sequence_name)
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))
+        },
+    )?;
+
+    // currval(sequence_name) - the value nextval() most recently returned in
+    // this session, without advancing it.
+    conn.create_scalar_function(
+        "currval",
+        1,
+        FunctionFlags::SQLITE_UTF8,
+        |ctx| {
+            let sequence_name: String = ctx.get(0)?;
+            let conn = unsafe { ctx.get_connection()? };
+            currval(&conn, &sequence_name)
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))
+        },
+    )?;
+
+    // setval(sequence_name, value) - reset the counter; the next nextval()
+    // call advances past it, matching PostgreSQL's 2-arg setval (is_called = true).
+    conn.create_scalar_function(
+        "setval",
+        2,
+        FunctionFlags::SQLITE_UTF8,
+        |ctx| {
+            let sequence_name: String = ctx.get(0)?;
+            let value: i64 = ctx.get(1)?;
+            let conn = unsafe { ctx.get_connection()? };
+            setval(&conn, &sequence_name, value, true)
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))
+        },
+    )?;
+
+    // setval(sequence_name, value, is_called) - same, with is_called explicit.
+    conn.create_scalar_function(
+        "setval",
+        3,
+        FunctionFlags::SQLITE_UTF8,
+        |ctx| {
+            let sequence_name: String = ctx.get(0)?;
+            let value: i64 = ctx.get(1)?;
+            let is_called: bool = ctx.get(2)?;
+            let conn = unsafe { ctx.get_connection()? };
+            setval(&conn, &sequence_name, value, is_called)
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))
+        },
+    )?;
+
+    // lastval() - the value nextval() most recently returned for *any*
+    // sequence, tracked in the single-row __pgsqlite_lastval table.
+    conn.create_scalar_function(
+        "lastval",
+        0,
+        FunctionFlags::SQLITE_UTF8,
+        |ctx| {
+            let conn = unsafe { ctx.get_connection()? };
+            lastval(&conn)
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))
+        },
+    )?;
+
+    // pg_get_serial_sequence(table_name, column_name) - resolve the automatic
+    // (deptype = 'a') pg_depend link from a column to its owning sequence,
+    // returning the qualified sequence name or NULL if the column isn't serial.
+    conn.create_scalar_function(
+        "pg_get_serial_sequence",
+        2,
+        FunctionFlags::SQLITE_UTF8,
+        |ctx| {
+            let table_name: String = ctx.get(0)?;
+            let column_name: String = ctx.get(1)?;
+            let conn = unsafe { ctx.get_connection()? };
+            match pg_get_serial_sequence(&conn, &table_name, &column_name) {
+                Ok(name) => Ok(name.map(rusqlite::types::Value::Text).unwrap_or(rusqlite::types::Value::Null)),
+                Err(e) => Err(rusqlite::Error::UserFunctionError(e.into())),
+            }
+        },
+    )?;
+
+    debug!("Sequence functions registered successfully");
+    Ok(())
+}
+
+/// Resolve `pg_get_serial_sequence('table', 'column')` via the automatic
+/// `pg_depend` entry `populate_table_dependencies` records for single-column
+/// `INTEGER PRIMARY KEY` columns, returning the schema-qualified sequence
+/// name (e.g. `"public.widgets_id_seq"`) or `None` if the column isn't serial.
+fn pg_get_serial_sequence(conn: &Connection, table_name: &str, column_name: &str) -> anyhow::Result<Option<String>> {
+    let table_name = table_name.rsplit('.').next().unwrap_or(table_name);
+
+    let cid: Option<i32> = conn.query_row(
+        &format!("SELECT cid FROM pragma_table_info('{table_name}') WHERE name = ?1"),
+        [column_name],
+        |row| row.get(0),
+    ).ok();
+    let Some(cid) = cid else { return Ok(None) };
+
+    let table_oid = crate::catalog::constraint_populator::generate_table_oid(conn, table_name)?;
+    let refobjsubid = cid + 1;
+
+    let seq_oid: Option<String> = conn.query_row(
+        "SELECT objid FROM pg_depend WHERE refobjid = ?1 AND refobjsubid = ?2 AND deptype = 'a'",
+        rusqlite::params![table_oid, refobjsubid],
+        |row| row.get(0),
+    ).ok();
+    let Some(seq_oid) = seq_oid else { return Ok(None) };
+
+    let sequence_name: Option<String> = conn.query_row(
+        "SELECT sequence_name FROM __pgsqlite_sequences WHERE seq_oid = ?1",
+        [&seq_oid],
+        |row| row.get(0),
+    ).ok();
+
+    Ok(sequence_name.map(|name| format!("public.{name}")))
+}
+
+/// Split a `{table}_{column}_seq` sequence name back into its owning
+/// table/column - the naming scheme `populate_table_dependencies` uses for
+/// single-column `INTEGER PRIMARY KEY` sequences.
+fn split_sequence_name(sequence_name: &str) -> Option<(String, String)> {
+    let base = sequence_name.strip_suffix("_seq")?;
+    let (table, column) = base.rsplit_once('_')?;
+    Some((table.to_string(), column.to_string()))
+}
+
+/// Look up the `__pgsqlite_sequences` row for `sequence_name`, lazily
+/// creating it from the owning table's current max value if this is the
+/// first time it's been referenced - so `nextval()` on a freshly created
+/// serial continues from the next unused rowid instead of colliding with it.
+fn ensure_sequence_row(conn: &Connection, sequence_name: &str) -> anyhow::Result<()> {
+    let exists: bool = conn.query_row(
+        "SELECT 1 FROM __pgsqlite_sequences WHERE sequence_name = ?1",
+        [sequence_name],
+        |_| Ok(true),
+    ).unwrap_or(false);
+    if exists {
+        return Ok(());
+    }
+
+    let (table_name, column_name) = split_sequence_name(sequence_name)
+        .ok_or_else(|| anyhow::anyhow!("relation \"{sequence_name}\" does not exist"))?;
+
+    let current_max: i64 = conn.query_row(
+        &format!("SELECT COALESCE(MAX({column_name}), 0) FROM {table_name}"),
+        [],
+        |row| row.get(0),
+    ).map_err(|_| anyhow::anyhow!("relation \"{sequence_name}\" does not exist"))?;
+
+    let seq_oid = crate::catalog::constraint_populator::generate_sequence_oid(conn, &table_name, &column_name)?;
+    conn.execute(
+        "INSERT OR IGNORE INTO __pgsqlite_sequences (
+            seq_oid, sequence_name, table_name, column_name,
+            last_value, start_value, increment_by, min_value, max_value, is_called
+        ) VALUES (?1, ?2, ?3, ?4, ?5, 1, 1, 1, 9223372036854775807, ?6)",
+        rusqlite::params![seq_oid.to_string(), sequence_name, table_name, column_name, current_max.max(1), (current_max > 0) as i32],
+    )?;
+    Ok(())
+}
+
+fn nextval(conn: &Connection, sequence_name: &str) -> anyhow::Result<i64> {
+    ensure_sequence_row(conn, sequence_name)?;
+
+    // First call after creation returns start_value without advancing;
+    // every later call advances by increment_by first - matching PostgreSQL.
+    conn.execute(
+        "UPDATE __pgsqlite_sequences
+         SET last_value = CASE WHEN is_called = 0 THEN last_value ELSE last_value + increment_by END,
+             is_called = 1
+         WHERE sequence_name = ?1",
+        [sequence_name],
+    )?;
+
+    let value = conn.query_row(
+        "SELECT last_value FROM __pgsqlite_sequences WHERE sequence_name = ?1",
+        [sequence_name],
+        |row| row.get(0),
+    ).map_err(|e| anyhow::anyhow!("relation \"{sequence_name}\" does not exist: {e}"))?;
+
+    conn.execute(
+        "INSERT INTO __pgsqlite_lastval (id, sequence_name) VALUES (1, ?1)
+         ON CONFLICT(id) DO UPDATE SET sequence_name = excluded.sequence_name",
+        [sequence_name],
+    )?;
+
+    Ok(value)
+}
+
+/// `lastval()` - the value most recently returned by `nextval()` for any
+/// sequence, tracked in the single-row `__pgsqlite_lastval` table `nextval`
+/// updates. Matches PostgreSQL's error text when no sequence has been used
+/// yet.
+fn lastval(conn: &Connection) -> anyhow::Result<i64> {
+    let sequence_name: Option<String> = conn.query_row(
+        "SELECT sequence_name FROM __pgsqlite_lastval WHERE id = 1",
+        [],
+        |row| row.get(0),
+    ).ok();
+
+    let sequence_name = sequence_name
+        .ok_or_else(|| anyhow::anyhow!("lastval is not yet defined in this session"))?;
+
+    conn.query_row(
+        "SELECT last_value FROM __pgsqlite_sequences WHERE sequence_name = ?1",
+        [&sequence_name],
+        |row| row.get(0),
+    ).map_err(|e| anyhow::anyhow!("relation \"{sequence_name}\" does not exist: {e}"))
+}
+
+fn currval(conn: &Connection, sequence_name: &str) -> anyhow::Result<i64> {
+    let row: Option<(i64, i64)> = conn.query_row(
+        "SELECT last_value, is_called FROM __pgsqlite_sequences WHERE sequence_name = ?1",
+        [sequence_name],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    ).ok();
+
+    match row {
+        Some((last_value, is_called)) if is_called != 0 => Ok(last_value),
+        Some(_) => Err(anyhow::anyhow!(
+            "currval of sequence \"{sequence_name}\" is not yet defined in this session"
+        )),
+        None => Err(anyhow::anyhow!("relation \"{sequence_name}\" does not exist")),
+    }
+}
+
+fn setval(conn: &Connection, sequence_name: &str, value: i64, is_called: bool) -> anyhow::Result<i64> {
+    ensure_sequence_row(conn, sequence_name)?;
+
+    conn.execute(
+        "UPDATE __pgsqlite_sequences SET last_value = ?1, is_called = ?2 WHERE sequence_name = ?3",
+        rusqlite::params![value, is_called as i32, sequence_name],
+    )?;
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE widgets (id INTEGER PRIMARY KEY, name TEXT);
+             CREATE TABLE __pgsqlite_sequences (
+                 seq_oid TEXT PRIMARY KEY,
+                 sequence_name TEXT NOT NULL UNIQUE,
+                 table_name TEXT NOT NULL,
+                 column_name TEXT NOT NULL,
+                 last_value INTEGER NOT NULL DEFAULT 1,
+                 start_value INTEGER NOT NULL DEFAULT 1,
+                 increment_by INTEGER NOT NULL DEFAULT 1,
+                 min_value INTEGER NOT NULL DEFAULT 1,
+                 max_value INTEGER NOT NULL DEFAULT 9223372036854775807,
+                 is_called INTEGER NOT NULL DEFAULT 0
+             );
+             CREATE TABLE pg_depend (
+                 classid TEXT, objid TEXT, objsubid INTEGER,
+                 refclassid TEXT, refobjid TEXT, refobjsubid INTEGER, deptype TEXT
+             );
+             CREATE TABLE __pgsqlite_lastval (
+                 id INTEGER PRIMARY KEY CHECK (id = 1),
+                 sequence_name TEXT
+             );"
+        ).unwrap();
+        register_sequence_functions(&conn).unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_nextval_starts_at_one_for_empty_table() {
+        let conn = setup();
+        let first: i64 = conn.query_row("SELECT nextval('widgets_id_seq')", [], |row| row.get(0)).unwrap();
+        assert_eq!(first, 1);
+        let second: i64 = conn.query_row("SELECT nextval('widgets_id_seq')", [], |row| row.get(0)).unwrap();
+        assert_eq!(second, 2);
+    }
+
+    #[test]
+    fn test_nextval_continues_past_existing_rows() {
+        let conn = setup();
+        conn.execute("INSERT INTO widgets (id, name) VALUES (5, 'x')", []).unwrap();
+        let next: i64 = conn.query_row("SELECT nextval('widgets_id_seq')", [], |row| row.get(0)).unwrap();
+        assert_eq!(next, 6);
+        let after: i64 = conn.query_row("SELECT nextval('widgets_id_seq')", [], |row| row.get(0)).unwrap();
+        assert_eq!(after, 7);
+    }
+
+    #[test]
+    fn test_setval_and_currval() {
+        let conn = setup();
+        conn.execute("SELECT setval('widgets_id_seq', 100)", []).unwrap();
+        let current: i64 = conn.query_row("SELECT currval('widgets_id_seq')", [], |row| row.get(0)).unwrap();
+        assert_eq!(current, 100);
+        let next: i64 = conn.query_row("SELECT nextval('widgets_id_seq')", [], |row| row.get(0)).unwrap();
+        assert_eq!(next, 101);
+    }
+
+    #[test]
+    fn test_currval_before_nextval_errors() {
+        let conn = setup();
+        let result = conn.query_row::<i64, _, _>("SELECT currval('widgets_id_seq')", [], |row| row.get(0));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_lastval_tracks_most_recent_nextval() {
+        let conn = setup();
+        let result = conn.query_row::<i64, _, _>("SELECT lastval()", [], |row| row.get(0));
+        assert!(result.is_err());
+
+        let next: i64 = conn.query_row("SELECT nextval('widgets_id_seq')", [], |row| row.get(0)).unwrap();
+        let last: i64 = conn.query_row("SELECT lastval()", [], |row| row.get(0)).unwrap();
+        assert_eq!(next, last);
+    }
+
+    #[test]
+    fn test_pg_get_serial_sequence_resolves_via_pg_depend() {
+        let conn = setup();
+        let table_oid = crate::catalog::constraint_populator::generate_table_oid(&conn, "widgets").unwrap();
+        conn.execute(
+            "INSERT INTO pg_depend (classid, objid, objsubid, refclassid, refobjid, refobjsubid, deptype)
+             VALUES ('1259', '99999', 0, '1259', ?1, 1, 'a')",
+            [&table_oid],
+        ).unwrap();
+        conn.execute(
+            "INSERT INTO __pgsqlite_sequences (seq_oid, sequence_name, table_name, column_name)
+             VALUES ('99999', 'widgets_id_seq', 'widgets', 'id')",
+            [],
+        ).unwrap();
+
+        let result: String = conn
+            .query_row("SELECT pg_get_serial_sequence('widgets', 'id')", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(result, "public.widgets_id_seq");
+    }
+
+    #[test]
+    fn test_pg_get_serial_sequence_returns_null_for_non_serial_column() {
+        let conn = setup();
+        let result: Option<String> = conn
+            .query_row("SELECT pg_get_serial_sequence('widgets', 'name')", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(result, None);
+    }
+}
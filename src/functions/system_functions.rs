@@ -184,20 +184,24 @@ pub fn register_system_functions(conn: &Connection) -> Result<()> {
         },
     )?;
     
-    // pg_has_role(user, role, privilege) - Check if user has role privilege
+    // pg_has_role(user, role, privilege) - does `user` have `privilege` on
+    // `role` (trivially true of itself, or via a transitive
+    // __pgsqlite_auth_members chain, or because `user` is a superuser)?
     conn.create_scalar_function(
         "pg_has_role",
         3,
         FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
         |ctx| {
-            let _user: String = ctx.get(0)?;
-            let _role: String = ctx.get(1)?;
+            let user: String = ctx.get(0)?;
+            let role: String = ctx.get(1)?;
             let _privilege: String = ctx.get(2)?;
-            // In SQLite, always return true for compatibility
-            Ok(1i32) // true in SQLite boolean representation
+            let conn = unsafe { ctx.get_connection()? };
+            pg_has_role(&conn, &user, &role)
+                .map(|has_role| has_role as i32)
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))
         },
     )?;
-    
+
     // has_database_privilege(user, database, privilege) - Check database privilege
     conn.create_scalar_function(
         "has_database_privilege",
@@ -207,39 +211,64 @@ pub fn register_system_functions(conn: &Connection) -> Result<()> {
             let _user: String = ctx.get(0)?;
             let _database: String = ctx.get(1)?;
             let _privilege: String = ctx.get(2)?;
-            // In SQLite, always return true for compatibility
+            // pgsqlite has no per-database privilege catalog; every database
+            // is the one SQLite file backing this connection.
             Ok(1i32) // true
         },
     )?;
-    
+
     // has_schema_privilege(user, schema, privilege) - Check schema privilege
     conn.create_scalar_function(
         "has_schema_privilege",
         3,
         FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
         |ctx| {
-            let _user: String = ctx.get(0)?;
-            let _schema: String = ctx.get(1)?;
-            let _privilege: String = ctx.get(2)?;
-            // In SQLite, always return true for compatibility
-            Ok(1i32) // true
+            let user: String = ctx.get(0)?;
+            let schema: String = ctx.get(1)?;
+            let privilege: String = ctx.get(2)?;
+            let conn = unsafe { ctx.get_connection()? };
+            has_object_privilege(&conn, &user, "schema", &schema, &privilege)
+                .map(|has_priv| has_priv as i32)
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))
         },
     )?;
-    
+
     // has_table_privilege(user, table, privilege) - Check table privilege
     conn.create_scalar_function(
         "has_table_privilege",
         3,
         FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
         |ctx| {
-            let _user: String = ctx.get(0)?;
-            let _table: String = ctx.get(1)?;
-            let _privilege: String = ctx.get(2)?;
-            // In SQLite, always return true for compatibility
-            Ok(1i32) // true
+            let user: String = ctx.get(0)?;
+            let table: String = ctx.get(1)?;
+            let privilege: String = ctx.get(2)?;
+            let conn = unsafe { ctx.get_connection()? };
+            has_object_privilege(&conn, &user, "table", &table, &privilege)
+                .map(|has_priv| has_priv as i32)
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))
         },
     )?;
-    
+
+    // has_column_privilege(user, table, column, privilege) - Check column privilege.
+    // __pgsqlite_privileges doesn't track grants at column granularity, so
+    // this falls back to the table-level grant, matching PostgreSQL's own
+    // behavior of column privileges being additive on top of table privileges.
+    conn.create_scalar_function(
+        "has_column_privilege",
+        4,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let user: String = ctx.get(0)?;
+            let table: String = ctx.get(1)?;
+            let _column: String = ctx.get(2)?;
+            let privilege: String = ctx.get(3)?;
+            let conn = unsafe { ctx.get_connection()? };
+            has_object_privilege(&conn, &user, "table", &table, &privilege)
+                .map(|has_priv| has_priv as i32)
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))
+        },
+    )?;
+
     // pg_get_userbyid(user_oid) - Returns username for user OID
     conn.create_scalar_function(
         "pg_get_userbyid",
@@ -339,6 +368,89 @@ pub fn register_system_functions(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+/// Does `__pgsqlite_roles` say `role` is a superuser? Falls back to `true`
+/// when the table doesn't exist yet (no migrations applied), so the
+/// privilege functions below stay permissive rather than erroring out on a
+/// bare connection.
+fn is_superuser(conn: &Connection, role: &str) -> anyhow::Result<bool> {
+    match conn.query_row(
+        "SELECT rolsuper FROM __pgsqlite_roles WHERE rolname = ?1",
+        rusqlite::params![role],
+        |row| row.get::<_, String>(0),
+    ) {
+        Ok(rolsuper) => Ok(rolsuper == "t"),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(false),
+        Err(e) if e.to_string().contains("no such table") => Ok(true),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Is `member` the same role as `role`, or transitively a member of it via
+/// `__pgsqlite_auth_members` (walked with a recursive CTE, the same idiom
+/// PostgreSQL itself uses to resolve role inheritance)?
+fn pg_has_role(conn: &Connection, member: &str, role: &str) -> anyhow::Result<bool> {
+    if member == role || is_superuser(conn, member)? {
+        return Ok(true);
+    }
+
+    let result = conn.query_row(
+        r#"
+        WITH RECURSIVE memberships(role_oid) AS (
+            SELECT oid FROM __pgsqlite_roles WHERE rolname = ?1
+            UNION
+            SELECT am.roleid FROM __pgsqlite_auth_members am
+            JOIN memberships m ON am.member = m.role_oid
+        )
+        SELECT EXISTS (
+            SELECT 1 FROM memberships
+            WHERE role_oid = (SELECT oid FROM __pgsqlite_roles WHERE rolname = ?2)
+        )
+        "#,
+        rusqlite::params![member, role],
+        |row| row.get::<_, i64>(0),
+    );
+
+    match result {
+        Ok(found) => Ok(found != 0),
+        Err(e) if e.to_string().contains("no such table") => Ok(true),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Does `role` have `privilege` on the object `object_name` of kind
+/// `object_kind` ("table"/"schema"/...), either directly, via `PUBLIC`, or
+/// via a role it's transitively a member of? Superusers always pass; a
+/// missing `__pgsqlite_privileges` table falls back to `true` (no grants
+/// have ever been tracked, so nothing has been revoked either).
+fn has_object_privilege(conn: &Connection, role: &str, object_kind: &str, object_name: &str, privilege: &str) -> anyhow::Result<bool> {
+    if is_superuser(conn, role)? {
+        return Ok(true);
+    }
+
+    let mut stmt = match conn.prepare(
+        "SELECT grantee FROM __pgsqlite_privileges \
+         WHERE object_kind = ?1 AND object_name = ?2 AND (privilege_type = ?3 OR privilege_type = 'ALL')",
+    ) {
+        Ok(stmt) => stmt,
+        Err(e) if e.to_string().contains("no such table") => return Ok(true),
+        Err(e) => return Err(e.into()),
+    };
+
+    let grantees = stmt.query_map(
+        rusqlite::params![object_kind, object_name, privilege.to_uppercase()],
+        |row| row.get::<_, String>(0),
+    )?;
+
+    for grantee in grantees {
+        let grantee = grantee?;
+        if grantee == "public" || pg_has_role(conn, role, &grantee)? {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
 /// Format size in bytes as human-readable string using PostgreSQL's algorithm
 /// Uses binary prefixes: 1 kB = 1024 bytes, 1 MB = 1024² bytes, etc.
 /// Based on PostgreSQL source code in src/backend/utils/adt/dbsize.c
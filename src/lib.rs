@@ -107,6 +107,7 @@ pub async fn handle_test_connection_with_pool(
     use query::{QueryExecutor, ExtendedQueryHandler};
     use tracing::{debug, info};
     use config::Config;
+    use rusqlite::OptionalExtension;
     
     let codec = PostgresCodec::new();
     let mut framed = Framed::new(stream, codec);
@@ -159,7 +160,32 @@ pub async fn handle_test_connection_with_pool(
     if config.use_pooling {
         info!("Connection pooling enabled with read/write separation (pool size: {})", config.pool_size);
     }
-    
+
+    // Reject a connecting role that CREATE/ALTER ROLE has explicitly marked
+    // NOLOGIN. We don't implement PostgreSQL's MD5/SCRAM password exchange
+    // (there's no wire-protocol support for a password challenge yet), so
+    // this stops short of real authentication - an unmanaged or unknown
+    // `user` parameter (the common case: nothing has ever called CREATE ROLE)
+    // is let through unchanged, matching the previous trust-everyone behavior.
+    let rolcanlogin: Option<String> = db_handler.with_session_connection(&session_id, |conn| {
+        conn.query_row(
+            "SELECT rolcanlogin FROM __pgsqlite_roles WHERE rolname = ?1",
+            [&session.user],
+            |row| row.get(0),
+        ).optional()
+    }).await?;
+
+    if rolcanlogin.as_deref() == Some("f") {
+        let err = ErrorResponse::new(
+            "FATAL".to_string(),
+            "28000".to_string(),
+            format!("role \"{}\" is not permitted to log in", session.user),
+        );
+        framed.send(BackendMessage::ErrorResponse(Box::new(err))).await?;
+        framed.flush().await?;
+        return Err(anyhow::anyhow!("role \"{}\" is not permitted to log in", session.user));
+    }
+
     // Send authentication OK
     framed.send(BackendMessage::Authentication(AuthenticationMessage::Ok)).await?;
     
@@ -25,6 +25,17 @@ lazy_static! {
         register_v16_pg_proc_support(&mut registry);
         register_v17_pg_description_support(&mut registry);
         register_v18_pg_roles_user_support(&mut registry);
+        register_v19_generated_columns(&mut registry);
+        register_v20_sequence_state(&mut registry);
+        register_v21_pg_sequence_catalog(&mut registry);
+        register_v22_persisted_roles(&mut registry);
+        register_v23_persisted_privileges(&mut registry);
+        register_v24_persisted_table_oids(&mut registry);
+        register_v25_sequence_objects(&mut registry);
+        register_v26_row_level_security(&mut registry);
+        register_v27_constraint_column_usage(&mut registry);
+        register_v28_relacl_and_pg_authid(&mut registry);
+        register_v29_persisted_attrelid(&mut registry);
 
         registry
     };
@@ -2305,4 +2316,1746 @@ fn register_v18_pg_roles_user_support(registry: &mut BTreeMap<u32, Migration>) {
         "#)),
         dependencies: vec![17],
     });
-}
\ No newline at end of file
+}
+
+/// Version 19: Generated column support
+fn register_v19_generated_columns(registry: &mut BTreeMap<u32, Migration>) {
+    registry.insert(19, Migration {
+        version: 19,
+        name: "generated_columns",
+        description: "Track SQLite GENERATED ALWAYS AS columns so pg_attribute.attgenerated reflects them",
+        up: MigrationAction::SqlBatch(&[
+            // Side table recording each generated column's expression and storage kind,
+            // populated by constraint_populator::populate_generated_columns at DDL time.
+            r#"
+            CREATE TABLE IF NOT EXISTS __pgsqlite_generated_columns (
+                table_name TEXT NOT NULL,
+                column_name TEXT NOT NULL,
+                expression TEXT NOT NULL,
+                generation_type TEXT NOT NULL DEFAULT 's',
+                PRIMARY KEY (table_name, column_name)
+            );
+            "#,
+            // Recreate pg_attribute so attgenerated reflects recorded generated columns
+            r#"
+            DROP VIEW IF EXISTS pg_attribute;
+            "#,
+            r#"
+            CREATE VIEW IF NOT EXISTS pg_attribute AS
+            SELECT
+                CAST(
+                    (
+                        (unicode(substr(m.name, 1, 1)) * 1000000) +
+                        (unicode(substr(m.name || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(m.name || '  ', 3, 1)) * 100) +
+                        (length(m.name) * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as attrelid,
+                p.cid + 1 as attnum,
+                p.name as attname,
+                CASE
+                    WHEN p.type LIKE '%INT%' THEN 23
+                    WHEN p.type = 'TEXT' THEN 25
+                    WHEN p.type = 'REAL' THEN 700
+                    WHEN p.type = 'BLOB' THEN 17
+                    WHEN p.type LIKE '%CHAR%' THEN 1043
+                    WHEN p.type = 'BOOLEAN' THEN 16
+                    WHEN p.type = 'DATE' THEN 1082
+                    WHEN p.type LIKE 'TIME%' THEN 1083
+                    WHEN p.type LIKE 'TIMESTAMP%' THEN 1114
+                    ELSE 25
+                END as atttypid,
+                -1 as attstattarget,
+                0 as attlen,
+                0 as attndims,
+                -1 as attcacheoff,
+                CASE WHEN p.type LIKE '%NOT NULL%' THEN 't' ELSE 'f' END as attnotnull,
+                'f' as atthasdef,
+                'f' as atthasmissing,
+                '' as attidentity,
+                COALESCE(g.generation_type, '') as attgenerated,
+                't' as attisdropped,
+                't' as attislocal,
+                0 as attinhcount,
+                0 as attcollation,
+                '' as attacl,
+                '' as attoptions,
+                '' as attfdwoptions,
+                '' as attmissingval
+            FROM pragma_table_info(m.name) p
+            JOIN sqlite_master m ON m.type = 'table'
+            LEFT JOIN __pgsqlite_generated_columns g
+                ON g.table_name = m.name AND g.column_name = p.name
+            WHERE m.type = 'table'
+              AND m.name NOT LIKE 'sqlite_%'
+              AND m.name NOT LIKE '__pgsqlite_%';
+            "#,
+            r#"
+            UPDATE __pgsqlite_metadata
+            SET value = '19', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+            "#,
+        ]),
+        down: Some(MigrationAction::Sql(r#"
+            DROP VIEW IF EXISTS pg_attribute;
+            DROP TABLE IF EXISTS __pgsqlite_generated_columns;
+
+            CREATE VIEW IF NOT EXISTS pg_attribute AS
+            SELECT
+                CAST(
+                    (
+                        (unicode(substr(m.name, 1, 1)) * 1000000) +
+                        (unicode(substr(m.name || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(m.name || '  ', 3, 1)) * 100) +
+                        (length(m.name) * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as attrelid,
+                p.cid + 1 as attnum,
+                p.name as attname,
+                CASE
+                    WHEN p.type LIKE '%INT%' THEN 23
+                    WHEN p.type = 'TEXT' THEN 25
+                    WHEN p.type = 'REAL' THEN 700
+                    WHEN p.type = 'BLOB' THEN 17
+                    WHEN p.type LIKE '%CHAR%' THEN 1043
+                    WHEN p.type = 'BOOLEAN' THEN 16
+                    WHEN p.type = 'DATE' THEN 1082
+                    WHEN p.type LIKE 'TIME%' THEN 1083
+                    WHEN p.type LIKE 'TIMESTAMP%' THEN 1114
+                    ELSE 25
+                END as atttypid,
+                -1 as attstattarget,
+                0 as attlen,
+                0 as attndims,
+                -1 as attcacheoff,
+                CASE WHEN p.type LIKE '%NOT NULL%' THEN 't' ELSE 'f' END as attnotnull,
+                'f' as atthasdef,
+                'f' as atthasmissing,
+                '' as attidentity,
+                '' as attgenerated,
+                't' as attisdropped,
+                't' as attislocal,
+                0 as attinhcount,
+                0 as attcollation,
+                '' as attacl,
+                '' as attoptions,
+                '' as attfdwoptions,
+                '' as attmissingval
+            FROM pragma_table_info(m.name) p
+            JOIN sqlite_master m ON m.type = 'table'
+            WHERE m.type = 'table'
+              AND m.name NOT LIKE 'sqlite_%'
+              AND m.name NOT LIKE '__pgsqlite_%';
+
+            UPDATE __pgsqlite_metadata
+            SET value = '18', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+        "#)),
+        dependencies: vec![18],
+    });
+}
+
+/// Version 20: Sequence state tracking
+fn register_v20_sequence_state(registry: &mut BTreeMap<u32, Migration>) {
+    registry.insert(20, Migration {
+        version: 20,
+        name: "sequence_state",
+        description: "Track emulated-serial sequence counters for nextval/currval/setval and ALTER SEQUENCE",
+        up: MigrationAction::SqlBatch(&[
+            // Per-sequence counter state, seeded by
+            // constraint_populator::populate_table_dependencies whenever a
+            // single-column INTEGER PRIMARY KEY is created, and advanced by
+            // the nextval/currval/setval scalar functions.
+            r#"
+            CREATE TABLE IF NOT EXISTS __pgsqlite_sequences (
+                seq_oid TEXT PRIMARY KEY,
+                sequence_name TEXT NOT NULL UNIQUE,
+                table_name TEXT NOT NULL,
+                column_name TEXT NOT NULL,
+                last_value INTEGER NOT NULL DEFAULT 1,
+                start_value INTEGER NOT NULL DEFAULT 1,
+                increment_by INTEGER NOT NULL DEFAULT 1,
+                min_value INTEGER NOT NULL DEFAULT 1,
+                max_value INTEGER NOT NULL DEFAULT 9223372036854775807,
+                is_called INTEGER NOT NULL DEFAULT 0
+            );
+            "#,
+            r#"
+            UPDATE __pgsqlite_metadata
+            SET value = '20', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+            "#,
+        ]),
+        down: Some(MigrationAction::Sql(r#"
+            DROP TABLE IF EXISTS __pgsqlite_sequences;
+
+            UPDATE __pgsqlite_metadata
+            SET value = '19', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+        "#)),
+        dependencies: vec![19],
+    });
+}
+
+/// Version 21: pg_sequence catalog
+fn register_v21_pg_sequence_catalog(registry: &mut BTreeMap<u32, Migration>) {
+    registry.insert(21, Migration {
+        version: 21,
+        name: "pg_sequence_catalog",
+        description: "Add a real pg_sequence table and list emulated serial sequences in pg_class, so ORMs can introspect them",
+        up: MigrationAction::SqlBatch(&[
+            // One row per sequence backing a single-column INTEGER PRIMARY KEY,
+            // populated by constraint_populator::populate_table_dependencies
+            // alongside the pg_depend row for that sequence.
+            r#"
+            CREATE TABLE IF NOT EXISTS pg_sequence (
+                seqrelid TEXT PRIMARY KEY,
+                seqtypid INTEGER NOT NULL DEFAULT 20,
+                seqstart INTEGER NOT NULL,
+                seqincrement INTEGER NOT NULL,
+                seqmax INTEGER NOT NULL,
+                seqmin INTEGER NOT NULL,
+                seqcache INTEGER NOT NULL DEFAULT 1,
+                seqcycle INTEGER NOT NULL DEFAULT 0
+            );
+            "#,
+            // List sequences in pg_class too (relkind 'S') so a
+            // pg_class/pg_sequence join resolves the way it does in real
+            // PostgreSQL - using the same seq_oid/name __pgsqlite_sequences
+            // already tracks rather than re-deriving a fresh OID formula.
+            "DROP VIEW IF EXISTS pg_class;",
+            r#"
+            CREATE VIEW IF NOT EXISTS pg_class AS
+            SELECT
+                CAST(
+                    (
+                        (unicode(substr(name, 1, 1)) * 1000000) +
+                        (unicode(substr(name || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(name || '  ', 3, 1)) * 100) +
+                        (length(name) * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as oid,
+                name as relname,
+                2200 as relnamespace,
+                CASE
+                    WHEN type = 'table' THEN 'r'
+                    WHEN type = 'view' THEN 'v'
+                    WHEN type = 'index' THEN 'i'
+                END as relkind,
+                10 as relowner,
+                CASE WHEN type = 'index' THEN 403 ELSE 0 END as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                CASE WHEN type = 'table' THEN 't' ELSE 'f' END as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                CAST(
+                    (
+                        (unicode(substr(name || '_type', 1, 1)) * 1000000) +
+                        (unicode(substr(name || '_type' || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(name || '_type' || '  ', 3, 1)) * 100) +
+                        (length(name || '_type') * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                'f' as relrowsecurity,
+                'f' as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                't' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                '' as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM sqlite_master
+            WHERE type IN ('table', 'view', 'index')
+              AND name NOT LIKE 'sqlite_%'
+              AND name NOT LIKE '__pgsqlite_%'
+            UNION ALL
+            SELECT
+                seq_oid as oid,
+                sequence_name as relname,
+                2200 as relnamespace,
+                'S' as relkind,
+                10 as relowner,
+                0 as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                'f' as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                0 as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                'f' as relrowsecurity,
+                'f' as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                'f' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                '' as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM __pgsqlite_sequences;
+            "#,
+            r#"
+            UPDATE __pgsqlite_metadata
+            SET value = '21', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+            "#,
+        ]),
+        down: Some(MigrationAction::Sql(r#"
+            DROP TABLE IF EXISTS pg_sequence;
+            DROP VIEW IF EXISTS pg_class;
+
+            CREATE VIEW IF NOT EXISTS pg_class AS
+            SELECT
+                CAST(
+                    (
+                        (unicode(substr(name, 1, 1)) * 1000000) +
+                        (unicode(substr(name || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(name || '  ', 3, 1)) * 100) +
+                        (length(name) * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as oid,
+                name as relname,
+                2200 as relnamespace,
+                CASE
+                    WHEN type = 'table' THEN 'r'
+                    WHEN type = 'view' THEN 'v'
+                    WHEN type = 'index' THEN 'i'
+                END as relkind,
+                10 as relowner,
+                CASE WHEN type = 'index' THEN 403 ELSE 0 END as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                CASE WHEN type = 'table' THEN 't' ELSE 'f' END as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                CAST(
+                    (
+                        (unicode(substr(name || '_type', 1, 1)) * 1000000) +
+                        (unicode(substr(name || '_type' || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(name || '_type' || '  ', 3, 1)) * 100) +
+                        (length(name || '_type') * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                'f' as relrowsecurity,
+                'f' as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                't' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                '' as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM sqlite_master
+            WHERE type IN ('table', 'view', 'index')
+              AND name NOT LIKE 'sqlite_%'
+              AND name NOT LIKE '__pgsqlite_%';
+
+            UPDATE __pgsqlite_metadata
+            SET value = '20', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+        "#)),
+        dependencies: vec![20],
+    });
+}
+
+/// Version 22: Persisted roles
+fn register_v22_persisted_roles(registry: &mut BTreeMap<u32, Migration>) {
+    registry.insert(22, Migration {
+        version: 22,
+        name: "persisted_roles",
+        description: "Back pg_roles/pg_user with a real __pgsqlite_roles table, mutated by CREATE/ALTER/DROP ROLE",
+        up: MigrationAction::SqlBatch(&[
+            // One row per role, populated by crate::ddl::RoleDdlHandler.
+            r#"
+            CREATE TABLE IF NOT EXISTS __pgsqlite_roles (
+                oid INTEGER PRIMARY KEY,
+                rolname TEXT NOT NULL UNIQUE,
+                rolsuper TEXT NOT NULL DEFAULT 'f',
+                rolinherit TEXT NOT NULL DEFAULT 't',
+                rolcreaterole TEXT NOT NULL DEFAULT 'f',
+                rolcreatedb TEXT NOT NULL DEFAULT 'f',
+                rolcanlogin TEXT NOT NULL DEFAULT 'f',
+                rolreplication TEXT NOT NULL DEFAULT 'f',
+                rolbypassrls TEXT NOT NULL DEFAULT 'f',
+                rolconnlimit INTEGER NOT NULL DEFAULT -1,
+                rolpassword TEXT,
+                rolvaliduntil TEXT
+            );
+            "#,
+            // Seed the three roles pg_roles/pg_user used to hardcode, so
+            // existing installs see the same rows once the views below
+            // start reading from this table instead of a constant UNION ALL.
+            r#"
+            INSERT OR IGNORE INTO __pgsqlite_roles
+                (oid, rolname, rolsuper, rolinherit, rolcreaterole, rolcreatedb, rolcanlogin, rolreplication, rolbypassrls, rolconnlimit, rolpassword, rolvaliduntil)
+            VALUES
+                (10, 'postgres', 't', 't', 't', 't', 't', 't', 't', -1, '********', NULL),
+                (0, 'public', 'f', 't', 'f', 'f', 'f', 'f', 'f', -1, NULL, NULL),
+                (100, 'pgsqlite_user', 't', 't', 't', 't', 't', 'f', 't', -1, '********', NULL);
+            "#,
+            "DROP VIEW IF EXISTS pg_roles;",
+            r#"
+            CREATE VIEW IF NOT EXISTS pg_roles AS
+            SELECT
+                oid,
+                rolname,
+                rolsuper,
+                rolinherit,
+                rolcreaterole,
+                rolcreatedb,
+                rolcanlogin,
+                rolreplication,
+                rolconnlimit,
+                CASE WHEN rolpassword IS NULL THEN NULL ELSE '********' END as rolpassword,
+                rolvaliduntil,
+                rolbypassrls,
+                NULL as rolconfig
+            FROM __pgsqlite_roles;
+            "#,
+            "DROP VIEW IF EXISTS pg_user;",
+            r#"
+            CREATE VIEW IF NOT EXISTS pg_user AS
+            SELECT
+                rolname as usename,
+                oid as usesysid,
+                rolcreatedb as usecreatedb,
+                rolsuper as usesuper,
+                rolreplication as userepl,
+                rolbypassrls as usebypassrls,
+                CASE WHEN rolpassword IS NULL THEN NULL ELSE '********' END as passwd,
+                rolvaliduntil as valuntil,
+                NULL as useconfig
+            FROM __pgsqlite_roles
+            WHERE rolcanlogin = 't';
+            "#,
+            r#"
+            UPDATE __pgsqlite_metadata
+            SET value = '22', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+            "#,
+        ]),
+        down: Some(MigrationAction::Sql(r#"
+            DROP VIEW IF EXISTS pg_roles;
+            DROP VIEW IF EXISTS pg_user;
+            DROP TABLE IF EXISTS __pgsqlite_roles;
+
+            CREATE VIEW IF NOT EXISTS pg_roles AS
+            SELECT
+                10 as oid, 'postgres' as rolname, 't' as rolsuper, 't' as rolinherit,
+                't' as rolcreaterole, 't' as rolcreatedb, 't' as rolcanlogin, 't' as rolreplication,
+                -1 as rolconnlimit, '********' as rolpassword, NULL as rolvaliduntil, 't' as rolbypassrls, NULL as rolconfig
+            UNION ALL
+            SELECT
+                0 as oid, 'public' as rolname, 'f' as rolsuper, 't' as rolinherit,
+                'f' as rolcreaterole, 'f' as rolcreatedb, 'f' as rolcanlogin, 'f' as rolreplication,
+                -1 as rolconnlimit, NULL as rolpassword, NULL as rolvaliduntil, 'f' as rolbypassrls, NULL as rolconfig
+            UNION ALL
+            SELECT
+                100 as oid, 'pgsqlite_user' as rolname, 't' as rolsuper, 't' as rolinherit,
+                't' as rolcreaterole, 't' as rolcreatedb, 't' as rolcanlogin, 'f' as rolreplication,
+                -1 as rolconnlimit, '********' as rolpassword, NULL as rolvaliduntil, 't' as rolbypassrls, NULL as rolconfig;
+
+            CREATE VIEW IF NOT EXISTS pg_user AS
+            SELECT
+                'postgres' as usename, 10 as usesysid, 't' as usecreatedb, 't' as usesuper,
+                't' as userepl, 't' as usebypassrls, '********' as passwd, NULL as valuntil, NULL as useconfig
+            UNION ALL
+            SELECT
+                'pgsqlite_user' as usename, 100 as usesysid, 't' as usecreatedb, 't' as usesuper,
+                'f' as userepl, 't' as usebypassrls, '********' as passwd, NULL as valuntil, NULL as useconfig;
+
+            UPDATE __pgsqlite_metadata
+            SET value = '21', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+        "#)),
+        dependencies: vec![21],
+    });
+}
+/// Version 23: Persisted privileges and role membership
+fn register_v23_persisted_privileges(registry: &mut BTreeMap<u32, Migration>) {
+    registry.insert(23, Migration {
+        version: 23,
+        name: "persisted_privileges",
+        description: "Back pg_auth_members with a real table, add __pgsqlite_privileges for GRANT/REVOKE on objects",
+        up: MigrationAction::SqlBatch(&[
+            // One row per (grantee, object, privilege) grant, mutated by
+            // crate::ddl::GrantDdlHandler and consulted by has_table_privilege()
+            // and friends in crate::functions::system_functions.
+            r#"
+            CREATE TABLE IF NOT EXISTS __pgsqlite_privileges (
+                grantee TEXT NOT NULL,
+                object_kind TEXT NOT NULL,
+                object_name TEXT NOT NULL,
+                privilege_type TEXT NOT NULL,
+                grantable TEXT NOT NULL DEFAULT 'f',
+                PRIMARY KEY (grantee, object_kind, object_name, privilege_type)
+            );
+            "#,
+            // One row per role-membership edge, mutated by `GRANT role TO
+            // role`/`REVOKE role FROM role` and exposed as pg_auth_members.
+            r#"
+            CREATE TABLE IF NOT EXISTS __pgsqlite_auth_members (
+                roleid INTEGER NOT NULL,
+                member INTEGER NOT NULL,
+                admin_option TEXT NOT NULL DEFAULT 'f',
+                PRIMARY KEY (roleid, member)
+            );
+            "#,
+            "DROP VIEW IF EXISTS pg_auth_members;",
+            r#"
+            CREATE VIEW IF NOT EXISTS pg_auth_members AS
+            SELECT
+                roleid,
+                member,
+                10 as grantor,
+                admin_option
+            FROM __pgsqlite_auth_members;
+            "#,
+            r#"
+            UPDATE __pgsqlite_metadata
+            SET value = '23', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+            "#,
+        ]),
+        down: Some(MigrationAction::Sql(r#"
+            DROP VIEW IF EXISTS pg_auth_members;
+            DROP TABLE IF EXISTS __pgsqlite_auth_members;
+            DROP TABLE IF EXISTS __pgsqlite_privileges;
+
+            UPDATE __pgsqlite_metadata
+            SET value = '22', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+        "#)),
+        dependencies: vec![22],
+    });
+}
+
+/// Version 24: Persisted table OIDs
+///
+/// `pg_class.oid` for real tables used to be recomputed inline with the
+/// `unicode()`-sampling formula every time the view was queried, which
+/// guarantees collisions across a schema of any real size and handed two
+/// distinct tables the same OID - breaking Rails sequence discovery and any
+/// join keyed on `pg_depend.refobjid`. Backfill every pre-existing table
+/// into `__pgsqlite_oid_registry` (the same persisted allocator
+/// `crate::utils::oid_registry` now uses for new tables, via
+/// `constraint_populator::generate_table_oid`) and have `pg_class` resolve a
+/// table's oid with a LEFT JOIN against it, falling back to the old formula
+/// only for the rare row a table-creation hook hasn't registered yet (e.g. a
+/// table that predates the registry table itself and hasn't been touched
+/// since). Views and indexes keep the old inline formula for `oid`, and
+/// every relkind keeps it for `reltype` - neither is part of the
+/// objid/refobjid join this migration fixes, so widening scope to them is
+/// left for a future pass.
+fn register_v24_persisted_table_oids(registry: &mut BTreeMap<u32, Migration>) {
+    registry.insert(24, Migration {
+        version: 24,
+        name: "persisted_table_oids",
+        description: "Back pg_class.oid for tables with the persisted __pgsqlite_oid_registry allocator instead of a recomputed hash",
+        up: MigrationAction::SqlBatch(&[
+            // Deterministically seed the registry for every table that
+            // existed before this migration ran, so the LEFT JOIN below
+            // never falls back to the old formula for them. New tables are
+            // registered as they're created, via
+            // constraint_populator::generate_table_oid.
+            r#"
+            CREATE TABLE IF NOT EXISTS __pgsqlite_oid_registry (
+                oid INTEGER PRIMARY KEY,
+                object_kind TEXT NOT NULL,
+                object_name TEXT NOT NULL,
+                UNIQUE(object_kind, object_name)
+            );
+            "#,
+            r#"
+            INSERT OR IGNORE INTO __pgsqlite_oid_registry (oid, object_kind, object_name)
+            SELECT 16384 + ROW_NUMBER() OVER (ORDER BY name) - 1, 'table', name
+            FROM sqlite_master
+            WHERE type = 'table'
+              AND name NOT LIKE 'sqlite_%'
+              AND name NOT LIKE '__pgsqlite_%';
+            "#,
+            "DROP VIEW IF EXISTS pg_class;",
+            r#"
+            CREATE VIEW IF NOT EXISTS pg_class AS
+            SELECT
+                CASE
+                    WHEN sqlite_master.type = 'table' THEN CAST(
+                        COALESCE(
+                            oid_reg.oid,
+                            (
+                                (unicode(substr(sqlite_master.name, 1, 1)) * 1000000) +
+                                (unicode(substr(sqlite_master.name || ' ', 2, 1)) * 10000) +
+                                (unicode(substr(sqlite_master.name || '  ', 3, 1)) * 100) +
+                                (length(sqlite_master.name) * 7)
+                            ) % 1000000 + 16384
+                        )
+                    AS TEXT)
+                    ELSE CAST(
+                        (
+                            (unicode(substr(sqlite_master.name, 1, 1)) * 1000000) +
+                            (unicode(substr(sqlite_master.name || ' ', 2, 1)) * 10000) +
+                            (unicode(substr(sqlite_master.name || '  ', 3, 1)) * 100) +
+                            (length(sqlite_master.name) * 7)
+                        ) % 1000000 + 16384
+                    AS TEXT)
+                END as oid,
+                sqlite_master.name as relname,
+                2200 as relnamespace,
+                CASE
+                    WHEN sqlite_master.type = 'table' THEN 'r'
+                    WHEN sqlite_master.type = 'view' THEN 'v'
+                    WHEN sqlite_master.type = 'index' THEN 'i'
+                END as relkind,
+                10 as relowner,
+                CASE WHEN sqlite_master.type = 'index' THEN 403 ELSE 0 END as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                CASE WHEN sqlite_master.type = 'table' THEN 't' ELSE 'f' END as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                CAST(
+                    (
+                        (unicode(substr(sqlite_master.name || '_type', 1, 1)) * 1000000) +
+                        (unicode(substr(sqlite_master.name || '_type' || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(sqlite_master.name || '_type' || '  ', 3, 1)) * 100) +
+                        (length(sqlite_master.name || '_type') * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                'f' as relrowsecurity,
+                'f' as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                't' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                '' as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM sqlite_master
+            LEFT JOIN __pgsqlite_oid_registry oid_reg
+                ON oid_reg.object_kind = 'table'
+                AND oid_reg.object_name = sqlite_master.name
+            WHERE sqlite_master.type IN ('table', 'view', 'index')
+              AND sqlite_master.name NOT LIKE 'sqlite_%'
+              AND sqlite_master.name NOT LIKE '__pgsqlite_%'
+            UNION ALL
+            SELECT
+                seq_oid as oid,
+                sequence_name as relname,
+                2200 as relnamespace,
+                'S' as relkind,
+                10 as relowner,
+                0 as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                'f' as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                0 as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                'f' as relrowsecurity,
+                'f' as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                'f' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                '' as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM __pgsqlite_sequences;
+            "#,
+            r#"
+            UPDATE __pgsqlite_metadata
+            SET value = '24', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+            "#,
+        ]),
+        down: Some(MigrationAction::Sql(r#"
+            DROP VIEW IF EXISTS pg_class;
+
+            CREATE VIEW IF NOT EXISTS pg_class AS
+            SELECT
+                CAST(
+                    (
+                        (unicode(substr(name, 1, 1)) * 1000000) +
+                        (unicode(substr(name || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(name || '  ', 3, 1)) * 100) +
+                        (length(name) * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as oid,
+                name as relname,
+                2200 as relnamespace,
+                CASE
+                    WHEN type = 'table' THEN 'r'
+                    WHEN type = 'view' THEN 'v'
+                    WHEN type = 'index' THEN 'i'
+                END as relkind,
+                10 as relowner,
+                CASE WHEN type = 'index' THEN 403 ELSE 0 END as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                CASE WHEN type = 'table' THEN 't' ELSE 'f' END as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                CAST(
+                    (
+                        (unicode(substr(name || '_type', 1, 1)) * 1000000) +
+                        (unicode(substr(name || '_type' || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(name || '_type' || '  ', 3, 1)) * 100) +
+                        (length(name || '_type') * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                'f' as relrowsecurity,
+                'f' as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                't' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                '' as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM sqlite_master
+            WHERE type IN ('table', 'view', 'index')
+              AND name NOT LIKE 'sqlite_%'
+              AND name NOT LIKE '__pgsqlite_%'
+            UNION ALL
+            SELECT
+                seq_oid as oid,
+                sequence_name as relname,
+                2200 as relnamespace,
+                'S' as relkind,
+                10 as relowner,
+                0 as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                'f' as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                0 as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                'f' as relrowsecurity,
+                'f' as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                'f' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                '' as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM __pgsqlite_sequences;
+
+            UPDATE __pgsqlite_metadata
+            SET value = '23', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+        "#)),
+        dependencies: vec![23],
+    });
+}
+
+/// Version 25: First-class sequence objects
+///
+/// `CREATE SEQUENCE`/`DROP SEQUENCE` (handled in
+/// `constraint_populator::handle_create_sequence`/`handle_drop_sequence`)
+/// insert/remove rows in the same `__pgsqlite_sequences`/`pg_sequence`
+/// tables the implicit serial-column sequences already use, so no new
+/// storage is needed for the sequences themselves. This migration adds the
+/// two pieces those rows alone don't cover: a `pg_sequences` view (the
+/// human-readable introspection view ORMs query, distinct from the
+/// `pg_sequence` catalog table), and `__pgsqlite_lastval`, the single-row
+/// table `lastval()` uses to remember which sequence `nextval()` touched
+/// most recently.
+fn register_v25_sequence_objects(registry: &mut BTreeMap<u32, Migration>) {
+    registry.insert(25, Migration {
+        version: 25,
+        name: "sequence_objects",
+        description: "Add pg_sequences view and __pgsqlite_lastval for standalone CREATE SEQUENCE and lastval()",
+        up: MigrationAction::SqlBatch(&[
+            r#"
+            CREATE TABLE IF NOT EXISTS __pgsqlite_lastval (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                sequence_name TEXT
+            );
+            "#,
+            "DROP VIEW IF EXISTS pg_sequences;",
+            r#"
+            CREATE VIEW IF NOT EXISTS pg_sequences AS
+            SELECT
+                'public' as schemaname,
+                s.sequence_name as sequencename,
+                10 as sequenceowner,
+                ps.seqtypid as data_type,
+                ps.seqstart as start_value,
+                ps.seqmin as min_value,
+                ps.seqmax as max_value,
+                ps.seqincrement as increment_by,
+                CASE WHEN ps.seqcycle = 1 THEN 't' ELSE 'f' END as cycle,
+                ps.seqcache as cache_size,
+                CASE WHEN s.is_called = 1 THEN s.last_value ELSE NULL END as last_value
+            FROM __pgsqlite_sequences s
+            JOIN pg_sequence ps ON ps.seqrelid = s.seq_oid;
+            "#,
+            r#"
+            UPDATE __pgsqlite_metadata
+            SET value = '25', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+            "#,
+        ]),
+        down: Some(MigrationAction::Sql(r#"
+            DROP VIEW IF EXISTS pg_sequences;
+            DROP TABLE IF EXISTS __pgsqlite_lastval;
+
+            UPDATE __pgsqlite_metadata
+            SET value = '24', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+        "#)),
+        dependencies: vec![24],
+    });
+}
+
+/// Version 26: Row-level security catalog
+///
+/// `ALTER TABLE ... ENABLE/DISABLE/FORCE ROW LEVEL SECURITY` and
+/// `CREATE POLICY`/`DROP POLICY` (handled in
+/// `ddl::policy_ddl_handler::PolicyDdlHandler`) persist into the two new
+/// tables this migration adds: `__pgsqlite_rls_tables` tracks each table's
+/// row-security flags, and `__pgsqlite_policies` holds the individual
+/// policies. `pg_class.relrowsecurity`/`relforcerowsecurity` (previously
+/// hardcoded `'f'`) are rewritten to read from `__pgsqlite_rls_tables`, and
+/// `pg_policy`/`pg_policies` expose the policies themselves the same way
+/// `pg_sequence`/`pg_sequences` expose sequences. Enforcement - rewriting
+/// SELECT/UPDATE/DELETE against a protected table to apply the matching
+/// policies' USING/WITH CHECK expressions - is not part of this migration;
+/// see `PolicyDdlHandler`'s doc comment.
+fn register_v26_row_level_security(registry: &mut BTreeMap<u32, Migration>) {
+    registry.insert(26, Migration {
+        version: 26,
+        name: "row_level_security",
+        description: "Add __pgsqlite_rls_tables/__pgsqlite_policies and pg_policy/pg_policies catalog relations",
+        up: MigrationAction::SqlBatch(&[
+            r#"
+            CREATE TABLE IF NOT EXISTS __pgsqlite_rls_tables (
+                table_name TEXT PRIMARY KEY,
+                rowsecurity TEXT NOT NULL DEFAULT 'f',
+                forcerowsecurity TEXT NOT NULL DEFAULT 'f'
+            );
+            "#,
+            r#"
+            CREATE TABLE IF NOT EXISTS __pgsqlite_policies (
+                oid INTEGER PRIMARY KEY,
+                policy_name TEXT NOT NULL,
+                table_name TEXT NOT NULL,
+                table_oid TEXT NOT NULL,
+                permissive TEXT NOT NULL DEFAULT 't',
+                cmd TEXT NOT NULL DEFAULT '*',
+                roles TEXT NOT NULL DEFAULT 'public',
+                using_expr TEXT,
+                check_expr TEXT,
+                UNIQUE(policy_name, table_name)
+            );
+            "#,
+            "DROP VIEW IF EXISTS pg_class;",
+            r#"
+            CREATE VIEW IF NOT EXISTS pg_class AS
+            SELECT
+                CASE
+                    WHEN sqlite_master.type = 'table' THEN CAST(
+                        COALESCE(
+                            oid_reg.oid,
+                            (
+                                (unicode(substr(sqlite_master.name, 1, 1)) * 1000000) +
+                                (unicode(substr(sqlite_master.name || ' ', 2, 1)) * 10000) +
+                                (unicode(substr(sqlite_master.name || '  ', 3, 1)) * 100) +
+                                (length(sqlite_master.name) * 7)
+                            ) % 1000000 + 16384
+                        )
+                    AS TEXT)
+                    ELSE CAST(
+                        (
+                            (unicode(substr(sqlite_master.name, 1, 1)) * 1000000) +
+                            (unicode(substr(sqlite_master.name || ' ', 2, 1)) * 10000) +
+                            (unicode(substr(sqlite_master.name || '  ', 3, 1)) * 100) +
+                            (length(sqlite_master.name) * 7)
+                        ) % 1000000 + 16384
+                    AS TEXT)
+                END as oid,
+                sqlite_master.name as relname,
+                2200 as relnamespace,
+                CASE
+                    WHEN sqlite_master.type = 'table' THEN 'r'
+                    WHEN sqlite_master.type = 'view' THEN 'v'
+                    WHEN sqlite_master.type = 'index' THEN 'i'
+                END as relkind,
+                10 as relowner,
+                CASE WHEN sqlite_master.type = 'index' THEN 403 ELSE 0 END as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                CASE WHEN sqlite_master.type = 'table' THEN 't' ELSE 'f' END as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                CAST(
+                    (
+                        (unicode(substr(sqlite_master.name || '_type', 1, 1)) * 1000000) +
+                        (unicode(substr(sqlite_master.name || '_type' || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(sqlite_master.name || '_type' || '  ', 3, 1)) * 100) +
+                        (length(sqlite_master.name || '_type') * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                CASE WHEN sqlite_master.type = 'table' THEN COALESCE(rls.rowsecurity, 'f') ELSE 'f' END as relrowsecurity,
+                CASE WHEN sqlite_master.type = 'table' THEN COALESCE(rls.forcerowsecurity, 'f') ELSE 'f' END as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                't' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                '' as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM sqlite_master
+            LEFT JOIN __pgsqlite_oid_registry oid_reg
+                ON oid_reg.object_kind = 'table'
+                AND oid_reg.object_name = sqlite_master.name
+            LEFT JOIN __pgsqlite_rls_tables rls
+                ON rls.table_name = sqlite_master.name
+            WHERE sqlite_master.type IN ('table', 'view', 'index')
+              AND sqlite_master.name NOT LIKE 'sqlite_%'
+              AND sqlite_master.name NOT LIKE '__pgsqlite_%'
+            UNION ALL
+            SELECT
+                seq_oid as oid,
+                sequence_name as relname,
+                2200 as relnamespace,
+                'S' as relkind,
+                10 as relowner,
+                0 as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                'f' as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                0 as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                'f' as relrowsecurity,
+                'f' as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                'f' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                '' as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM __pgsqlite_sequences;
+            "#,
+            "DROP VIEW IF EXISTS pg_policy;",
+            r#"
+            CREATE VIEW IF NOT EXISTS pg_policy AS
+            SELECT
+                oid,
+                policy_name as polname,
+                table_oid as polrelid,
+                cmd as polcmd,
+                permissive as polpermissive,
+                roles as polroles,
+                using_expr as polqual,
+                check_expr as polwithcheck
+            FROM __pgsqlite_policies;
+            "#,
+            "DROP VIEW IF EXISTS pg_policies;",
+            r#"
+            CREATE VIEW IF NOT EXISTS pg_policies AS
+            SELECT
+                'public' as schemaname,
+                table_name as tablename,
+                policy_name as policyname,
+                CASE WHEN permissive = 't' THEN 'PERMISSIVE' ELSE 'RESTRICTIVE' END as permissive,
+                roles,
+                CASE cmd
+                    WHEN 'r' THEN 'SELECT'
+                    WHEN 'a' THEN 'INSERT'
+                    WHEN 'w' THEN 'UPDATE'
+                    WHEN 'd' THEN 'DELETE'
+                    ELSE 'ALL'
+                END as cmd,
+                using_expr as qual,
+                check_expr as with_check
+            FROM __pgsqlite_policies;
+            "#,
+            r#"
+            UPDATE __pgsqlite_metadata
+            SET value = '26', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+            "#,
+        ]),
+        down: Some(MigrationAction::Sql(r#"
+            DROP VIEW IF EXISTS pg_policies;
+            DROP VIEW IF EXISTS pg_policy;
+            DROP VIEW IF EXISTS pg_class;
+
+            CREATE VIEW IF NOT EXISTS pg_class AS
+            SELECT
+                CASE
+                    WHEN sqlite_master.type = 'table' THEN CAST(
+                        COALESCE(
+                            oid_reg.oid,
+                            (
+                                (unicode(substr(sqlite_master.name, 1, 1)) * 1000000) +
+                                (unicode(substr(sqlite_master.name || ' ', 2, 1)) * 10000) +
+                                (unicode(substr(sqlite_master.name || '  ', 3, 1)) * 100) +
+                                (length(sqlite_master.name) * 7)
+                            ) % 1000000 + 16384
+                        )
+                    AS TEXT)
+                    ELSE CAST(
+                        (
+                            (unicode(substr(sqlite_master.name, 1, 1)) * 1000000) +
+                            (unicode(substr(sqlite_master.name || ' ', 2, 1)) * 10000) +
+                            (unicode(substr(sqlite_master.name || '  ', 3, 1)) * 100) +
+                            (length(sqlite_master.name) * 7)
+                        ) % 1000000 + 16384
+                    AS TEXT)
+                END as oid,
+                sqlite_master.name as relname,
+                2200 as relnamespace,
+                CASE
+                    WHEN sqlite_master.type = 'table' THEN 'r'
+                    WHEN sqlite_master.type = 'view' THEN 'v'
+                    WHEN sqlite_master.type = 'index' THEN 'i'
+                END as relkind,
+                10 as relowner,
+                CASE WHEN sqlite_master.type = 'index' THEN 403 ELSE 0 END as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                CASE WHEN sqlite_master.type = 'table' THEN 't' ELSE 'f' END as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                CAST(
+                    (
+                        (unicode(substr(sqlite_master.name || '_type', 1, 1)) * 1000000) +
+                        (unicode(substr(sqlite_master.name || '_type' || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(sqlite_master.name || '_type' || '  ', 3, 1)) * 100) +
+                        (length(sqlite_master.name || '_type') * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                'f' as relrowsecurity,
+                'f' as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                't' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                '' as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM sqlite_master
+            LEFT JOIN __pgsqlite_oid_registry oid_reg
+                ON oid_reg.object_kind = 'table'
+                AND oid_reg.object_name = sqlite_master.name
+            WHERE sqlite_master.type IN ('table', 'view', 'index')
+              AND sqlite_master.name NOT LIKE 'sqlite_%'
+              AND sqlite_master.name NOT LIKE '__pgsqlite_%'
+            UNION ALL
+            SELECT
+                seq_oid as oid,
+                sequence_name as relname,
+                2200 as relnamespace,
+                'S' as relkind,
+                10 as relowner,
+                0 as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                'f' as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                0 as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                'f' as relrowsecurity,
+                'f' as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                'f' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                '' as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM __pgsqlite_sequences;
+
+            DROP TABLE IF EXISTS __pgsqlite_policies;
+            DROP TABLE IF EXISTS __pgsqlite_rls_tables;
+
+            UPDATE __pgsqlite_metadata
+            SET value = '25', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+        "#)),
+        dependencies: vec![25],
+    });
+}
+
+/// Version 27: information_schema.constraint_column_usage view
+///
+/// Companion to the v14 information_schema views: PostgreSQL's
+/// `constraint_column_usage` identifies the columns a constraint actually
+/// uses, which for `PRIMARY KEY`/`UNIQUE` is the constrained table's own
+/// columns (same membership as `key_column_usage`) but for `FOREIGN KEY`
+/// is the *referenced* table's columns (`confrelid`/`confkey`), not the
+/// referencing ones. Built the same way as `information_schema_key_column_usage`:
+/// joining `pg_constraint`/`pg_class`/`pg_attribute` and testing membership
+/// in the comma-joined `conkey`/`confkey` column-number lists.
+fn register_v27_constraint_column_usage(registry: &mut BTreeMap<u32, Migration>) {
+    registry.insert(27, Migration {
+        version: 27,
+        name: "constraint_column_usage",
+        description: "Add information_schema.constraint_column_usage view for schema-reflection tools",
+        up: MigrationAction::SqlBatch(&[
+            r#"
+            CREATE VIEW IF NOT EXISTS information_schema_constraint_column_usage AS
+            SELECT
+                'main' as table_catalog,
+                'public' as table_schema,
+                c.relname as table_name,
+                a.attname as column_name,
+                'main' as constraint_catalog,
+                'public' as constraint_schema,
+                con.conname as constraint_name
+            FROM pg_constraint con
+            JOIN pg_class c ON con.conrelid = c.oid
+            JOIN pg_attribute a ON c.oid = a.attrelid
+            WHERE con.contype IN ('p', 'u')
+              AND a.attnum > 0
+              AND (',' || con.conkey || ',') LIKE ('%,' || a.attnum || ',%')
+            UNION ALL
+            SELECT
+                'main' as table_catalog,
+                'public' as table_schema,
+                rc.relname as table_name,
+                a.attname as column_name,
+                'main' as constraint_catalog,
+                'public' as constraint_schema,
+                con.conname as constraint_name
+            FROM pg_constraint con
+            JOIN pg_class rc ON con.confrelid = rc.oid
+            JOIN pg_attribute a ON rc.oid = a.attrelid
+            WHERE con.contype = 'f'
+              AND a.attnum > 0
+              AND (',' || con.confkey || ',') LIKE ('%,' || a.attnum || ',%');
+            "#,
+            r#"
+            UPDATE __pgsqlite_metadata
+            SET value = '27', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+            "#,
+        ]),
+        down: Some(MigrationAction::Sql(r#"
+            DROP VIEW IF EXISTS information_schema_constraint_column_usage;
+
+            UPDATE __pgsqlite_metadata
+            SET value = '26', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+        "#)),
+        dependencies: vec![26],
+    });
+}
+
+/// Version 28: pg_class.relacl population and pg_authid
+///
+/// Two gaps in the roles/privileges subsystem added across v22/v23: `pg_class.relacl`
+/// was left hardcoded to `''` in every prior version of the view, so `GRANT`/`REVOKE`
+/// (persisted in `__pgsqlite_privileges` since v23) never showed up in `\dp`-style
+/// tooling that reads it; and `pg_authid` - the superuser-only table pg_roles/pg_user
+/// are themselves defined as filtered views over, per `pg_user.rs` - didn't exist at
+/// all. Adds `pg_authid` as a thin, unmasked view over `__pgsqlite_roles` (the same
+/// role data `pg_roles` exposes, minus the `rolpassword` masking `pg_roles` applies),
+/// and rewrites `pg_class` to aggregate `__pgsqlite_privileges` rows for each relation
+/// into a PostgreSQL aclitem-array-literal string (`{grantee=privs/grantor,...}`),
+/// using PostgreSQL's single-character privilege codes (`r`, `a`, `w`, `d`, `D`,
+/// `x`, `t`) for the privilege names stored in `__pgsqlite_privileges`.
+fn register_v28_relacl_and_pg_authid(registry: &mut BTreeMap<u32, Migration>) {
+    registry.insert(28, Migration {
+        version: 28,
+        name: "relacl_and_pg_authid",
+        description: "Populate pg_class.relacl from __pgsqlite_privileges and add the pg_authid catalog view",
+        up: MigrationAction::SqlBatch(&[
+            r#"
+            CREATE VIEW IF NOT EXISTS pg_authid AS
+            SELECT
+                oid,
+                rolname,
+                rolsuper,
+                rolinherit,
+                rolcreaterole,
+                rolcreatedb,
+                rolcanlogin,
+                rolreplication,
+                rolbypassrls,
+                rolconnlimit,
+                rolpassword,
+                rolvaliduntil
+            FROM __pgsqlite_roles;
+            "#,
+            "DROP VIEW IF EXISTS pg_class;",
+            r#"
+            CREATE VIEW IF NOT EXISTS pg_class AS
+            SELECT
+                CASE
+                    WHEN sqlite_master.type = 'table' THEN CAST(
+                        COALESCE(
+                            oid_reg.oid,
+                            (
+                                (unicode(substr(sqlite_master.name, 1, 1)) * 1000000) +
+                                (unicode(substr(sqlite_master.name || ' ', 2, 1)) * 10000) +
+                                (unicode(substr(sqlite_master.name || '  ', 3, 1)) * 100) +
+                                (length(sqlite_master.name) * 7)
+                            ) % 1000000 + 16384
+                        )
+                    AS TEXT)
+                    ELSE CAST(
+                        (
+                            (unicode(substr(sqlite_master.name, 1, 1)) * 1000000) +
+                            (unicode(substr(sqlite_master.name || ' ', 2, 1)) * 10000) +
+                            (unicode(substr(sqlite_master.name || '  ', 3, 1)) * 100) +
+                            (length(sqlite_master.name) * 7)
+                        ) % 1000000 + 16384
+                    AS TEXT)
+                END as oid,
+                sqlite_master.name as relname,
+                2200 as relnamespace,
+                CASE
+                    WHEN sqlite_master.type = 'table' THEN 'r'
+                    WHEN sqlite_master.type = 'view' THEN 'v'
+                    WHEN sqlite_master.type = 'index' THEN 'i'
+                END as relkind,
+                10 as relowner,
+                CASE WHEN sqlite_master.type = 'index' THEN 403 ELSE 0 END as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                CASE WHEN sqlite_master.type = 'table' THEN 't' ELSE 'f' END as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                CAST(
+                    (
+                        (unicode(substr(sqlite_master.name || '_type', 1, 1)) * 1000000) +
+                        (unicode(substr(sqlite_master.name || '_type' || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(sqlite_master.name || '_type' || '  ', 3, 1)) * 100) +
+                        (length(sqlite_master.name || '_type') * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                CASE WHEN sqlite_master.type = 'table' THEN COALESCE(rls.rowsecurity, 'f') ELSE 'f' END as relrowsecurity,
+                CASE WHEN sqlite_master.type = 'table' THEN COALESCE(rls.forcerowsecurity, 'f') ELSE 'f' END as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                't' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                COALESCE(
+                    (
+                        SELECT '{' || group_concat(grantee_acl.acl, ',') || '}'
+                        FROM (
+                            SELECT
+                                COALESCE(priv.grantee, 'public') || '=' || group_concat(
+                                    CASE priv.privilege_type
+                                        WHEN 'ALL' THEN 'arwdDxt'
+                                        WHEN 'SELECT' THEN 'r'
+                                        WHEN 'INSERT' THEN 'a'
+                                        WHEN 'UPDATE' THEN 'w'
+                                        WHEN 'DELETE' THEN 'd'
+                                        WHEN 'TRUNCATE' THEN 'D'
+                                        WHEN 'REFERENCES' THEN 'x'
+                                        WHEN 'TRIGGER' THEN 't'
+                                        ELSE ''
+                                    END, ''
+                                ) || '/postgres' as acl
+                            FROM (
+                                SELECT * FROM __pgsqlite_privileges
+                                WHERE object_kind = 'table' AND object_name = sqlite_master.name
+                                ORDER BY CASE privilege_type
+                                    WHEN 'SELECT' THEN 1 WHEN 'INSERT' THEN 2 WHEN 'UPDATE' THEN 3
+                                    WHEN 'DELETE' THEN 4 WHEN 'TRUNCATE' THEN 5 WHEN 'REFERENCES' THEN 6
+                                    WHEN 'TRIGGER' THEN 7 ELSE 0 END
+                            ) priv
+                            GROUP BY priv.grantee
+                        ) grantee_acl
+                    ),
+                    ''
+                ) as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM sqlite_master
+            LEFT JOIN __pgsqlite_oid_registry oid_reg
+                ON oid_reg.object_kind = 'table'
+                AND oid_reg.object_name = sqlite_master.name
+            LEFT JOIN __pgsqlite_rls_tables rls
+                ON rls.table_name = sqlite_master.name
+            WHERE sqlite_master.type IN ('table', 'view', 'index')
+              AND sqlite_master.name NOT LIKE 'sqlite_%'
+              AND sqlite_master.name NOT LIKE '__pgsqlite_%'
+            UNION ALL
+            SELECT
+                seq_oid as oid,
+                sequence_name as relname,
+                2200 as relnamespace,
+                'S' as relkind,
+                10 as relowner,
+                0 as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                'f' as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                0 as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                'f' as relrowsecurity,
+                'f' as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                'f' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                COALESCE(
+                    (
+                        SELECT '{' || group_concat(grantee_acl.acl, ',') || '}'
+                        FROM (
+                            SELECT
+                                COALESCE(priv.grantee, 'public') || '=' || group_concat(
+                                    CASE priv.privilege_type
+                                        WHEN 'ALL' THEN 'arwdDxt'
+                                        WHEN 'SELECT' THEN 'r'
+                                        WHEN 'INSERT' THEN 'a'
+                                        WHEN 'UPDATE' THEN 'w'
+                                        WHEN 'DELETE' THEN 'd'
+                                        WHEN 'TRUNCATE' THEN 'D'
+                                        WHEN 'REFERENCES' THEN 'x'
+                                        WHEN 'TRIGGER' THEN 't'
+                                        ELSE ''
+                                    END, ''
+                                ) || '/postgres' as acl
+                            FROM (
+                                SELECT * FROM __pgsqlite_privileges
+                                WHERE object_kind = 'sequence' AND object_name = __pgsqlite_sequences.sequence_name
+                                ORDER BY CASE privilege_type
+                                    WHEN 'SELECT' THEN 1 WHEN 'INSERT' THEN 2 WHEN 'UPDATE' THEN 3
+                                    WHEN 'DELETE' THEN 4 WHEN 'TRUNCATE' THEN 5 WHEN 'REFERENCES' THEN 6
+                                    WHEN 'TRIGGER' THEN 7 ELSE 0 END
+                            ) priv
+                            GROUP BY priv.grantee
+                        ) grantee_acl
+                    ),
+                    ''
+                ) as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM __pgsqlite_sequences;
+            "#,
+            r#"
+            UPDATE __pgsqlite_metadata
+            SET value = '28', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+            "#,
+        ]),
+        down: Some(MigrationAction::Sql(r#"
+            DROP VIEW IF EXISTS pg_authid;
+            DROP VIEW IF EXISTS pg_class;
+
+            CREATE VIEW IF NOT EXISTS pg_class AS
+            SELECT
+                CASE
+                    WHEN sqlite_master.type = 'table' THEN CAST(
+                        COALESCE(
+                            oid_reg.oid,
+                            (
+                                (unicode(substr(sqlite_master.name, 1, 1)) * 1000000) +
+                                (unicode(substr(sqlite_master.name || ' ', 2, 1)) * 10000) +
+                                (unicode(substr(sqlite_master.name || '  ', 3, 1)) * 100) +
+                                (length(sqlite_master.name) * 7)
+                            ) % 1000000 + 16384
+                        )
+                    AS TEXT)
+                    ELSE CAST(
+                        (
+                            (unicode(substr(sqlite_master.name, 1, 1)) * 1000000) +
+                            (unicode(substr(sqlite_master.name || ' ', 2, 1)) * 10000) +
+                            (unicode(substr(sqlite_master.name || '  ', 3, 1)) * 100) +
+                            (length(sqlite_master.name) * 7)
+                        ) % 1000000 + 16384
+                    AS TEXT)
+                END as oid,
+                sqlite_master.name as relname,
+                2200 as relnamespace,
+                CASE
+                    WHEN sqlite_master.type = 'table' THEN 'r'
+                    WHEN sqlite_master.type = 'view' THEN 'v'
+                    WHEN sqlite_master.type = 'index' THEN 'i'
+                END as relkind,
+                10 as relowner,
+                CASE WHEN sqlite_master.type = 'index' THEN 403 ELSE 0 END as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                CASE WHEN sqlite_master.type = 'table' THEN 't' ELSE 'f' END as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                CAST(
+                    (
+                        (unicode(substr(sqlite_master.name || '_type', 1, 1)) * 1000000) +
+                        (unicode(substr(sqlite_master.name || '_type' || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(sqlite_master.name || '_type' || '  ', 3, 1)) * 100) +
+                        (length(sqlite_master.name || '_type') * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                CASE WHEN sqlite_master.type = 'table' THEN COALESCE(rls.rowsecurity, 'f') ELSE 'f' END as relrowsecurity,
+                CASE WHEN sqlite_master.type = 'table' THEN COALESCE(rls.forcerowsecurity, 'f') ELSE 'f' END as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                't' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                '' as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM sqlite_master
+            LEFT JOIN __pgsqlite_oid_registry oid_reg
+                ON oid_reg.object_kind = 'table'
+                AND oid_reg.object_name = sqlite_master.name
+            LEFT JOIN __pgsqlite_rls_tables rls
+                ON rls.table_name = sqlite_master.name
+            WHERE sqlite_master.type IN ('table', 'view', 'index')
+              AND sqlite_master.name NOT LIKE 'sqlite_%'
+              AND sqlite_master.name NOT LIKE '__pgsqlite_%'
+            UNION ALL
+            SELECT
+                seq_oid as oid,
+                sequence_name as relname,
+                2200 as relnamespace,
+                'S' as relkind,
+                10 as relowner,
+                0 as relam,
+                0 as relfilenode,
+                0 as reltablespace,
+                0 as relpages,
+                -1 as reltuples,
+                0 as relallvisible,
+                0 as reltoastrelid,
+                'f' as relhasindex,
+                'f' as relisshared,
+                'p' as relpersistence,
+                0 as reltype,
+                0 as reloftype,
+                0 as relnatts,
+                0 as relchecks,
+                'f' as relhasrules,
+                'f' as relhastriggers,
+                'f' as relhassubclass,
+                'f' as relrowsecurity,
+                'f' as relforcerowsecurity,
+                't' as relispopulated,
+                'p' as relreplident,
+                'f' as relispartition,
+                0 as relrewrite,
+                0 as relfrozenxid,
+                '{}' as relminmxid,
+                '' as relacl,
+                '' as reloptions,
+                '' as relpartbound
+            FROM __pgsqlite_sequences;
+
+            UPDATE __pgsqlite_metadata
+            SET value = '27', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+        "#)),
+        dependencies: vec![27],
+    });
+}
+
+/// Version 29: Back `pg_attribute.attrelid` with the persisted OID allocator
+///
+/// v24 moved `pg_class.oid` onto `__pgsqlite_oid_registry`, but
+/// `pg_attribute.attrelid` (added back in v19) was never touched and kept
+/// recomputing the old inline hash formula. The two disagree for every
+/// table, so `pg_attribute JOIN pg_class ON attrelid = oid` - the single
+/// most common catalog JOIN an ORM issues - silently returned zero rows.
+/// Resolve `attrelid` the same way `pg_class.oid` does: a LEFT JOIN against
+/// the registry, falling back to the old formula only for a table the
+/// registry hasn't seen yet.
+fn register_v29_persisted_attrelid(registry: &mut BTreeMap<u32, Migration>) {
+    registry.insert(29, Migration {
+        version: 29,
+        name: "persisted_attrelid",
+        description: "Back pg_attribute.attrelid with the persisted __pgsqlite_oid_registry allocator instead of a recomputed hash",
+        up: MigrationAction::SqlBatch(&[
+            r#"
+            DROP VIEW IF EXISTS pg_attribute;
+            "#,
+            r#"
+            CREATE VIEW IF NOT EXISTS pg_attribute AS
+            SELECT
+                CAST(
+                    COALESCE(
+                        oid_reg.oid,
+                        (
+                            (unicode(substr(m.name, 1, 1)) * 1000000) +
+                            (unicode(substr(m.name || ' ', 2, 1)) * 10000) +
+                            (unicode(substr(m.name || '  ', 3, 1)) * 100) +
+                            (length(m.name) * 7)
+                        ) % 1000000 + 16384
+                    )
+                AS TEXT) as attrelid,
+                p.cid + 1 as attnum,
+                p.name as attname,
+                CASE
+                    WHEN p.type LIKE '%INT%' THEN 23
+                    WHEN p.type = 'TEXT' THEN 25
+                    WHEN p.type = 'REAL' THEN 700
+                    WHEN p.type = 'BLOB' THEN 17
+                    WHEN p.type LIKE '%CHAR%' THEN 1043
+                    WHEN p.type = 'BOOLEAN' THEN 16
+                    WHEN p.type = 'DATE' THEN 1082
+                    WHEN p.type LIKE 'TIME%' THEN 1083
+                    WHEN p.type LIKE 'TIMESTAMP%' THEN 1114
+                    ELSE 25
+                END as atttypid,
+                -1 as attstattarget,
+                0 as attlen,
+                0 as attndims,
+                -1 as attcacheoff,
+                CASE WHEN p.type LIKE '%NOT NULL%' THEN 't' ELSE 'f' END as attnotnull,
+                'f' as atthasdef,
+                'f' as atthasmissing,
+                '' as attidentity,
+                COALESCE(g.generation_type, '') as attgenerated,
+                't' as attisdropped,
+                't' as attislocal,
+                0 as attinhcount,
+                0 as attcollation,
+                '' as attacl,
+                '' as attoptions,
+                '' as attfdwoptions,
+                '' as attmissingval
+            FROM pragma_table_info(m.name) p
+            JOIN sqlite_master m ON m.type = 'table'
+            LEFT JOIN __pgsqlite_generated_columns g
+                ON g.table_name = m.name AND g.column_name = p.name
+            LEFT JOIN __pgsqlite_oid_registry oid_reg
+                ON oid_reg.object_kind = 'table' AND oid_reg.object_name = m.name
+            WHERE m.type = 'table'
+              AND m.name NOT LIKE 'sqlite_%'
+              AND m.name NOT LIKE '__pgsqlite_%';
+            "#,
+            r#"
+            UPDATE __pgsqlite_metadata
+            SET value = '29', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+            "#,
+        ]),
+        down: Some(MigrationAction::Sql(r#"
+            DROP VIEW IF EXISTS pg_attribute;
+
+            CREATE VIEW IF NOT EXISTS pg_attribute AS
+            SELECT
+                CAST(
+                    (
+                        (unicode(substr(m.name, 1, 1)) * 1000000) +
+                        (unicode(substr(m.name || ' ', 2, 1)) * 10000) +
+                        (unicode(substr(m.name || '  ', 3, 1)) * 100) +
+                        (length(m.name) * 7)
+                    ) % 1000000 + 16384
+                AS TEXT) as attrelid,
+                p.cid + 1 as attnum,
+                p.name as attname,
+                CASE
+                    WHEN p.type LIKE '%INT%' THEN 23
+                    WHEN p.type = 'TEXT' THEN 25
+                    WHEN p.type = 'REAL' THEN 700
+                    WHEN p.type = 'BLOB' THEN 17
+                    WHEN p.type LIKE '%CHAR%' THEN 1043
+                    WHEN p.type = 'BOOLEAN' THEN 16
+                    WHEN p.type = 'DATE' THEN 1082
+                    WHEN p.type LIKE 'TIME%' THEN 1083
+                    WHEN p.type LIKE 'TIMESTAMP%' THEN 1114
+                    ELSE 25
+                END as atttypid,
+                -1 as attstattarget,
+                0 as attlen,
+                0 as attndims,
+                -1 as attcacheoff,
+                CASE WHEN p.type LIKE '%NOT NULL%' THEN 't' ELSE 'f' END as attnotnull,
+                'f' as atthasdef,
+                'f' as atthasmissing,
+                '' as attidentity,
+                COALESCE(g.generation_type, '') as attgenerated,
+                't' as attisdropped,
+                't' as attislocal,
+                0 as attinhcount,
+                0 as attcollation,
+                '' as attacl,
+                '' as attoptions,
+                '' as attfdwoptions,
+                '' as attmissingval
+            FROM pragma_table_info(m.name) p
+            JOIN sqlite_master m ON m.type = 'table'
+            LEFT JOIN __pgsqlite_generated_columns g
+                ON g.table_name = m.name AND g.column_name = p.name
+            WHERE m.type = 'table'
+              AND m.name NOT LIKE 'sqlite_%'
+              AND m.name NOT LIKE '__pgsqlite_%';
+
+            UPDATE __pgsqlite_metadata
+            SET value = '28', updated_at = strftime('%s', 'now')
+            WHERE key = 'schema_version';
+        "#)),
+        dependencies: vec![28],
+    });
+}
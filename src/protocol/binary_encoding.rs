@@ -6,6 +6,22 @@ use crate::PgSqliteError;
 use rust_decimal::Decimal;
 use std::str::FromStr;
 
+// TSRANGE/DATERANGE have no PgType variant yet; their wire OIDs are fixed by
+// Postgres so we can still recognize and binary-encode them by number.
+const TSRANGE_OID: i32 = 3908;
+const DATERANGE_OID: i32 = 3912;
+
+/// Element type of a range's bounds, used to pick the right binary encoding
+/// for the lower/upper values inside `encode_range`.
+#[derive(Clone, Copy)]
+enum RangeSubtype {
+    Int4,
+    Int8,
+    Numeric,
+    Timestamp,
+    Date,
+}
+
 /// Optimized binary result encoder that minimizes allocations
 pub struct BinaryResultEncoder {
     buffer: BytesMut,
@@ -230,9 +246,20 @@ impl BinaryResultEncoder {
                         }
                     }
                 }
+                t if t == PgType::Int4range.to_oid() => {
+                    self.encode_range(text, RangeSubtype::Int4)
+                }
+                t if t == PgType::Int8range.to_oid() => {
+                    self.encode_range(text, RangeSubtype::Int8)
+                }
                 t if t == PgType::Numrange.to_oid() => {
-                    // Encode NUMRANGE in PostgreSQL binary format
-                    self.encode_numrange(text)
+                    self.encode_range(text, RangeSubtype::Numeric)
+                }
+                TSRANGE_OID => {
+                    self.encode_range(text, RangeSubtype::Timestamp)
+                }
+                DATERANGE_OID => {
+                    self.encode_range(text, RangeSubtype::Date)
                 }
                 _ => false
             }
@@ -245,41 +272,42 @@ impl BinaryResultEncoder {
         }
     }
 
-    /// Encode NUMRANGE value in PostgreSQL binary format
-    fn encode_numrange(&mut self, text: &str) -> bool {
-        // Parse NUMRANGE text format: "empty", "[1.5,10.5)", etc.
+    /// Encode a range value (`empty`, `[1,10)`, `(,5]`, ...) in PostgreSQL
+    /// binary range format: a flags byte followed by each non-infinite bound
+    /// as a length-prefixed value encoded per `subtype`.
+    fn encode_range(&mut self, text: &str, subtype: RangeSubtype) -> bool {
         let trimmed = text.trim();
-        
-        if trimmed == "empty" {
+
+        if trimmed.eq_ignore_ascii_case("empty") {
             // Empty range - just the flags byte with EMPTY flag set
             self.buffer.put_u8(0x01); // EMPTY flag
             return true;
         }
-        
+
         // Parse range format: [lower,upper) or (lower,upper] etc.
         if trimmed.len() < 3 {
             return false;
         }
-        
+
         let lower_inclusive = trimmed.starts_with('[');
         let upper_inclusive = trimmed.ends_with(']');
-        
+
         // Extract the bounds part (remove brackets)
         let bounds = &trimmed[1..trimmed.len()-1];
-        
+
         // Split on comma to get lower and upper bounds
-        let parts: Vec<&str> = bounds.split(',').collect();
+        let parts: Vec<&str> = bounds.splitn(2, ',').collect();
         if parts.len() != 2 {
             return false;
         }
-        
+
         let lower_str = parts[0].trim();
         let upper_str = parts[1].trim();
-        
+
         // Check for infinite bounds (PostgreSQL uses empty string or special values)
         let lower_infinite = lower_str.is_empty() || lower_str == "-infinity";
         let upper_infinite = upper_str.is_empty() || upper_str == "infinity";
-        
+
         // Build flags byte
         let mut flags = 0u8;
         if lower_inclusive {
@@ -294,36 +322,80 @@ impl BinaryResultEncoder {
         if upper_infinite {
             flags |= 0x10; // UB_INF
         }
-        
+
         // Write flags
         self.buffer.put_u8(flags);
-        
+
         // Write bounds (only if not infinite)
-        if !lower_infinite {
-            if let Ok(lower_val) = Decimal::from_str(lower_str) {
-                // Encode as NUMERIC
-                let encoded = DecimalHandler::encode_numeric(&lower_val);
-                self.buffer.put_i32(encoded.len() as i32);
-                self.buffer.put_slice(&encoded);
-            } else {
-                return false;
-            }
+        if !lower_infinite && !self.encode_range_bound(lower_str, subtype) {
+            return false;
         }
-        
-        if !upper_infinite {
-            if let Ok(upper_val) = Decimal::from_str(upper_str) {
-                // Encode as NUMERIC
-                let encoded = DecimalHandler::encode_numeric(&upper_val);
-                self.buffer.put_i32(encoded.len() as i32);
-                self.buffer.put_slice(&encoded);
-            } else {
-                return false;
+
+        if !upper_infinite && !self.encode_range_bound(upper_str, subtype) {
+            return false;
+        }
+
+        true
+    }
+
+    /// Encode one range bound as a length-prefixed value, using the same
+    /// wire representation as a bare column of the subtype would get.
+    fn encode_range_bound(&mut self, value: &str, subtype: RangeSubtype) -> bool {
+        let start = self.buffer.len();
+        self.buffer.put_i32(0); // placeholder length, patched below
+
+        let encoded = match subtype {
+            RangeSubtype::Int4 => value.parse::<i32>().map(|v| self.buffer.put_i32(v)).is_ok(),
+            RangeSubtype::Int8 => value.parse::<i64>().map(|v| self.buffer.put_i64(v)).is_ok(),
+            RangeSubtype::Numeric => {
+                if let Ok(decimal) = Decimal::from_str(value) {
+                    self.buffer.put_slice(&DecimalHandler::encode_numeric(&decimal));
+                    true
+                } else {
+                    false
+                }
             }
+            RangeSubtype::Timestamp => {
+                if let Ok(micros) = value.parse::<i64>() {
+                    self.buffer.put_slice(&BinaryEncoder::encode_timestamp(micros as f64));
+                    true
+                } else {
+                    false
+                }
+            }
+            RangeSubtype::Date => {
+                if let Ok(days) = value.parse::<i32>() {
+                    self.buffer.put_slice(&BinaryEncoder::encode_date(days as f64));
+                    true
+                } else {
+                    false
+                }
+            }
+        };
+
+        if !encoded {
+            self.buffer.truncate(start);
+            return false;
         }
-        
+
+        let len = (self.buffer.len() - start - 4) as i32;
+        self.buffer[start..start + 4].copy_from_slice(&len.to_be_bytes());
         true
     }
 
+    /// Binary-encode a single value already in canonical PostgreSQL text
+    /// form (not a full row), for callers that pick text/binary per column
+    /// outside of a full `encode_row` pass (e.g. the simple-query array/range
+    /// conversion in `QueryExecutor::convert_array_data_in_rows`).
+    pub(crate) fn encode_scalar(text: &str, type_oid: i32) -> Option<Vec<u8>> {
+        let mut encoder = Self::new(1, 1);
+        if encoder.encode_value_into_buffer(text.as_bytes(), type_oid) {
+            Some(encoder.buffer.to_vec())
+        } else {
+            None
+        }
+    }
+
     /// Get buffer statistics for monitoring
     pub fn stats(&self) -> (usize, usize, usize) {
         (
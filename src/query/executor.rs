@@ -952,7 +952,10 @@ impl QueryExecutor {
         // Convert array data before sending rows
         debug!("Converting array data for {} rows", response.rows.len());
         debug!("About to convert array data for {} rows", response.rows.len());
-        let converted_rows = Self::convert_array_data_in_rows(response.rows, &fields)?;
+        // Simple Query Protocol never negotiates a Bind format vector, so
+        // every column is text here; extended-query callers that do have one
+        // pass it through to get mixed per-column text/binary results.
+        let converted_rows = Self::convert_array_data_in_rows(response.rows, &fields, &[])?;
         debug!("Completed array data conversion");
         
         // Store row count before potential move
@@ -1327,7 +1330,65 @@ impl QueryExecutor {
         use crate::translator::CreateTableTranslator;
         use crate::query::{QueryTypeDetector, QueryType};
         use crate::ddl::EnumDdlHandler;
-        
+        use crate::ddl::RoleDdlHandler;
+        use crate::ddl::GrantDdlHandler;
+        use crate::ddl::PolicyDdlHandler;
+
+        // CREATE/ALTER/DROP ROLE (and their USER aliases) mutate
+        // __pgsqlite_roles directly rather than going through SQLite, since
+        // SQLite has no role system of its own.
+        if RoleDdlHandler::is_role_ddl(query) {
+            db.with_session_connection_mut(&session.id, |conn| {
+                RoleDdlHandler::handle_role_ddl(conn, query)
+                    .map_err(|e| rusqlite::Error::SqliteFailure(
+                        rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_ERROR),
+                        Some(format!("Role DDL failed: {e}"))
+                    ))
+            }).await?;
+
+            let command_tag = if query.trim().to_uppercase().starts_with("CREATE") {
+                "CREATE ROLE"
+            } else if query.trim().to_uppercase().starts_with("ALTER") {
+                "ALTER ROLE"
+            } else {
+                "DROP ROLE"
+            };
+
+            framed.send(BackendMessage::CommandComplete {
+                tag: command_tag.to_string()
+            }).await
+                .map_err(PgSqliteError::Io)?;
+
+            return Ok(());
+        }
+
+        // GRANT/REVOKE (both the privilege-on-object form and the
+        // role-membership form) mutate __pgsqlite_privileges /
+        // __pgsqlite_auth_members rather than going through SQLite, since
+        // SQLite has no privilege system of its own.
+        if GrantDdlHandler::is_grant_ddl(query) {
+            db.with_session_connection_mut(&session.id, |conn| {
+                GrantDdlHandler::handle_grant_ddl(conn, query)
+                    .map_err(|e| rusqlite::Error::SqliteFailure(
+                        rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_ERROR),
+                        Some(format!("GRANT/REVOKE failed: {e}"))
+                    ))
+            }).await?;
+
+            let command_tag = if query.trim().to_uppercase().starts_with("GRANT") {
+                "GRANT"
+            } else {
+                "REVOKE"
+            };
+
+            framed.send(BackendMessage::CommandComplete {
+                tag: command_tag.to_string()
+            }).await
+                .map_err(PgSqliteError::Io)?;
+
+            return Ok(());
+        }
+
         // Check if this is an ENUM DDL statement
         if EnumDdlHandler::is_enum_ddl(query) {
             // Handle ENUM DDL with session connections
@@ -1357,7 +1418,156 @@ impl QueryExecutor {
             
             return Ok(());
         }
-        
+
+        // ALTER SEQUENCE ... RESTART [WITH n] has no SQLite equivalent -
+        // rewrite it into an update of __pgsqlite_sequences instead of
+        // forwarding it and letting SQLite reject the syntax outright.
+        if crate::catalog::constraint_populator::is_alter_sequence(query) {
+            db.with_session_connection_mut(&session.id, |conn| {
+                crate::catalog::constraint_populator::handle_alter_sequence(conn, query)
+                    .map_err(|e| rusqlite::Error::SqliteFailure(
+                        rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_ERROR),
+                        Some(format!("ALTER SEQUENCE failed: {e}"))
+                    ))
+            }).await?;
+
+            framed.send(BackendMessage::CommandComplete {
+                tag: "ALTER SEQUENCE".to_string()
+            }).await
+                .map_err(PgSqliteError::Io)?;
+
+            return Ok(());
+        }
+
+        // CREATE SEQUENCE has no SQLite equivalent - rewrite it into
+        // __pgsqlite_sequences/pg_sequence rows instead of forwarding it.
+        if crate::catalog::constraint_populator::is_create_sequence(query) {
+            db.with_session_connection_mut(&session.id, |conn| {
+                crate::catalog::constraint_populator::handle_create_sequence(conn, query)
+                    .map_err(|e| rusqlite::Error::SqliteFailure(
+                        rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_ERROR),
+                        Some(format!("CREATE SEQUENCE failed: {e}"))
+                    ))
+            }).await?;
+
+            framed.send(BackendMessage::CommandComplete {
+                tag: "CREATE SEQUENCE".to_string()
+            }).await
+                .map_err(PgSqliteError::Io)?;
+
+            return Ok(());
+        }
+
+        // DROP SEQUENCE likewise has no SQLite equivalent.
+        if crate::catalog::constraint_populator::is_drop_sequence(query) {
+            db.with_session_connection_mut(&session.id, |conn| {
+                crate::catalog::constraint_populator::handle_drop_sequence(conn, query)
+                    .map_err(|e| rusqlite::Error::SqliteFailure(
+                        rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_ERROR),
+                        Some(format!("DROP SEQUENCE failed: {e}"))
+                    ))
+            }).await?;
+
+            framed.send(BackendMessage::CommandComplete {
+                tag: "DROP SEQUENCE".to_string()
+            }).await
+                .map_err(PgSqliteError::Io)?;
+
+            return Ok(());
+        }
+
+        // DROP TABLE without CASCADE must fail like PostgreSQL when another
+        // table still depends on it through a foreign key (an 'n'
+        // dependency); the table's own serial sequence ('a'/'i') does not
+        // block the drop. With CASCADE, drop owned sequences and detach
+        // foreign-key dependents first so the table drop that follows
+        // doesn't leave them orphaned.
+        if matches!(QueryTypeDetector::detect_query_type(query), QueryType::Drop)
+            && query.trim_start()[4..].trim_start().to_uppercase().starts_with("TABLE")
+            && let Some(table_name) = extract_table_name_from_drop(query) {
+                let has_cascade = query.to_uppercase().contains("CASCADE");
+                let has_dependents = db.with_session_connection(&session.id, |conn| {
+                    crate::catalog::constraint_populator::table_has_dependents(conn, &table_name)
+                        .map_err(|e| rusqlite::Error::SqliteFailure(
+                            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_ERROR),
+                            Some(format!("dependency check failed: {e}"))
+                        ))
+                }).await?;
+
+                if has_dependents && !has_cascade {
+                    return Err(PgSqliteError::Protocol(format!(
+                        "cannot drop table {table_name} because other objects depend on it\nHINT: Use DROP ... CASCADE to drop the dependent objects too."
+                    )));
+                }
+
+                if has_dependents && has_cascade {
+                    // Log the full transitive closure via the live-synthesized
+                    // dependency graph (the same one a client's `SELECT * FROM
+                    // pg_depend` sees) so CASCADE's actual scope is visible -
+                    // the stored pg_depend table only ever holds 'a'/'i' rows,
+                    // never the 'n' foreign-key dependents this walks.
+                    if let Ok(table_oid) = db.with_session_connection(&session.id, |conn| {
+                        crate::catalog::constraint_populator::generate_table_oid(conn, &table_name)
+                            .map_err(|e| rusqlite::Error::SqliteFailure(
+                                rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_ERROR),
+                                Some(format!("table oid lookup failed: {e}"))
+                            ))
+                    }).await
+                        && let Ok(table_oid) = table_oid.parse::<u32>() {
+                            match crate::catalog::pg_depend::PgDependHandler::find_dependents(db, Some(session.id), 1259, table_oid).await {
+                                Ok(dependents) => debug!(
+                                    "DROP TABLE {} CASCADE: cascading {} dependent object(s)",
+                                    table_name, dependents.len()
+                                ),
+                                Err(e) => debug!("DROP TABLE {} CASCADE: dependent lookup failed: {}", table_name, e),
+                            }
+                        }
+
+                    db.with_session_connection_mut(&session.id, |conn| {
+                        crate::catalog::constraint_populator::drop_dependent_sequences(conn, &table_name)
+                            .map_err(|e| rusqlite::Error::SqliteFailure(
+                                rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_ERROR),
+                                Some(format!("CASCADE drop of dependent sequences failed: {e}"))
+                            ))?;
+                        crate::catalog::constraint_populator::drop_foreign_key_dependents(conn, &table_name)
+                            .map(|_| ())
+                            .map_err(|e| rusqlite::Error::SqliteFailure(
+                                rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_ERROR),
+                                Some(format!("CASCADE drop of dependent foreign keys failed: {e}"))
+                            ))
+                    }).await?;
+                }
+            }
+
+        // ALTER TABLE ... ENABLE/DISABLE/FORCE ROW LEVEL SECURITY and
+        // CREATE/DROP POLICY have no SQLite equivalent - persist them into
+        // __pgsqlite_rls_tables/__pgsqlite_policies instead of forwarding.
+        if PolicyDdlHandler::is_policy_ddl(query) {
+            db.with_session_connection_mut(&session.id, |conn| {
+                PolicyDdlHandler::handle_policy_ddl(conn, query)
+                    .map_err(|e| rusqlite::Error::SqliteFailure(
+                        rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_ERROR),
+                        Some(format!("Row-level security DDL failed: {e}"))
+                    ))
+            }).await?;
+
+            let upper = query.trim().to_uppercase();
+            let command_tag = if upper.starts_with("ALTER TABLE") {
+                "ALTER TABLE"
+            } else if upper.starts_with("CREATE POLICY") {
+                "CREATE POLICY"
+            } else {
+                "DROP POLICY"
+            };
+
+            framed.send(BackendMessage::CommandComplete {
+                tag: command_tag.to_string()
+            }).await
+                .map_err(PgSqliteError::Io)?;
+
+            return Ok(());
+        }
+
         let (translated_query, type_mappings, enum_columns, array_columns) = if matches!(QueryTypeDetector::detect_query_type(query), QueryType::Create) && query.trim_start()[6..].trim_start().to_uppercase().starts_with("TABLE") {
             // Use CREATE TABLE translator with connection for ENUM support
             db.with_session_connection(&session.id, |conn| {
@@ -1373,6 +1583,13 @@ impl QueryExecutor {
             // For other DDL, check for JSON/JSONB types
             let translated = if query.to_lowercase().contains("json") || query.to_lowercase().contains("jsonb") {
                 JsonTranslator::translate_statement(query)?
+            } else if matches!(QueryTypeDetector::detect_query_type(query), QueryType::Drop)
+                && query.trim_start()[4..].trim_start().to_uppercase().starts_with("TABLE") {
+                // SQLite's DROP TABLE grammar has no CASCADE/RESTRICT clause
+                // ("near "CASCADE": syntax error") - the dependency check
+                // above already decided whether the drop is allowed, so
+                // strip the keyword before forwarding.
+                strip_drop_table_cascade_restrict(query)
             } else {
                 query.to_string()
             };
@@ -1381,7 +1598,34 @@ impl QueryExecutor {
         
         // Execute the translated query
         db.execute_with_session(&translated_query, &session.id).await?;
-        
+
+        // Keep pg_constraint/pg_attrdef/pg_index/pg_depend in sync with
+        // schema changes made outside of CREATE TABLE - otherwise they stay
+        // stuck with whatever shape the table had when it was first created.
+        match QueryTypeDetector::detect_query_type(query) {
+            QueryType::Alter if query.trim_start()[5..].trim_start().to_uppercase().starts_with("TABLE") => {
+                if let Some(table_name) = extract_table_name_from_alter(query) {
+                    db.with_session_connection(&session.id, |conn| {
+                        if let Err(e) = crate::catalog::constraint_populator::refresh_constraints_for_table(conn, &table_name) {
+                            debug!("Failed to refresh constraint catalog for table {}: {}", table_name, e);
+                        }
+                        Ok(())
+                    }).await?;
+                }
+            }
+            QueryType::Drop if query.trim_start()[4..].trim_start().to_uppercase().starts_with("TABLE") => {
+                if let Some(table_name) = extract_table_name_from_drop(query) {
+                    db.with_session_connection(&session.id, |conn| {
+                        if let Err(e) = crate::catalog::constraint_populator::remove_constraints_for_table(conn, &table_name) {
+                            debug!("Failed to remove constraint catalog rows for table {}: {}", table_name, e);
+                        }
+                        Ok(())
+                    }).await?;
+                }
+            }
+            _ => {}
+        }
+
         // If we have type mappings, store them in the metadata table
         debug!("Type mappings count: {}", type_mappings.len());
         if !type_mappings.is_empty() {
@@ -1757,31 +2001,80 @@ impl QueryExecutor {
         has_datetime_translation || is_date_function
     }
     
-    /// Convert array data in rows using type OIDs from field descriptions
+    // TSRANGE/DATERANGE have no PgType variant yet; their wire OIDs are fixed
+    // by Postgres so we can still recognize them by number.
+    const TSRANGE_OID: i32 = 3908;
+    const DATERANGE_OID: i32 = 3912;
+
+    /// Resolve the format code Postgres negotiated for column `col_idx`: a
+    /// single code applies to every column, an empty list means all-text,
+    /// and a per-column list selects text (0) or binary (1) independently.
+    fn resolve_column_format(result_formats: &[i16], col_idx: usize) -> i16 {
+        if result_formats.is_empty() {
+            0
+        } else if result_formats.len() == 1 {
+            result_formats[0]
+        } else {
+            result_formats.get(col_idx).copied().unwrap_or(0)
+        }
+    }
+
+    /// Element type OID for a known array type OID, used to binary-encode
+    /// array cells via `BinaryEncoder::encode_array`. Falls back to TEXT for
+    /// array types we don't special-case (matches the array's own text
+    /// fallback, which just stringifies unknown element kinds).
+    fn array_element_type_oid(array_oid: i32) -> i32 {
+        if array_oid == PgType::BoolArray.to_oid() {
+            PgType::Bool.to_oid()
+        } else if array_oid == PgType::Int4Array.to_oid() {
+            PgType::Int4.to_oid()
+        } else if array_oid == PgType::Int8Array.to_oid() {
+            PgType::Int8.to_oid()
+        } else if array_oid == PgType::Float8Array.to_oid() {
+            PgType::Float8.to_oid()
+        } else {
+            PgType::Text.to_oid()
+        }
+    }
+
+    /// Convert array data in rows using type OIDs from field descriptions,
+    /// honoring the per-column result format vector negotiated at Bind time
+    /// (see `resolve_column_format`): text-format columns get the usual
+    /// JSON-storage -> Postgres text literal conversion, binary-format
+    /// columns are encoded straight to wire binary instead.
     fn convert_array_data_in_rows(
         rows: Vec<Vec<Option<Vec<u8>>>>,
         fields: &[FieldDescription],
+        result_formats: &[i16],
     ) -> Result<Vec<Vec<Option<Vec<u8>>>>, PgSqliteError> {
         // Extract type OIDs from field descriptions
         let type_oids: Vec<i32> = fields.iter().map(|f| f.type_oid).collect();
         debug!("Type OIDs for conversion: {:?}", type_oids);
         debug!("Boolean type OID: {}", PgType::Bool.to_oid());
         
-        // Quick check: if no array, boolean, or datetime types, return rows as-is
+        // Quick check: if no array, boolean, datetime, or range types, return rows as-is
         let bool_oid = PgType::Bool.to_oid();
         let date_oid = PgType::Date.to_oid();
         let time_oid = PgType::Time.to_oid();
         let timetz_oid = PgType::Timetz.to_oid();
         let timestamp_oid = PgType::Timestamp.to_oid();
         let timestamptz_oid = PgType::Timestamptz.to_oid();
-        
+        let int4range_oid = PgType::Int4range.to_oid();
+        let int8range_oid = PgType::Int8range.to_oid();
+        let numrange_oid = PgType::Numrange.to_oid();
+
         let needs_conversion = type_oids.iter().any(|&oid| {
-            oid == bool_oid || 
+            oid == bool_oid ||
             oid == date_oid ||
             oid == time_oid ||
             oid == timetz_oid ||
             oid == timestamp_oid ||
             oid == timestamptz_oid ||
+            oid == int4range_oid ||
+            oid == int8range_oid ||
+            oid == numrange_oid ||
+            oid == Self::TSRANGE_OID ||
+            oid == Self::DATERANGE_OID ||
             PgType::from_oid(oid).is_some_and(|t| t.is_array())
         });
         
@@ -1796,20 +2089,37 @@ impl QueryExecutor {
             let mut converted_row = Vec::with_capacity(row.len());
             
             for (col_idx, cell) in row.into_iter().enumerate() {
+                let format = Self::resolve_column_format(result_formats, col_idx);
+
                 let converted_cell = if let Some(data) = cell {
                     let type_oid = type_oids.get(col_idx).copied().unwrap_or(25); // Default to TEXT
-                    
+
                     // Check if this is an array type that needs conversion
                     if PgType::from_oid(type_oid).is_some_and(|t| t.is_array()) {
-                        // Try to convert JSON array to PostgreSQL array format
-                        match Self::convert_json_to_pg_array(&data) {
-                            Ok(converted_data) => Some(converted_data),
-                            Err(_) => Some(data), // Keep original data if conversion fails
+                        if format == 1 {
+                            // Binary: encode the stored JSON array straight to wire format
+                            if let Ok(s) = std::str::from_utf8(&data) {
+                                let elem_oid = Self::array_element_type_oid(type_oid);
+                                match crate::protocol::binary::BinaryEncoder::encode_array(s, elem_oid) {
+                                    Ok(bytes) => Some(bytes),
+                                    Err(_) => Some(data),
+                                }
+                            } else {
+                                Some(data)
+                            }
+                        } else {
+                            // Try to convert JSON array to PostgreSQL array format
+                            match Self::convert_json_to_pg_array(&data) {
+                                Ok(converted_data) => Some(converted_data),
+                                Err(_) => Some(data), // Keep original data if conversion fails
+                            }
                         }
                     } else if type_oid == PgType::Bool.to_oid() {
-                        // Convert boolean values from integer 0/1 to PostgreSQL f/t format
-                        // Optimized: work directly with bytes to avoid string conversion overhead
-                        if data.len() == 1 && data[0] == b'0' {
+                        if format == 1 {
+                            Some(vec![if data.first() == Some(&b'1') { 1 } else { 0 }])
+                        } else if data.len() == 1 && data[0] == b'0' {
+                            // Convert boolean values from integer 0/1 to PostgreSQL f/t format
+                            // Optimized: work directly with bytes to avoid string conversion overhead
                             Some(b"f".to_vec())
                         } else if data.len() == 1 && data[0] == b'1' {
                             Some(b"t".to_vec())
@@ -1817,9 +2127,12 @@ impl QueryExecutor {
                             Some(data) // Keep original data if not 0/1
                         }
                     } else if type_oid == date_oid {
-                        // Convert INTEGER days to YYYY-MM-DD format
                         if let Ok(s) = std::str::from_utf8(&data) {
-                            if let Ok(days) = s.parse::<i32>() {
+                            if format == 1 {
+                                crate::protocol::binary_encoding::BinaryResultEncoder::encode_scalar(s, type_oid)
+                                    .or(Some(data))
+                            } else if let Ok(days) = s.parse::<i32>() {
+                                // Convert INTEGER days to YYYY-MM-DD format
                                 use crate::types::datetime_utils::format_days_to_date_buf;
                                 let mut buf = vec![0u8; 32];
                                 let len = format_days_to_date_buf(days, &mut buf);
@@ -1832,9 +2145,12 @@ impl QueryExecutor {
                             Some(data) // Keep original if not valid UTF-8
                         }
                     } else if type_oid == time_oid || type_oid == timetz_oid {
-                        // Convert INTEGER microseconds to HH:MM:SS.ffffff format
                         if let Ok(s) = std::str::from_utf8(&data) {
-                            if let Ok(micros) = s.parse::<i64>() {
+                            if format == 1 {
+                                crate::protocol::binary_encoding::BinaryResultEncoder::encode_scalar(s, type_oid)
+                                    .or(Some(data))
+                            } else if let Ok(micros) = s.parse::<i64>() {
+                                // Convert INTEGER microseconds to HH:MM:SS.ffffff format
                                 use crate::types::datetime_utils::format_microseconds_to_time_buf;
                                 let mut buf = vec![0u8; 32];
                                 let len = format_microseconds_to_time_buf(micros, &mut buf);
@@ -1847,9 +2163,12 @@ impl QueryExecutor {
                             Some(data) // Keep original if not valid UTF-8
                         }
                     } else if type_oid == timestamp_oid || type_oid == timestamptz_oid {
-                        // Convert INTEGER microseconds to YYYY-MM-DD HH:MM:SS.ffffff format
                         if let Ok(s) = std::str::from_utf8(&data) {
-                            if let Ok(micros) = s.parse::<i64>() {
+                            if format == 1 {
+                                crate::protocol::binary_encoding::BinaryResultEncoder::encode_scalar(s, type_oid)
+                                    .or(Some(data))
+                            } else if let Ok(micros) = s.parse::<i64>() {
+                                // Convert INTEGER microseconds to YYYY-MM-DD HH:MM:SS.ffffff format
                                 use crate::types::datetime_utils::format_microseconds_to_timestamp_buf;
                                 let mut buf = vec![0u8; 32];
                                 let len = format_microseconds_to_timestamp_buf(micros, &mut buf);
@@ -1861,13 +2180,44 @@ impl QueryExecutor {
                         } else {
                             Some(data) // Keep original if not valid UTF-8
                         }
+                    } else if type_oid == int4range_oid || type_oid == int8range_oid
+                        || type_oid == numrange_oid || type_oid == Self::TSRANGE_OID || type_oid == Self::DATERANGE_OID {
+                        // Convert the stored {lower, upper, lower_inc, upper_inc} JSON
+                        // into the canonical Postgres range literal, e.g. "[1,10)"
+                        let subtype = if type_oid == int4range_oid {
+                            crate::types::type_mapper::PgType::Int4
+                        } else if type_oid == int8range_oid {
+                            crate::types::type_mapper::PgType::Int8
+                        } else if type_oid == numrange_oid {
+                            crate::types::type_mapper::PgType::Numeric
+                        } else if type_oid == Self::TSRANGE_OID {
+                            crate::types::type_mapper::PgType::Timestamp
+                        } else {
+                            crate::types::type_mapper::PgType::Date
+                        };
+
+                        if let Ok(s) = std::str::from_utf8(&data) {
+                            match crate::types::ValueConverter::range_to_pg_text(s, subtype) {
+                                Ok(literal) => {
+                                    if format == 1 {
+                                        crate::protocol::binary_encoding::BinaryResultEncoder::encode_scalar(&literal, type_oid)
+                                            .or(Some(data))
+                                    } else {
+                                        Some(literal.into_bytes())
+                                    }
+                                }
+                                Err(_) => Some(data), // Keep original if not valid stored range JSON
+                            }
+                        } else {
+                            Some(data)
+                        }
                     } else {
                         Some(data)
                     }
                 } else {
                     None
                 };
-                
+
                 converted_row.push(converted_cell);
             }
             
@@ -1929,174 +2279,69 @@ impl QueryExecutor {
     }
 }
 
+/// Extract table name from SELECT query, parser-backed (see `extended_helpers`)
 fn extract_table_name_from_select(query: &str) -> Option<String> {
-    // Look for FROM keyword using regex to handle various whitespace patterns
-    use once_cell::sync::Lazy;
-    use regex::Regex;
-    
-    static FROM_TABLE_REGEX: Lazy<Regex> = Lazy::new(|| {
-        Regex::new(r"(?i)\bFROM\s+([^\s,;()]+)").unwrap()
-    });
-    
-    if let Some(captures) = FROM_TABLE_REGEX.captures(query) {
-        if let Some(table_match) = captures.get(1) {
-            let table_name = table_match.as_str().trim();
-            
-            // Remove quotes if present
-            let table_name = table_name.trim_matches('"').trim_matches('\'');
-            
-            if !table_name.is_empty() {
-                debug!("extract_table_name_from_select: query='{}' -> table='{}'", query, table_name);
-                return Some(table_name.to_string());
-            }
-        }
-    }
-    
-    debug!("extract_table_name_from_select: query='{}' -> None", query);
-    None
+    let table = crate::query::extended_helpers::extract_primary_table(query);
+    debug!("extract_table_name_from_select: query='{}' -> {:?}", query, table);
+    table
 }
 
-/// Extract column mappings from SELECT query with AS aliases
+/// Extract column mappings (output alias -> source column) from a SELECT
+/// query, following joins and schema-qualified names (see `extended_helpers`)
 fn extract_column_mappings_from_query(query: &str, table: &str) -> std::collections::HashMap<String, String> {
-    use regex::Regex;
-    use std::collections::HashMap;
-    
-    let mut mappings = HashMap::new();
-    
-    // Match patterns like "table.column_name AS alias"
-    let re = Regex::new(&format!(
-        r"(?i)\b{}\.(\w+)\s+AS\s+(\w+)",
-        regex::escape(table)
-    )).unwrap_or_else(|_| {
-        // Fallback pattern - just match any alias pattern
-        Regex::new(r"(?i)\b(\w+)\s+AS\s+(\w+)").unwrap()
-    });
-    
-    for captures in re.captures_iter(query) {
-        if let (Some(source_col), Some(alias)) = (captures.get(1), captures.get(2)) {
-            let source_column = source_col.as_str().to_string();
-            let alias_name = alias.as_str().to_string();
-            
-            debug!("Column mapping: {} -> {}", alias_name, source_column);
-            mappings.insert(alias_name, source_column);
-        }
-    }
-    
+    let mappings = crate::query::extended_helpers::extract_column_mappings(query, table);
+    debug!("Column mappings for table '{}': {:?}", table, mappings);
     mappings
 }
 
 /// Extract table name from CREATE TABLE statement
 fn extract_table_name_from_create(query: &str) -> Option<String> {
-    // Look for CREATE TABLE pattern with case-insensitive search
-    let create_table_pos = query.as_bytes().windows(12)
-        .position(|window| window.eq_ignore_ascii_case(b"CREATE TABLE"))?;
-    
-    let after_create = &query[create_table_pos + 12..].trim();
-    
-    // Skip IF NOT EXISTS if present
-    let after_create = if after_create.len() >= 13 && after_create[..13].eq_ignore_ascii_case("IF NOT EXISTS") {
-        &after_create[13..].trim()
-    } else {
-        after_create
-    };
-    
-    // Find the end of table name
-    let table_end = after_create.find(|c: char| {
-        c.is_whitespace() || c == '('
-    }).unwrap_or(after_create.len());
-    
-    let table_name = after_create[..table_end].trim();
-    
-    // Remove quotes if present
-    let table_name = table_name.trim_matches('"').trim_matches('\'');
-    
-    if !table_name.is_empty() {
-        Some(table_name.to_string())
-    } else {
-        None
+    crate::query::extended_helpers::extract_primary_table(query)
+}
+
+/// Extract table name from an ALTER TABLE statement
+fn extract_table_name_from_alter(query: &str) -> Option<String> {
+    crate::query::extended_helpers::extract_primary_table(query)
+}
+
+/// Extract table name from a DROP TABLE statement
+fn extract_table_name_from_drop(query: &str) -> Option<String> {
+    crate::query::extended_helpers::extract_primary_table(query)
+}
+
+/// Strip a trailing `CASCADE`/`RESTRICT` keyword from a `DROP TABLE`
+/// statement. SQLite's own `DROP TABLE` grammar has no such clause, so
+/// forwarding either keyword verbatim fails with a syntax error regardless
+/// of whether the CASCADE dependency check upstream already ran.
+fn strip_drop_table_cascade_restrict(query: &str) -> String {
+    let trimmed = query.trim_end();
+    let trimmed = trimmed.strip_suffix(';').unwrap_or(trimmed).trim_end();
+
+    for keyword in ["CASCADE", "RESTRICT"] {
+        if trimmed.len() > keyword.len() {
+            let (rest, tail) = trimmed.split_at(trimmed.len() - keyword.len());
+            if tail.eq_ignore_ascii_case(keyword) && rest.ends_with(char::is_whitespace) {
+                return rest.trim_end().to_string();
+            }
+        }
     }
+
+    query.to_string()
 }
 
 /// Extract table name from INSERT statement
 fn extract_table_name_from_insert(query: &str) -> Option<String> {
-    // Look for INSERT INTO pattern with case-insensitive search
-    let insert_pos = query.as_bytes().windows(11)
-        .position(|window| window.eq_ignore_ascii_case(b"INSERT INTO"))?;
-    
-    let after_insert = &query[insert_pos + 11..].trim();
-    
-    // Find the end of table name
-    let table_end = after_insert.find(|c: char| {
-        c.is_whitespace() || c == '(' || c == ';'
-    }).unwrap_or(after_insert.len());
-    
-    let table_name = after_insert[..table_end].trim();
-    
-    // Remove quotes if present
-    let table_name = table_name.trim_matches('"').trim_matches('\'');
-    
-    if !table_name.is_empty() {
-        Some(table_name.to_string())
-    } else {
-        None
-    }
+    crate::query::extended_helpers::extract_primary_table(query)
 }
 
 /// Extract table name from UPDATE statement
 fn extract_table_name_from_update(query: &str) -> Option<String> {
-    // Look for UPDATE pattern with case-insensitive search
-    let update_pos = query.as_bytes().windows(6)
-        .position(|window| window.eq_ignore_ascii_case(b"UPDATE"))?;
-    
-    let after_update = &query[update_pos + 6..].trim();
-    
-    // Find the end of table name (SET keyword)
-    let table_end = after_update.find(|c: char| {
-        c.is_whitespace() || c == ';'
-    }).unwrap_or(after_update.len());
-    
-    let table_name = after_update[..table_end].trim();
-    
-    // Remove quotes if present
-    let table_name = table_name.trim_matches('"').trim_matches('\'');
-    
-    if !table_name.is_empty() {
-        Some(table_name.to_string())
-    } else {
-        None
-    }
+    crate::query::extended_helpers::extract_primary_table(query)
 }
 
 /// Extract table name from DELETE statement
 fn extract_table_name_from_delete(query: &str) -> Option<String> {
-    // Look for DELETE FROM pattern with case-insensitive search
-    let delete_pos = query.as_bytes().windows(6)
-        .position(|window| window.eq_ignore_ascii_case(b"DELETE"))?;
-    
-    let after_delete = &query[delete_pos + 6..].trim();
-    
-    // Skip optional FROM keyword
-    let after_from = if after_delete.to_uppercase().starts_with("FROM") {
-        &after_delete[4..].trim()
-    } else {
-        after_delete
-    };
-    
-    // Find the end of table name (WHERE or end of query)
-    let table_end = after_from.find(|c: char| {
-        c.is_whitespace() || c == ';'
-    }).unwrap_or(after_from.len());
-    
-    let table_name = after_from[..table_end].trim();
-    
-    // Remove quotes if present
-    let table_name = table_name.trim_matches('"').trim_matches('\'');
-    
-    if !table_name.is_empty() {
-        Some(table_name.to_string())
-    } else {
-        None
-    }
+    crate::query::extended_helpers::extract_primary_table(query)
 }
 
 #[cfg(test)]
@@ -2154,9 +2399,38 @@ mod tests {
         ];
         
         let rows = vec![vec![Some(b"[\"a\", \"b\", \"c\"]".to_vec())]];
-        let converted = QueryExecutor::convert_array_data_in_rows(rows, &fields).unwrap();
+        let converted = QueryExecutor::convert_array_data_in_rows(rows, &fields, &[]).unwrap();
         let result_data = &converted[0][0].as_ref().unwrap();
         let result_str = String::from_utf8_lossy(result_data);
         assert_eq!(result_str, r#"{"a","b","c"}"#);
     }
+
+    #[test]
+    fn test_convert_array_data_honors_per_column_result_format() {
+        use crate::protocol::FieldDescription;
+
+        let field = |type_oid| FieldDescription {
+            name: "col".to_string(),
+            table_oid: 0,
+            column_id: 1,
+            type_oid,
+            type_size: -1,
+            type_modifier: -1,
+            format: 0,
+        };
+
+        // A single format code applies to every column, here requesting
+        // binary for a bool and a date in the same result set.
+        let fields = vec![field(PgType::Bool.to_oid()), field(PgType::Date.to_oid())];
+        let rows = vec![vec![Some(b"1".to_vec()), Some(b"10957".to_vec())]]; // 10957 = 2000-01-01
+        let converted = QueryExecutor::convert_array_data_in_rows(rows, &fields, &[1]).unwrap();
+        assert_eq!(converted[0][0].as_ref().unwrap(), &vec![1u8]);
+        assert_eq!(converted[0][1].as_ref().unwrap(), &0i32.to_be_bytes().to_vec());
+
+        // A per-column vector lets one column stay text while another goes binary.
+        let rows = vec![vec![Some(b"1".to_vec()), Some(b"10957".to_vec())]];
+        let converted = QueryExecutor::convert_array_data_in_rows(rows, &fields, &[0, 1]).unwrap();
+        assert_eq!(converted[0][0].as_ref().unwrap(), b"t");
+        assert_eq!(converted[0][1].as_ref().unwrap(), &0i32.to_be_bytes().to_vec());
+    }
 }
\ No newline at end of file
@@ -4878,95 +4878,19 @@ impl ExtendedQueryHandler {
     
     /// Extract table names from a parsed SQL statement
     fn extract_table_names_from_statement(statement: &sqlparser::ast::Statement) -> Vec<String> {
-        use sqlparser::ast::TableFactor;
-        
-        let mut tables = Vec::new();
-        
-        match statement {
-            sqlparser::ast::Statement::Insert(insert) => {
-                tables.push(insert.table.to_string());
-            }
-            sqlparser::ast::Statement::Query(query) => {
-                super::extended_helpers::extract_tables_from_query(query, &mut tables);
-            }
-            sqlparser::ast::Statement::Update { table, .. } => {
-                if let TableFactor::Table { name, .. } = &table.relation {
-                    tables.push(name.to_string());
-                }
-            }
-            sqlparser::ast::Statement::Delete(delete) => {
-                // For DELETE, just get the main table from the FROM clause
-                match &delete.from {
-                    sqlparser::ast::FromTable::WithFromKeyword(table_list) => {
-                        for table in table_list {
-                            if let TableFactor::Table { name, .. } = &table.relation {
-                                tables.push(name.to_string());
-                            }
-                        }
-                    }
-                    sqlparser::ast::FromTable::WithoutKeyword(names) => {
-                        for name in names {
-                            tables.push(name.to_string());
-                        }
-                    }
-                }
-            }
-            _ => {}
-        }
-        
-        tables
+        super::extended_helpers::extract_table_names_from_statement(statement)
     }
     
     /// Extract table name from INSERT statement
     fn extract_table_name_from_insert(query: &str) -> Option<String> {
-        // Look for INSERT INTO pattern with case-insensitive search
-        let insert_pos = query.as_bytes().windows(11)
-            .position(|window| window.eq_ignore_ascii_case(b"INSERT INTO"))?;
-        
-        let after_insert = &query[insert_pos + 11..].trim();
-        
-        // Find the end of table name
-        let table_end = after_insert.find(|c: char| {
-            c.is_whitespace() || c == '(' || c == ';'
-        }).unwrap_or(after_insert.len());
-        
-        let table_name = after_insert[..table_end].trim();
-        
-        // Remove quotes if present
-        let table_name = table_name.trim_matches('"').trim_matches('\'');
-        
-        if !table_name.is_empty() {
-            Some(table_name.to_string())
-        } else {
-            None
-        }
+        super::extended_helpers::extract_primary_table(query)
     }
-    
+
     /// Extract table name from UPDATE statement
     fn extract_table_name_from_update(query: &str) -> Option<String> {
-        // Look for UPDATE pattern with case-insensitive search
-        let update_pos = query.as_bytes().windows(6)
-            .position(|window| window.eq_ignore_ascii_case(b"UPDATE"))?;
-        
-        let after_update = &query[update_pos + 6..].trim();
-        
-        // Find the end of table name (SET keyword)
-        let table_end = after_update.find(|c: char| {
-            c.is_whitespace() || c == ';'
-        }).unwrap_or(after_update.len());
-        
-        let table_name = after_update[..table_end].trim();
-        
-        // Remove quotes if present
-        let table_name = table_name.trim_matches('"').trim_matches('\'');
-        
-        if !table_name.is_empty() {
-            Some(table_name.to_string())
-        } else {
-            None
-        }
+        super::extended_helpers::extract_primary_table(query)
     }
-    
+
     async fn send_data_rows_only<T>(
         framed: &mut Framed<T, crate::protocol::PostgresCodec>,
         response: crate::session::db_handler::DbResponse,
@@ -5009,69 +4933,14 @@ impl ExtendedQueryHandler {
 
 /// Extract table name from SELECT query
 fn extract_table_name_from_select(query: &str) -> Option<String> {
-    info!("extract_table_name_from_select: Analyzing query: '{}'", query);
-    // Look for FROM clause using case-insensitive search
-    if let Some(from_pos) = find_keyword_position(query, " from ") {
-        info!("extract_table_name_from_select: Found FROM at position {}", from_pos);
-        let after_from = &query[from_pos + 6..].trim();
-        
-        // Find the end of table name (space, where, order by, etc.)
-        let table_end = after_from.find(|c: char| {
-            c.is_whitespace() || c == ',' || c == ';' || c == '('
-        }).unwrap_or(after_from.len());
-        
-        let table_name = after_from[..table_end].trim();
-        
-        // Remove quotes if present
-        let table_name = table_name.trim_matches('"').trim_matches('\'');
-        
-        if !table_name.is_empty() {
-            info!("extract_table_name_from_select: Extracted table name: '{}'", table_name);
-            Some(table_name.to_string())
-        } else {
-            info!("extract_table_name_from_select: Empty table name");
-            None
-        }
-    } else {
-        info!("extract_table_name_from_select: No FROM clause found");
-        None
-    }
+    let table = super::extended_helpers::extract_primary_table(query);
+    info!("extract_table_name_from_select: query='{}' -> {:?}", query, table);
+    table
 }
 
 /// Extract table name from CREATE TABLE statement
 fn extract_table_name_from_create(query: &str) -> Option<String> {
-    info!("extract_table_name_from_create: Analyzing CREATE query: '{}'", query);
-    // Look for CREATE TABLE pattern
-    if let Some(table_pos) = find_keyword_position(query, "CREATE TABLE") {
-        info!("extract_table_name_from_create: Found CREATE TABLE at position {}", table_pos);
-        let after_create = &query[table_pos + 12..].trim();
-        
-        // Skip IF NOT EXISTS if present
-        let after_create = if query_starts_with_ignore_case(after_create, "IF NOT EXISTS") {
-            &after_create[13..].trim()
-        } else {
-            after_create
-        };
-        
-        // Find the end of table name
-        let table_end = after_create.find(|c: char| {
-            c.is_whitespace() || c == '('
-        }).unwrap_or(after_create.len());
-        
-        let table_name = after_create[..table_end].trim();
-        
-        // Remove quotes if present
-        let table_name = table_name.trim_matches('"').trim_matches('\'');
-        
-        if !table_name.is_empty() {
-            info!("extract_table_name_from_create: Extracted table name: '{}'", table_name);
-            Some(table_name.to_string())
-        } else {
-            info!("extract_table_name_from_create: Empty table name");
-            None
-        }
-    } else {
-        info!("extract_table_name_from_create: No CREATE TABLE found");
-        None
-    }
+    let table = super::extended_helpers::extract_primary_table(query);
+    info!("extract_table_name_from_create: query='{}' -> {:?}", query, table);
+    table
 }
\ No newline at end of file
@@ -0,0 +1,213 @@
+//! Parser-backed table/column reference analysis, shared by `executor` and
+//! `extended`.
+//!
+//! Replaces the old regex/byte-window scans that only ever found the first
+//! `FROM` table: a real AST walk correctly handles schema-qualified names
+//! (`public.users`), JOINs, CTEs, derived subqueries, and `table.col AS x`
+//! projections spanning more than one table.
+
+use sqlparser::ast::{
+    Cte, Expr, Query, Select, SelectItem, SetExpr, Statement, TableFactor,
+};
+use sqlparser::dialect::PostgreSqlDialect;
+use sqlparser::parser::Parser;
+use std::collections::HashMap;
+
+/// Where a projected output column's value actually comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnSource {
+    pub table: String,
+    pub column: String,
+}
+
+fn parse_statement(query: &str) -> Option<Statement> {
+    Parser::parse_sql(&PostgreSqlDialect {}, query).ok()?.into_iter().next()
+}
+
+/// Drop any schema qualifier (`public.users` -> `users`); callers key their
+/// schema caches by the bare table name.
+fn bare_table_name(name: &sqlparser::ast::ObjectName) -> String {
+    name.0.last().map(|ident| ident.value.clone()).unwrap_or_else(|| name.to_string())
+}
+
+fn collect_tables_from_factor(factor: &TableFactor, tables: &mut Vec<String>) {
+    match factor {
+        TableFactor::Table { name, .. } => tables.push(bare_table_name(name)),
+        TableFactor::Derived { subquery, .. } => extract_tables_from_query(subquery, tables),
+        TableFactor::NestedJoin { table_with_joins, .. } => {
+            collect_tables_from_factor(&table_with_joins.relation, tables);
+            for join in &table_with_joins.joins {
+                collect_tables_from_factor(&join.relation, tables);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_tables_from_select(select: &Select, tables: &mut Vec<String>) {
+    for twj in &select.from {
+        collect_tables_from_factor(&twj.relation, tables);
+        for join in &twj.joins {
+            collect_tables_from_factor(&join.relation, tables);
+        }
+    }
+}
+
+fn collect_tables_from_set_expr(expr: &SetExpr, tables: &mut Vec<String>) {
+    match expr {
+        SetExpr::Select(select) => collect_tables_from_select(select, tables),
+        SetExpr::Query(query) => extract_tables_from_query(query, tables),
+        SetExpr::SetOperation { left, right, .. } => {
+            collect_tables_from_set_expr(left, tables);
+            collect_tables_from_set_expr(right, tables);
+        }
+        _ => {}
+    }
+}
+
+fn collect_tables_from_cte(cte: &Cte, tables: &mut Vec<String>) {
+    extract_tables_from_query(&cte.query, tables);
+}
+
+/// All tables referenced anywhere in `query` (FROM, JOINs, CTEs, derived
+/// subqueries, set operations), appended in first-seen order.
+pub fn extract_tables_from_query(query: &Query, tables: &mut Vec<String>) {
+    if let Some(with) = &query.with {
+        for cte in &with.cte_tables {
+            collect_tables_from_cte(cte, tables);
+        }
+    }
+    collect_tables_from_set_expr(&query.body, tables);
+}
+
+/// All distinct tables referenced by any statement kind, deduped but
+/// otherwise in first-seen order.
+pub fn extract_table_names_from_statement(statement: &Statement) -> Vec<String> {
+    let mut tables = Vec::new();
+
+    match statement {
+        Statement::Insert(insert) => tables.push(bare_table_name(&insert.table)),
+        Statement::Query(query) => extract_tables_from_query(query, &mut tables),
+        Statement::Update { table, .. } => {
+            if let TableFactor::Table { name, .. } = &table.relation {
+                tables.push(bare_table_name(name));
+            }
+        }
+        Statement::Delete(delete) => match &delete.from {
+            sqlparser::ast::FromTable::WithFromKeyword(table_list)
+            | sqlparser::ast::FromTable::WithoutKeyword(table_list) => {
+                for table in table_list {
+                    if let TableFactor::Table { name, .. } = &table.relation {
+                        tables.push(bare_table_name(name));
+                    }
+                }
+            }
+        },
+        Statement::CreateTable { name, .. } => tables.push(bare_table_name(name)),
+        Statement::AlterTable { name, .. } => tables.push(bare_table_name(name)),
+        Statement::Drop { names, .. } => {
+            for name in names {
+                tables.push(bare_table_name(name));
+            }
+        }
+        _ => {}
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    tables.retain(|t| seen.insert(t.clone()));
+    tables
+}
+
+/// Best-effort single table for statements that only ever touch one (used
+/// wherever a caller used to guess from the first `FROM`/`INSERT INTO`/etc.).
+pub fn extract_primary_table(query: &str) -> Option<String> {
+    let statement = parse_statement(query)?;
+    extract_table_names_from_statement(&statement).into_iter().next()
+}
+
+/// Map `alias -> real table name` for every table/join entry in a `SELECT`'s
+/// `FROM` clause (an unaliased table maps to itself).
+fn from_aliases(select: &Select) -> HashMap<String, String> {
+    fn record(factor: &TableFactor, map: &mut HashMap<String, String>) {
+        if let TableFactor::Table { name, alias, .. } = factor {
+            let table = bare_table_name(name);
+            let key = alias.as_ref().map(|a| a.name.value.clone()).unwrap_or_else(|| table.clone());
+            map.insert(key, table);
+        }
+    }
+
+    let mut map = HashMap::new();
+    for twj in &select.from {
+        record(&twj.relation, &mut map);
+        for join in &twj.joins {
+            record(&join.relation, &mut map);
+        }
+    }
+    map
+}
+
+/// Resolve a projected output column's real `(table, column)` source,
+/// following `table.col` / `alias.col` through the FROM-clause alias map,
+/// or falling back to the lone FROM table for a bare identifier.
+fn resolve_column_source(expr: &Expr, aliases: &HashMap<String, String>) -> Option<ColumnSource> {
+    match expr {
+        Expr::CompoundIdentifier(parts) => {
+            let column = parts.last()?.value.clone();
+            let qualifier = parts.first()?.value.clone();
+            let table = aliases.get(&qualifier).cloned().unwrap_or(qualifier);
+            Some(ColumnSource { table, column })
+        }
+        Expr::Identifier(ident) => {
+            if aliases.len() == 1 {
+                let table = aliases.values().next().cloned()?;
+                Some(ColumnSource { table, column: ident.value.clone() })
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Map every projected output column/alias to its source `(table, column)`,
+/// across joins, for a single `SELECT`.
+pub fn analyze_select_columns(select: &Select) -> HashMap<String, ColumnSource> {
+    let aliases = from_aliases(select);
+    let mut mapping = HashMap::new();
+
+    for item in &select.projection {
+        match item {
+            SelectItem::UnnamedExpr(expr) => {
+                if let Some(source) = resolve_column_source(expr, &aliases) {
+                    mapping.insert(source.column.clone(), source);
+                }
+            }
+            SelectItem::ExprWithAlias { expr, alias } => {
+                if let Some(source) = resolve_column_source(expr, &aliases) {
+                    mapping.insert(alias.value.clone(), source);
+                }
+            }
+            SelectItem::Wildcard(..) | SelectItem::QualifiedWildcard(..) => {}
+        }
+    }
+
+    mapping
+}
+
+/// Output alias -> source column name for columns sourced from `table`,
+/// across joins. Drop-in replacement for the old single-table regex scan.
+pub fn extract_column_mappings(query: &str, table: &str) -> HashMap<String, String> {
+    let mut mappings = HashMap::new();
+
+    let Some(statement) = parse_statement(query) else { return mappings };
+    let Statement::Query(query) = &statement else { return mappings };
+    let SetExpr::Select(select) = query.body.as_ref() else { return mappings };
+
+    for (alias, source) in analyze_select_columns(select) {
+        if source.table == table {
+            mappings.insert(alias, source.column);
+        }
+    }
+
+    mappings
+}
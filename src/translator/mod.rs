@@ -5,9 +5,19 @@ mod returning_translator;
 mod create_table_translator;
 mod enum_validator;
 mod cast_translator;
+mod unnest_translator;
+mod array_translator;
+mod json_each_translator;
+mod create_index_translator;
+mod session_identifier_translator;
 
 pub use json_translator::JsonTranslator;
 pub use returning_translator::ReturningTranslator;
 pub use create_table_translator::CreateTableTranslator;
 pub use enum_validator::EnumValidator;
-pub use cast_translator::CastTranslator;
\ No newline at end of file
+pub use cast_translator::CastTranslator;
+pub use unnest_translator::UnnestTranslator;
+pub use array_translator::ArrayTranslator;
+pub use json_each_translator::JsonEachTranslator;
+pub use create_index_translator::CreateIndexTranslator;
+pub use session_identifier_translator::SessionIdentifierTranslator;
\ No newline at end of file
@@ -0,0 +1,216 @@
+use crate::PgSqliteError;
+use crate::translator::{TranslationMetadata, ColumnTypeHint, ExpressionType};
+use crate::types::PgType;
+use regex::Regex;
+use once_cell::sync::Lazy;
+use tracing::debug;
+
+/// Regex for `unnest()`/`array_to_rows()` (a bare alias for the same
+/// operation) used as a FROM-clause item, with optional `WITH ORDINALITY`
+/// and an optional alias/column-list: `FROM unnest(tags) WITH ORDINALITY AS
+/// t(tag, n)`.
+static UNNEST_FROM_CLAUSE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\bFROM\s+(unnest|array_to_rows)\s*\(\s*([^)]+)\s*\)(\s+WITH\s+ORDINALITY)?(?:\s+(?:AS\s+)?(\w+)(?:\s*\(([^)]*)\))?)?").unwrap()
+});
+
+/// Same function names, used bare (e.g. in a SELECT list) rather than as a
+/// FROM-clause item.
+static UNNEST_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(unnest|array_to_rows)\s*\(\s*([^)]+)\s*\)").unwrap()
+});
+
+/// Translates PostgreSQL's `unnest()` set-returning function (and its
+/// `array_to_rows()` alias) to SQLite's `json_each()`, the same bridge
+/// `ArrayTranslator`'s `ANY`/`ALL` rewriting and `JsonEachTranslator` already
+/// use for array/JSON expansion. `WITH ORDINALITY` maps onto `json_each`'s
+/// 0-based `key` column, shifted by one to match PostgreSQL's 1-based
+/// ordinality.
+pub struct UnnestTranslator;
+
+impl UnnestTranslator {
+    /// Check if SQL contains an `unnest()`/`array_to_rows()` call, to skip
+    /// translation work entirely on the common case where it doesn't.
+    pub fn contains_unnest(sql: &str) -> bool {
+        if !sql.to_lowercase().contains("unnest(") && !sql.to_lowercase().contains("array_to_rows(") {
+            return false;
+        }
+        UNNEST_REGEX.is_match(sql)
+    }
+
+    /// Translate `unnest()`/`array_to_rows()` calls to SQLite equivalents.
+    pub fn translate_unnest(sql: &str) -> Result<String, PgSqliteError> {
+        if !Self::contains_unnest(sql) {
+            return Ok(sql.to_string());
+        }
+
+        let mut result = sql.to_string();
+        result = Self::translate_from_clause(&result)?;
+        result = Self::translate_select_clause(&result)?;
+
+        Ok(result)
+    }
+
+    /// Translate unnest with metadata, the same shape as
+    /// `JsonEachTranslator::translate_with_metadata`.
+    pub fn translate_with_metadata(sql: &str) -> Result<(String, TranslationMetadata), PgSqliteError> {
+        if !Self::contains_unnest(sql) {
+            return Ok((sql.to_string(), TranslationMetadata::new()));
+        }
+
+        let mut result = sql.to_string();
+        let mut metadata = TranslationMetadata::new();
+
+        result = Self::translate_from_clause(&result)?;
+        result = Self::translate_select_clause(&result)?;
+
+        Self::extract_unnest_metadata(sql, &mut metadata);
+
+        Ok((result, metadata))
+    }
+
+    /// Translate `FROM unnest(array_expr) [WITH ORDINALITY] [AS alias[(cols)]]`
+    /// into a `json_each`-backed subquery, yielding one row per element (plus
+    /// a 1-based ordinal column when `WITH ORDINALITY` was given).
+    fn translate_from_clause(sql: &str) -> Result<String, PgSqliteError> {
+        let mut result = sql.to_string();
+        let mut replacements = Vec::new();
+
+        for captures in UNNEST_FROM_CLAUSE_REGEX.captures_iter(&result) {
+            let function_name = &captures[1];
+            let array_expr = captures[2].trim();
+            let with_ordinality = captures.get(3).is_some();
+            let alias = captures.get(4).map(|m| m.as_str()).unwrap_or("unnest_table");
+            let columns: Vec<&str> = captures.get(5)
+                .map(|m| m.as_str().split(',').map(|c| c.trim()).collect())
+                .unwrap_or_default();
+
+            let value_col = columns.first().copied().unwrap_or("value");
+
+            let replacement = if with_ordinality {
+                let ordinal_col = columns.get(1).copied().unwrap_or("ordinal");
+                format!(
+                    "(SELECT value AS {value_col}, key + 1 AS {ordinal_col} FROM json_each({array_expr})) AS {alias}"
+                )
+            } else {
+                format!("(SELECT value AS {value_col} FROM json_each({array_expr})) AS {alias}")
+            };
+
+            debug!("Planning to translate FROM {}: {} -> {}", function_name, &captures[0], &replacement);
+            replacements.push((captures[0].to_string(), replacement));
+        }
+
+        for (original, replacement) in replacements {
+            result = result.replace(&original, &replacement);
+            debug!("Translated FROM unnest: {} -> {}", original, replacement);
+        }
+
+        Ok(result)
+    }
+
+    /// Translate a bare `unnest(array_expr)` (not already handled by
+    /// `translate_from_clause`) into a subquery collecting the expanded
+    /// elements back into a JSON array, the same approximation
+    /// `JsonEachTranslator` uses for `json_each()` in a SELECT list.
+    fn translate_select_clause(sql: &str) -> Result<String, PgSqliteError> {
+        let mut result = sql.to_string();
+        let mut replacements = Vec::new();
+
+        for captures in UNNEST_REGEX.captures_iter(&result) {
+            let function_name = &captures[1];
+            let array_expr = captures[2].trim();
+            let full_match = &captures[0];
+
+            if result.contains(&format!("FROM {}", full_match)) {
+                continue; // Already handled by translate_from_clause
+            }
+
+            let replacement = format!(
+                "(SELECT json_group_array(value) FROM json_each({}))",
+                array_expr
+            );
+
+            debug!("Planning to translate {}: {} -> {}", function_name, full_match, &replacement);
+            replacements.push((full_match.to_string(), replacement));
+        }
+
+        for (original, replacement) in replacements {
+            result = result.replace(&original, &replacement);
+            debug!("Translated unnest: {} -> {}", original, replacement);
+        }
+
+        Ok(result)
+    }
+
+    /// Extract type hints for aliased `unnest()`/`array_to_rows()` calls.
+    fn extract_unnest_metadata(sql: &str, metadata: &mut TranslationMetadata) {
+        let alias_regex = Regex::new(r"(?i)(?:unnest|array_to_rows)\s*\([^)]+\)\s+(?:AS\s+)?(\w+)").unwrap();
+
+        for captures in alias_regex.captures_iter(sql) {
+            let alias = captures[1].to_string();
+            debug!("Found unnest alias: {}", alias);
+
+            metadata.add_hint(format!("{}.value", alias), ColumnTypeHint {
+                source_column: None,
+                suggested_type: Some(PgType::Json),
+                datetime_subtype: None,
+                is_expression: true,
+                expression_type: Some(ExpressionType::Other),
+            });
+
+            metadata.add_hint(format!("{}.ordinal", alias), ColumnTypeHint {
+                source_column: None,
+                suggested_type: Some(PgType::Int8),
+                datetime_subtype: None,
+                is_expression: true,
+                expression_type: Some(ExpressionType::Other),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unnest_from_clause() {
+        let sql = "SELECT t.value FROM unnest(tags) AS t";
+        let result = UnnestTranslator::translate_unnest(sql).unwrap();
+        assert!(result.contains("(SELECT value AS value FROM json_each(tags)) AS t"));
+    }
+
+    #[test]
+    fn test_array_to_rows_alias() {
+        let sql = "SELECT t.value FROM array_to_rows(tags) AS t";
+        let result = UnnestTranslator::translate_unnest(sql).unwrap();
+        assert!(result.contains("(SELECT value AS value FROM json_each(tags)) AS t"));
+    }
+
+    #[test]
+    fn test_unnest_with_ordinality() {
+        let sql = "SELECT t.value, t.ordinal FROM unnest(tags) WITH ORDINALITY AS t(value, ordinal)";
+        let result = UnnestTranslator::translate_unnest(sql).unwrap();
+        assert!(result.contains("SELECT value AS value, key + 1 AS ordinal FROM json_each(tags)"));
+    }
+
+    #[test]
+    fn test_unnest_select_clause() {
+        let sql = "SELECT unnest(tags) FROM products";
+        let result = UnnestTranslator::translate_unnest(sql).unwrap();
+        assert!(result.contains("(SELECT json_group_array(value) FROM json_each(tags))"));
+    }
+
+    #[test]
+    fn test_no_unnest() {
+        let sql = "SELECT name FROM users";
+        let result = UnnestTranslator::translate_unnest(sql).unwrap();
+        assert_eq!(result, "SELECT name FROM users");
+    }
+
+    #[test]
+    fn test_contains_unnest() {
+        assert!(UnnestTranslator::contains_unnest("SELECT * FROM unnest(tags) AS t"));
+        assert!(UnnestTranslator::contains_unnest("SELECT * FROM array_to_rows(tags) AS t"));
+        assert!(!UnnestTranslator::contains_unnest("SELECT name FROM users"));
+    }
+}
@@ -2,6 +2,7 @@ use crate::types::type_mapper::PgType;
 use std::net::{Ipv4Addr, Ipv6Addr};
 use regex::Regex;
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime, DateTime, Timelike};
+use serde_json::json;
 
 pub struct ValueConverter;
 
@@ -10,7 +11,9 @@ impl ValueConverter {
     pub fn pg_to_sqlite(value: &str, pg_type: PgType) -> Result<String, String> {
         match pg_type {
             PgType::Money => Self::convert_money(value),
-            PgType::Int4range | PgType::Int8range | PgType::Numrange => Self::convert_range(value),
+            PgType::Int4range => Self::convert_range(value, PgType::Int4),
+            PgType::Int8range => Self::convert_range(value, PgType::Int8),
+            PgType::Numrange => Self::convert_range(value, PgType::Numeric),
             PgType::Cidr => Self::convert_cidr(value),
             PgType::Inet => Self::convert_inet(value),
             PgType::Macaddr => Self::convert_macaddr(value),
@@ -30,7 +33,9 @@ impl ValueConverter {
     pub fn sqlite_to_pg(value: &str, pg_type: PgType) -> Result<String, String> {
         match pg_type {
             PgType::Money => Ok(value.to_string()), // Money is stored as-is
-            PgType::Int4range | PgType::Int8range | PgType::Numrange => Ok(value.to_string()), // Ranges stored as-is
+            PgType::Int4range => Self::range_to_pg_text(value, PgType::Int4),
+            PgType::Int8range => Self::range_to_pg_text(value, PgType::Int8),
+            PgType::Numrange => Self::range_to_pg_text(value, PgType::Numeric),
             PgType::Cidr => Ok(value.to_string()), // CIDR stored as-is
             PgType::Inet => Ok(value.to_string()), // INET stored as-is
             PgType::Macaddr => Ok(value.to_string()), // MAC addresses stored as-is
@@ -60,15 +65,80 @@ impl ValueConverter {
         }
     }
     
-    /// Validate and convert range values
-    fn convert_range(value: &str) -> Result<String, String> {
-        // Range format: [lower,upper) or (lower,upper] or [lower,upper] or (lower,upper)
-        let range_regex = Regex::new(r"^[\[\(]-?\d+,-?\d+[\]\)]$").unwrap();
-        if range_regex.is_match(value.trim()) {
-            Ok(value.trim().to_string())
-        } else {
-            Err(format!("Invalid range format: {}", value))
+    /// Parse a PostgreSQL range literal (`[1,10)`, `(,5]`, `empty`, ...) into
+    /// its SQLite storage form: a compact JSON object
+    /// `{"lower":..,"upper":..,"lower_inc":bool,"upper_inc":bool}`, or
+    /// `{"empty":true}`. Each bound is run through `pg_to_sqlite` for
+    /// `subtype`, so e.g. a `numrange` bound is stored exactly like a bare
+    /// `numeric` column would be (only int4range, int8range and numrange
+    /// values reach this).
+    fn convert_range(value: &str, subtype: PgType) -> Result<String, String> {
+        let trimmed = value.trim();
+
+        if trimmed.eq_ignore_ascii_case("empty") {
+            return Ok(json!({"empty": true}).to_string());
+        }
+
+        let lower_inc = trimmed.starts_with('[');
+        let upper_inc = trimmed.ends_with(']');
+        if (!lower_inc && !trimmed.starts_with('(')) || (!upper_inc && !trimmed.ends_with(')')) || trimmed.len() < 2 {
+            return Err(format!("Invalid range format: {}", value));
+        }
+
+        let bounds = &trimmed[1..trimmed.len() - 1];
+        let parts: Vec<&str> = bounds.splitn(2, ',').collect();
+        if parts.len() != 2 {
+            return Err(format!("Invalid range format: {}", value));
         }
+
+        let lower = Self::convert_range_bound(parts[0].trim(), subtype)?;
+        let upper = Self::convert_range_bound(parts[1].trim(), subtype)?;
+
+        Ok(json!({
+            "lower": lower,
+            "upper": upper,
+            "lower_inc": lower_inc,
+            "upper_inc": upper_inc,
+        }).to_string())
+    }
+
+    /// Convert one range bound to its stored form, or `None` for an
+    /// unbounded (empty-string / `infinity` / `-infinity`) side.
+    fn convert_range_bound(bound: &str, subtype: PgType) -> Result<Option<String>, String> {
+        if bound.is_empty() || bound == "infinity" || bound == "-infinity" {
+            return Ok(None);
+        }
+        Self::pg_to_sqlite(bound, subtype).map(Some)
+    }
+
+    /// Format a stored range (see `convert_range`) back into the canonical
+    /// PostgreSQL range literal, running each bound through `sqlite_to_pg`
+    /// for `subtype`.
+    pub(crate) fn range_to_pg_text(value: &str, subtype: PgType) -> Result<String, String> {
+        let stored: serde_json::Value = serde_json::from_str(value.trim())
+            .map_err(|e| format!("Invalid stored range: {} ({})", value, e))?;
+
+        if stored.get("empty").and_then(|v| v.as_bool()).unwrap_or(false) {
+            return Ok("empty".to_string());
+        }
+
+        let lower_inc = stored.get("lower_inc").and_then(|v| v.as_bool()).unwrap_or(false);
+        let upper_inc = stored.get("upper_inc").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        let format_bound = |key: &str| -> Result<String, String> {
+            match stored.get(key) {
+                Some(serde_json::Value::String(s)) => Self::sqlite_to_pg(s, subtype),
+                _ => Ok(String::new()), // unbounded side
+            }
+        };
+
+        Ok(format!(
+            "{}{},{}{}",
+            if lower_inc { '[' } else { '(' },
+            format_bound("lower")?,
+            format_bound("upper")?,
+            if upper_inc { ']' } else { ')' },
+        ))
     }
     
     /// Validate and convert CIDR values
@@ -492,7 +562,32 @@ mod tests {
         assert!(ValueConverter::convert_bit("B'1010'").is_ok());
         assert!(ValueConverter::convert_bit("1012").is_err()); // Invalid character
     }
-    
+
+    #[test]
+    fn test_range_conversion_round_trip() {
+        // Bounded int4range
+        let stored = ValueConverter::convert_range("[1,10)", PgType::Int4).unwrap();
+        let literal = ValueConverter::range_to_pg_text(&stored, PgType::Int4).unwrap();
+        assert_eq!(literal, "[1,10)");
+
+        // Unbounded lower side
+        let stored = ValueConverter::convert_range("(,5]", PgType::Int4).unwrap();
+        let literal = ValueConverter::range_to_pg_text(&stored, PgType::Int4).unwrap();
+        assert_eq!(literal, "(,5]");
+
+        // Numeric bounds go through the numeric formatter untouched
+        let stored = ValueConverter::convert_range("[1.50,10.25)", PgType::Numeric).unwrap();
+        let literal = ValueConverter::range_to_pg_text(&stored, PgType::Numeric).unwrap();
+        assert_eq!(literal, "[1.50,10.25)");
+
+        // Empty range
+        let stored = ValueConverter::convert_range("empty", PgType::Int8).unwrap();
+        let literal = ValueConverter::range_to_pg_text(&stored, PgType::Int8).unwrap();
+        assert_eq!(literal, "empty");
+
+        assert!(ValueConverter::convert_range("not a range", PgType::Int4).is_err());
+    }
+
     #[test]
     fn test_date_conversion() {
         // Test DATE to Unix timestamp
@@ -0,0 +1,5 @@
+pub mod oid_generator;
+pub mod oid_registry;
+
+pub use oid_generator::{generate_oid, generate_oid_i32, generate_oid_string};
+pub use oid_registry::{allocate_oid, Oid, OidKind};
@@ -1,63 +1,97 @@
-/// Central OID generation module to ensure consistency across the codebase
-/// Uses the same formula as the pg_class view in migrations
+/// Central OID generation module to ensure consistency across the codebase.
+///
+/// `generate_oid`/`generate_oid_i32`/`generate_oid_string` used to derive an
+/// OID purely by hashing a name, which guarantees collisions across a schema
+/// of any real size (every hash lands in the same 1000000-wide bucket) and
+/// gave two distinct objects the same OID whenever their hashes happened to
+/// coincide - breaking anything that joins catalog relations on
+/// objid/refobjid. They're now thin wrappers over the persisted,
+/// collision-free allocator in [`super::oid_registry`]: the same `(kind,
+/// name)` pair always returns the same OID, and a hash collision within a
+/// kind's range is detected and probed to the next free slot instead of
+/// silently aliasing two objects together.
+use rusqlite::Connection;
 
-/// Generate a stable OID from a name using the same formula as SQLite views
-/// This matches: (unicode(substr(name, 1, 1)) * 1000000) + (unicode(substr(name || ' ', 2, 1)) * 10000) + ...
-pub fn generate_oid(name: &str) -> u32 {
-    // For better uniqueness, sample characters from different positions
-    let chars: Vec<char> = name.chars().collect();
-    let len = chars.len();
-
-    // Sample characters from different positions for better distribution
-    // Use first, middle, and last characters to avoid collisions
-    let char1 = chars.get(0).copied().unwrap_or(' ') as u32;
-    let char2 = chars.get(1).copied().unwrap_or(' ') as u32;
-    let char3 = chars.get(len / 3).copied().unwrap_or(' ') as u32;  // 1/3 position
-    let char4 = chars.get(2 * len / 3).copied().unwrap_or(' ') as u32;  // 2/3 position
-    let char5 = chars.get(len.saturating_sub(1)).copied().unwrap_or(' ') as u32;  // Last char
-    let char6 = chars.get(len / 2).copied().unwrap_or(' ') as u32;  // Middle char
-    let length = name.len() as u32;
+use super::oid_registry::{allocate_oid, OidKind};
 
-    // Include characters from different positions for better uniqueness
-    // This helps distinguish constraints with the same prefix
-    ((char1 * 1000000) + (char2 * 10000) + (char3 * 100) +
-     (char4 * 37) + (char5 * 23) + (char6 * 19) + (length * 7)) % 1000000 + 16384
+/// Look up (or allocate) the persisted OID for `name` within `kind`.
+pub fn generate_oid(conn: &Connection, kind: OidKind, name: &str) -> rusqlite::Result<u32> {
+    allocate_oid(conn, kind, name).map(|oid| oid.get())
 }
 
 /// Generate OID as i32 (for functions that need signed integers)
-pub fn generate_oid_i32(name: &str) -> i32 {
-    generate_oid(name) as i32
+pub fn generate_oid_i32(conn: &Connection, kind: OidKind, name: &str) -> rusqlite::Result<i32> {
+    generate_oid(conn, kind, name).map(|oid| oid as i32)
 }
 
 /// Generate OID as String (for database storage)
-pub fn generate_oid_string(name: &str) -> String {
-    generate_oid(name).to_string()
+pub fn generate_oid_string(conn: &Connection, kind: OidKind, name: &str) -> rusqlite::Result<String> {
+    generate_oid(conn, kind, name).map(|oid| oid.to_string())
+}
+
+/// The pure hash `allocate_oid` uses to pick a name's initial probe position
+/// within its kind's range. Not itself collision-free or object-identifying
+/// - only `allocate_oid`'s registry lookup/insert makes an OID stable and
+/// unique - so this is private to `crate::utils` rather than re-exported
+/// alongside the allocator functions above.
+pub(super) fn hash_seed(name: &str) -> u32 {
+    // Sample characters from different positions for better distribution:
+    // first, middle, and last characters, to avoid collisions between names
+    // sharing a common prefix.
+    let chars: Vec<char> = name.chars().collect();
+    let len = chars.len();
+
+    let char1 = chars.first().copied().unwrap_or(' ') as u32;
+    let char2 = chars.get(1).copied().unwrap_or(' ') as u32;
+    let char3 = chars.get(len / 3).copied().unwrap_or(' ') as u32;
+    let char4 = chars.get(2 * len / 3).copied().unwrap_or(' ') as u32;
+    let char5 = chars.get(len.saturating_sub(1)).copied().unwrap_or(' ') as u32;
+    let char6 = chars.get(len / 2).copied().unwrap_or(' ') as u32;
+    let length = name.len() as u32;
+
+    (char1 * 1000000) + (char2 * 10000) + (char3 * 100) +
+    (char4 * 37) + (char5 * 23) + (char6 * 19) + (length * 7)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn setup() -> Connection {
+        Connection::open_in_memory().unwrap()
+    }
+
     #[test]
     fn test_oid_generation_consistency() {
-        // Test that same name produces same OID
-        let oid1 = generate_oid("test_table");
-        let oid2 = generate_oid("test_table");
+        let conn = setup();
+        let oid1 = generate_oid(&conn, OidKind::Table, "test_table").unwrap();
+        let oid2 = generate_oid(&conn, OidKind::Table, "test_table").unwrap();
         assert_eq!(oid1, oid2);
 
-        // Test that different names produce different OIDs
-        let oid3 = generate_oid("other_table");
+        let oid3 = generate_oid(&conn, OidKind::Table, "other_table").unwrap();
         assert_ne!(oid1, oid3);
     }
 
     #[test]
     fn test_oid_formats() {
+        let conn = setup();
         let name = "users";
-        let oid_u32 = generate_oid(name);
-        let oid_i32 = generate_oid_i32(name);
-        let oid_string = generate_oid_string(name);
+        let oid_u32 = generate_oid(&conn, OidKind::Table, name).unwrap();
+        let oid_i32 = generate_oid_i32(&conn, OidKind::Table, name).unwrap();
+        let oid_string = generate_oid_string(&conn, OidKind::Table, name).unwrap();
 
         assert_eq!(oid_u32 as i32, oid_i32);
         assert_eq!(oid_u32.to_string(), oid_string);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_names_differing_only_in_sampled_characters_get_distinct_oids() {
+        // The old hash only sampled a handful of character positions, so
+        // names differing elsewhere could collide; the registry now keys on
+        // the full name, so every distinct name gets its own OID regardless.
+        let conn = setup();
+        let oid1 = generate_oid(&conn, OidKind::Table, "widgets_aaaa").unwrap();
+        let oid2 = generate_oid(&conn, OidKind::Table, "widgets_bbbb").unwrap();
+        assert_ne!(oid1, oid2);
+    }
+}
@@ -0,0 +1,200 @@
+/// Centralized, collision-free OID allocator.
+///
+/// Table, sequence, index and role OIDs are looked up or allocated here
+/// rather than recomputed by hashing a name (constraint OIDs are still
+/// hashed; `OidKind::Type`/`Constraint` reserve ranges that nothing
+/// allocates from): each object class gets its own reserved range, `hash_seed` only
+/// picks a candidate's initial probe position within that range, and every
+/// allocation is recorded in `__pgsqlite_oid_registry` so a hash collision
+/// is detected and linearly probed to the next free slot instead of silently
+/// aliasing two objects onto the same OID. Because the OID lives in a real
+/// table, `pg_class` resolves a table's OID with a LEFT JOIN on it (falling
+/// back to the name formula only for a table with no registry row; views
+/// and indexes in `pg_class` still use the formula), and it stays stable
+/// across server restarts for the same database file.
+use rusqlite::{Connection, Result};
+
+use super::oid_generator::hash_seed;
+
+/// A PostgreSQL OID. Newtype instead of a bare `u32` so callers can't
+/// accidentally pass a raw hash or table rowid where a registry-issued OID
+/// is expected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Oid(u32);
+
+impl Oid {
+    pub fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for Oid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The class of catalog object an OID is being allocated for. Each variant
+/// owns a disjoint, non-overlapping range so that, even before collision
+/// probing kicks in, a sequence can never land on a table's OID or vice
+/// versa.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OidKind {
+    Table,
+    Sequence,
+    Type,
+    Constraint,
+    Index,
+    Role,
+}
+
+impl OidKind {
+    const fn range(self) -> (u32, u32) {
+        // Non-overlapping 200000-wide bands starting above SQLite's own
+        // system OIDs (< 16384, matching PostgreSQL's FirstNormalObjectId).
+        match self {
+            OidKind::Table => (16384, 216383),
+            OidKind::Sequence => (216384, 416383),
+            OidKind::Type => (416384, 616383),
+            OidKind::Constraint => (616384, 816383),
+            OidKind::Index => (816384, 1016383),
+            OidKind::Role => (1016384, 1216383),
+        }
+    }
+
+    const fn label(self) -> &'static str {
+        match self {
+            OidKind::Table => "table",
+            OidKind::Sequence => "sequence",
+            OidKind::Type => "type",
+            OidKind::Constraint => "constraint",
+            OidKind::Index => "index",
+            OidKind::Role => "role",
+        }
+    }
+}
+
+/// `CREATE TABLE IF NOT EXISTS` for the persisted OID registry. Migration
+/// v24 creates the same table before seeding it for the catalog views that
+/// join it; `allocate_oid` also calls this lazily, since callers may
+/// allocate before any migration runs in tests.
+fn ensure_registry_table(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS __pgsqlite_oid_registry (
+            oid INTEGER PRIMARY KEY,
+            object_kind TEXT NOT NULL,
+            object_name TEXT NOT NULL,
+            UNIQUE(object_kind, object_name)
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+/// Allocate (or look up) the OID for `name` within `kind`'s reserved range.
+///
+/// Repeated calls for the same `(kind, name)` always return the same OID.
+/// A fresh name hashes into the range via `hash_seed`; if that slot is
+/// already taken by a *different* name (a hash collision), it probes
+/// linearly to the next free slot within the range, wrapping at the range's
+/// end. The result is asserted to fit PostgreSQL's signed int4 wire format
+/// (OIDs are unsigned on disk but transmitted as int4, so a value at or
+/// above 2^31 would come out negative) - every reserved range sits well
+/// under that ceiling, so this is a safety net against a future range being
+/// misconfigured rather than something that should ever actually fire.
+pub fn allocate_oid(conn: &Connection, kind: OidKind, name: &str) -> Result<Oid> {
+    ensure_registry_table(conn)?;
+
+    let kind_label = kind.label();
+
+    if let Some(existing) = conn.query_row(
+        "SELECT oid FROM __pgsqlite_oid_registry WHERE object_kind = ?1 AND object_name = ?2",
+        rusqlite::params![kind_label, name],
+        |row| row.get::<_, i64>(0),
+    ).ok() {
+        return Ok(Oid(existing as u32));
+    }
+
+    let (start, end) = kind.range();
+    let span = end - start + 1;
+    let hashed = start + (hash_seed(name) % span);
+
+    let mut candidate = hashed;
+    loop {
+        let taken: bool = conn.query_row(
+            "SELECT 1 FROM __pgsqlite_oid_registry WHERE oid = ?1",
+            [candidate],
+            |_| Ok(true),
+        ).unwrap_or(false);
+
+        if !taken {
+            break;
+        }
+
+        candidate = if candidate >= end { start } else { candidate + 1 };
+        if candidate == hashed {
+            // The entire range is full - astronomically unlikely at 200000
+            // slots per class, but fail loudly rather than loop forever.
+            return Err(rusqlite::Error::SqliteFailure(
+                rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_FULL),
+                Some(format!("OID registry exhausted for object kind '{kind_label}'")),
+            ));
+        }
+    }
+
+    assert!(candidate < i32::MAX as u32, "allocated OID {candidate} does not fit in a positive int4");
+
+    conn.execute(
+        "INSERT INTO __pgsqlite_oid_registry (oid, object_kind, object_name) VALUES (?1, ?2, ?3)",
+        rusqlite::params![candidate, kind_label, name],
+    )?;
+
+    Ok(Oid(candidate))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> Connection {
+        Connection::open_in_memory().unwrap()
+    }
+
+    #[test]
+    fn test_allocation_is_stable_for_same_name() {
+        let conn = setup();
+        let first = allocate_oid(&conn, OidKind::Sequence, "widgets_id_seq").unwrap();
+        let second = allocate_oid(&conn, OidKind::Sequence, "widgets_id_seq").unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_different_kinds_cannot_collide() {
+        let conn = setup();
+        let seq_oid = allocate_oid(&conn, OidKind::Sequence, "x").unwrap();
+        let type_oid = allocate_oid(&conn, OidKind::Type, "x").unwrap();
+        assert_ne!(seq_oid, type_oid);
+        let (seq_start, seq_end) = OidKind::Sequence.range();
+        assert!(seq_oid.get() >= seq_start && seq_oid.get() <= seq_end);
+        let (type_start, type_end) = OidKind::Type.range();
+        assert!(type_oid.get() >= type_start && type_oid.get() <= type_end);
+    }
+
+    #[test]
+    fn test_hash_collision_probes_to_next_free_slot() {
+        let conn = setup();
+        ensure_registry_table(&conn).unwrap();
+        let (start, _) = OidKind::Sequence.range();
+        let hashed = start + (hash_seed("taken_seq") % (OidKind::Sequence.range().1 - start + 1));
+
+        // Manually occupy the slot "taken_seq" would hash to, under a
+        // different name, to force a collision.
+        conn.execute(
+            "INSERT INTO __pgsqlite_oid_registry (oid, object_kind, object_name) VALUES (?1, 'sequence', 'squatter')",
+            [hashed],
+        ).unwrap();
+
+        let allocated = allocate_oid(&conn, OidKind::Sequence, "taken_seq").unwrap();
+        assert_ne!(allocated.get(), hashed);
+    }
+}
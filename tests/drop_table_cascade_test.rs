@@ -0,0 +1,123 @@
+mod common;
+use common::setup_test_server_with_init;
+
+#[tokio::test]
+async fn test_drop_table_integer_primary_key_without_cascade() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let server = setup_test_server_with_init(|db| {
+        Box::pin(async move {
+            // A single-column INTEGER PRIMARY KEY owns an implicit serial
+            // sequence (pg_depend deptype='a'); that's an automatic
+            // dependency PostgreSQL drops for free, not a CASCADE precondition.
+            db.execute("CREATE TABLE widgets (id INTEGER PRIMARY KEY, name TEXT)").await?;
+            Ok(())
+        })
+    }).await;
+    let client = &server.client;
+
+    // A plain DROP TABLE must succeed without CASCADE - this is the common
+    // case for essentially every ORM-generated schema with a serial PK.
+    client.execute("DROP TABLE widgets", &[]).await
+        .expect("DROP TABLE on a table with only its own implicit serial sequence should not require CASCADE");
+
+    let rows = client.query(
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'widgets'",
+        &[],
+    ).await.unwrap();
+    assert!(rows.is_empty(), "widgets should no longer exist after DROP TABLE");
+}
+
+#[tokio::test]
+async fn test_drop_table_cleans_up_owned_sequence() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let server = setup_test_server_with_init(|db| {
+        Box::pin(async move {
+            db.execute("CREATE TABLE gadgets (id INTEGER PRIMARY KEY, name TEXT)").await?;
+            Ok(())
+        })
+    }).await;
+    let client = &server.client;
+
+    client.execute("DROP TABLE gadgets", &[]).await.unwrap();
+
+    let rows = client.query("SELECT * FROM pg_depend", &[]).await.unwrap();
+    assert!(rows.is_empty(), "pg_depend should have no rows left for the dropped table's owned sequence");
+}
+
+#[tokio::test]
+async fn test_drop_table_cascade_keyword_is_accepted() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let server = setup_test_server_with_init(|db| {
+        Box::pin(async move {
+            db.execute("CREATE TABLE sprockets (id INTEGER PRIMARY KEY, name TEXT)").await?;
+            Ok(())
+        })
+    }).await;
+    let client = &server.client;
+
+    // The CASCADE keyword itself must actually reach SQLite stripped out -
+    // SQLite's DROP TABLE grammar has no CASCADE clause and raises a syntax
+    // error if it's forwarded verbatim.
+    client.execute("DROP TABLE sprockets CASCADE", &[]).await
+        .expect("DROP TABLE ... CASCADE should not fail with a SQLite syntax error");
+
+    let rows = client.query(
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'sprockets'",
+        &[],
+    ).await.unwrap();
+    assert!(rows.is_empty(), "sprockets should no longer exist after DROP TABLE ... CASCADE");
+}
+
+#[tokio::test]
+async fn test_drop_table_cascade_detaches_foreign_key_dependent() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let server = setup_test_server_with_init(|db| {
+        Box::pin(async move {
+            db.execute("CREATE TABLE makers (id INTEGER PRIMARY KEY, name TEXT)").await?;
+            db.execute(
+                "CREATE TABLE parts (id INTEGER PRIMARY KEY, maker_id INTEGER REFERENCES makers(id), label TEXT)"
+            ).await?;
+            Ok(())
+        })
+    }).await;
+    let client = &server.client;
+
+    // parts.maker_id is a genuine ('n') dependent on makers, so a plain DROP
+    // TABLE must be rejected just like it is for the sequence-only case.
+    let err = client.execute("DROP TABLE makers", &[]).await
+        .expect_err("DROP TABLE on a table with a real foreign-key dependent should require CASCADE");
+    assert!(err.to_string().contains("CASCADE"), "error should mention CASCADE: {err}");
+
+    // CASCADE must actually detach the dependent - not just silently leave
+    // pg_constraint/pg_depend pointing at a table that no longer exists.
+    client.execute("DROP TABLE makers CASCADE", &[]).await
+        .expect("DROP TABLE ... CASCADE should succeed and detach the foreign-key dependent");
+
+    let rows = client.query(
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'makers'",
+        &[],
+    ).await.unwrap();
+    assert!(rows.is_empty(), "makers should no longer exist after DROP TABLE ... CASCADE");
+
+    // parts itself survives CASCADE (only the foreign key is dropped), and
+    // its schema text should no longer reference the dropped table.
+    let rows = client.query(
+        "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = 'parts'",
+        &[],
+    ).await.unwrap();
+    assert_eq!(rows.len(), 1, "parts should still exist after CASCADE detaches its foreign key");
+    let sql: String = String::from_utf8(rows[0][0].clone().unwrap()).unwrap();
+    assert!(
+        !sql.to_uppercase().contains("REFERENCES"),
+        "parts' schema should no longer reference makers: {sql}"
+    );
+
+    // And the now-dangling reference no longer blocks inserts that would
+    // have violated the foreign key before.
+    client.execute("INSERT INTO parts (maker_id, label) VALUES (999, 'widget')", &[]).await
+        .expect("parts should accept rows without a valid maker_id once the foreign key is detached");
+}
@@ -0,0 +1,108 @@
+use pgsqlite::session::db_handler::DbHandler;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_constraint_column_usage_primary_key() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("ccu_pkey.db");
+    let db_handler = Arc::new(DbHandler::new(db_path.to_str().unwrap()).unwrap());
+
+    let session_id = Uuid::new_v4();
+    db_handler.create_session_connection(session_id).await.unwrap();
+
+    db_handler.execute_with_session("CREATE TABLE departments (id INTEGER PRIMARY KEY, name TEXT)", &session_id).await.unwrap();
+
+    let result = db_handler.query_with_session(
+        "SELECT table_name, column_name, constraint_name FROM information_schema.constraint_column_usage",
+        &session_id
+    ).await.unwrap();
+
+    assert_eq!(result.rows.len(), 1);
+    let table_name = String::from_utf8(result.rows[0][0].as_ref().unwrap().clone()).unwrap();
+    let column_name = String::from_utf8(result.rows[0][1].as_ref().unwrap().clone()).unwrap();
+    let constraint_name = String::from_utf8(result.rows[0][2].as_ref().unwrap().clone()).unwrap();
+
+    assert_eq!(table_name, "departments");
+    assert_eq!(column_name, "id");
+    assert!(constraint_name.contains("departments") && constraint_name.contains("pkey"));
+}
+
+#[tokio::test]
+async fn test_constraint_column_usage_foreign_key_reports_referenced_table() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("ccu_fkey.db");
+    let db_handler = Arc::new(DbHandler::new(db_path.to_str().unwrap()).unwrap());
+
+    let session_id = Uuid::new_v4();
+    db_handler.create_session_connection(session_id).await.unwrap();
+
+    db_handler.execute_with_session("CREATE TABLE departments (id INTEGER PRIMARY KEY, name TEXT)", &session_id).await.unwrap();
+    db_handler.execute_with_session("CREATE TABLE employees (id INTEGER PRIMARY KEY, name TEXT, dept_id INTEGER REFERENCES departments(id))", &session_id).await.unwrap();
+
+    // The FK constraint's column usage is the *referenced* table/column, not employees.dept_id
+    let result = db_handler.query_with_session(
+        "SELECT table_name, column_name FROM information_schema.constraint_column_usage WHERE constraint_name LIKE '%fkey%'",
+        &session_id
+    ).await.unwrap();
+
+    assert_eq!(result.rows.len(), 1);
+    let table_name = String::from_utf8(result.rows[0][0].as_ref().unwrap().clone()).unwrap();
+    let column_name = String::from_utf8(result.rows[0][1].as_ref().unwrap().clone()).unwrap();
+    assert_eq!(table_name, "departments");
+    assert_eq!(column_name, "id");
+}
+
+#[tokio::test]
+async fn test_constraint_column_usage_where_table_filter() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("ccu_filter.db");
+    let db_handler = Arc::new(DbHandler::new(db_path.to_str().unwrap()).unwrap());
+
+    let session_id = Uuid::new_v4();
+    db_handler.create_session_connection(session_id).await.unwrap();
+
+    db_handler.execute_with_session("CREATE TABLE categories (id INTEGER PRIMARY KEY, name TEXT UNIQUE)", &session_id).await.unwrap();
+    db_handler.execute_with_session("CREATE TABLE products (id INTEGER PRIMARY KEY, category_id INTEGER REFERENCES categories(id))", &session_id).await.unwrap();
+
+    let result = db_handler.query_with_session(
+        "SELECT column_name FROM information_schema.constraint_column_usage WHERE table_name = 'categories'",
+        &session_id
+    ).await.unwrap();
+
+    // categories.id (its own pkey), categories.name (its own UNIQUE key) and
+    // categories.id (referenced by products' fkey)
+    assert_eq!(result.rows.len(), 3);
+    let mut column_names: Vec<String> = result.rows.iter()
+        .map(|row| String::from_utf8(row[0].as_ref().unwrap().clone()).unwrap())
+        .collect();
+    column_names.sort();
+    assert_eq!(column_names, vec!["id", "id", "name"]);
+}
+
+#[tokio::test]
+async fn test_referential_constraints_reports_real_fk_actions() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("ref_constraints_actions.db");
+    let db_handler = Arc::new(DbHandler::new(db_path.to_str().unwrap()).unwrap());
+
+    let session_id = Uuid::new_v4();
+    db_handler.create_session_connection(session_id).await.unwrap();
+
+    db_handler.execute_with_session("CREATE TABLE departments (id INTEGER PRIMARY KEY, name TEXT)", &session_id).await.unwrap();
+    db_handler.execute_with_session(
+        "CREATE TABLE employees (id INTEGER PRIMARY KEY, dept_id INTEGER REFERENCES departments(id) ON UPDATE CASCADE ON DELETE SET NULL)",
+        &session_id
+    ).await.unwrap();
+
+    let result = db_handler.query_with_session(
+        "SELECT update_rule, delete_rule FROM information_schema.referential_constraints",
+        &session_id
+    ).await.unwrap();
+
+    assert_eq!(result.rows.len(), 1);
+    let update_rule = String::from_utf8(result.rows[0][0].as_ref().unwrap().clone()).unwrap();
+    let delete_rule = String::from_utf8(result.rows[0][1].as_ref().unwrap().clone()).unwrap();
+    assert_eq!(update_rule, "CASCADE");
+    assert_eq!(delete_rule, "SET NULL");
+}
@@ -0,0 +1,54 @@
+use tokio::net::TcpListener;
+use pgsqlite::session::DbHandler;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn nologin_role_is_rejected_at_startup() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let server_handle = tokio::spawn(async move {
+        let db_handler = Arc::new(DbHandler::new(":memory:").unwrap());
+        db_handler.execute("CREATE ROLE readonly_bot NOLOGIN").await.unwrap();
+
+        let (stream, addr) = listener.accept().await.unwrap();
+        let _ = pgsqlite::handle_test_connection_with_pool(stream, addr, db_handler).await;
+    });
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    let config = format!("host=localhost port={} dbname=test user=readonly_bot", port);
+    let result = tokio_postgres::connect(&config, tokio_postgres::NoTls).await;
+    assert!(result.is_err(), "a role created with NOLOGIN should not be able to connect");
+
+    let _ = server_handle.await;
+}
+
+#[tokio::test]
+async fn unmanaged_user_can_still_connect() {
+    // A `user` parameter that was never the target of CREATE ROLE has no row
+    // in __pgsqlite_roles at all, so the startup gate must stay permissive
+    // for it - this is the same bare-connection behavior every other test
+    // harness in this crate (e.g. minimal_catalog_test) relies on.
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let server_handle = tokio::spawn(async move {
+        let db_handler = Arc::new(DbHandler::new(":memory:").unwrap());
+        let (stream, addr) = listener.accept().await.unwrap();
+        let _ = pgsqlite::handle_test_connection_with_pool(stream, addr, db_handler).await;
+    });
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    let config = format!("host=localhost port={} dbname=test user=someone_unmanaged", port);
+    let (client, connection) = tokio_postgres::connect(&config, tokio_postgres::NoTls).await.unwrap();
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+
+    let rows = client.query("SELECT 1", &[]).await.unwrap();
+    assert_eq!(rows.len(), 1);
+
+    let _ = server_handle.await;
+}
@@ -0,0 +1,50 @@
+use pgsqlite::session::db_handler::DbHandler;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_pg_authid_exposes_unmasked_password() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("pg_authid.db");
+    let db_handler = Arc::new(DbHandler::new(db_path.to_str().unwrap()).unwrap());
+
+    db_handler.execute("CREATE ROLE alice LOGIN PASSWORD 'hunter2'").await.unwrap();
+
+    // pg_roles masks rolpassword; pg_authid (the table pg_roles is filtered
+    // from) reports what's actually stored.
+    let roles_result = db_handler.query("SELECT rolpassword FROM pg_roles WHERE rolname = 'alice'").await.unwrap();
+    let masked = String::from_utf8(roles_result.rows[0][0].as_ref().unwrap().clone()).unwrap();
+    assert_eq!(masked, "********");
+
+    let authid_result = db_handler.query("SELECT rolpassword FROM pg_authid WHERE rolname = 'alice'").await.unwrap();
+    let stored = String::from_utf8(authid_result.rows[0][0].as_ref().unwrap().clone()).unwrap();
+    assert_eq!(stored, "hunter2");
+}
+
+#[tokio::test]
+async fn test_relacl_reflects_granted_privileges() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("relacl.db");
+    let db_handler = Arc::new(DbHandler::new(db_path.to_str().unwrap()).unwrap());
+
+    let session_id = uuid::Uuid::new_v4();
+    db_handler.create_session_connection(session_id).await.unwrap();
+
+    db_handler.execute_with_session("CREATE TABLE widgets (id INTEGER PRIMARY KEY)", &session_id).await.unwrap();
+
+    // No grants yet: relacl is empty, matching the pre-existing default.
+    let before = db_handler.query_with_session(
+        "SELECT relacl FROM pg_class WHERE relname = 'widgets'",
+        &session_id
+    ).await.unwrap();
+    let before_acl = String::from_utf8(before.rows[0][0].as_ref().unwrap().clone()).unwrap();
+    assert_eq!(before_acl, "");
+
+    db_handler.execute_with_session("GRANT SELECT, INSERT ON widgets TO alice", &session_id).await.unwrap();
+
+    let after = db_handler.query_with_session(
+        "SELECT relacl FROM pg_class WHERE relname = 'widgets'",
+        &session_id
+    ).await.unwrap();
+    let after_acl = String::from_utf8(after.rows[0][0].as_ref().unwrap().clone()).unwrap();
+    assert_eq!(after_acl, "{alice=ra/postgres}");
+}
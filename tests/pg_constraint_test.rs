@@ -227,4 +227,157 @@ async fn test_pg_constraint_django_pattern() {
     assert_eq!(confupdtype, "a", "Should default to NO ACTION (a) for updates");
     assert_eq!(confdeltype, "a", "Should default to NO ACTION (a) for deletes");
     assert_eq!(confmatchtype, "s", "Should default to SIMPLE (s) match");
+}
+
+#[tokio::test]
+async fn test_pg_constraint_fk_referential_actions() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let server = setup_test_server_with_init(|db| {
+        Box::pin(async move {
+            db.execute("PRAGMA foreign_keys = ON").await?;
+
+            db.execute("CREATE TABLE departments (id INTEGER PRIMARY KEY, name TEXT)").await?;
+            db.execute(
+                "CREATE TABLE employees (
+                    id INTEGER PRIMARY KEY,
+                    dept_id INTEGER,
+                    name TEXT,
+                    FOREIGN KEY(dept_id) REFERENCES departments(id) ON DELETE CASCADE ON UPDATE SET NULL MATCH FULL
+                )"
+            ).await?;
+            Ok(())
+        })
+    }).await;
+    let client = &server.client;
+
+    // Reflect ON DELETE CASCADE / ON UPDATE SET NULL / MATCH FULL exactly as
+    // PostgreSQL's single-char codes instead of the NO ACTION/SIMPLE
+    // defaults, so ORMs that honor these rules (Django, SQLAlchemy) see the
+    // real referential actions.
+    let rows = client.query(
+        "SELECT conname, confupdtype, confdeltype, confmatchtype, conkey, confkey
+         FROM pg_constraint WHERE contype = 'f'",
+        &[]
+    ).await.unwrap();
+
+    let fk_row = rows.iter()
+        .find(|row| {
+            let conname: &str = row.get(0);
+            conname.starts_with("employees_")
+        })
+        .expect("Should find the employees.dept_id foreign key");
+
+    let confupdtype: &str = fk_row.get(1);
+    let confdeltype: &str = fk_row.get(2);
+    let confmatchtype: &str = fk_row.get(3);
+    let conkey: &str = fk_row.get(4);
+    let confkey: &str = fk_row.get(5);
+
+    assert_eq!(confupdtype, "n", "ON UPDATE SET NULL should map to 'n'");
+    assert_eq!(confdeltype, "c", "ON DELETE CASCADE should map to 'c'");
+    assert_eq!(confmatchtype, "f", "MATCH FULL should map to 'f'");
+
+    // conkey/confkey should be int2vector-style literals like "{2}", not
+    // raw column names.
+    assert!(conkey.starts_with('{') && conkey.ends_with('}'), "conkey should be an int2vector literal, got {conkey}");
+    assert!(confkey.starts_with('{') && confkey.ends_with('}'), "confkey should be an int2vector literal, got {confkey}");
+}
+
+#[tokio::test]
+async fn test_pg_constraint_unique_and_check_rows() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let server = setup_test_server_with_init(|db| {
+        Box::pin(async move {
+            db.execute(
+                "CREATE TABLE products (
+                    id INTEGER PRIMARY KEY,
+                    sku TEXT UNIQUE,
+                    price NUMERIC CHECK (price > 0),
+                    CHECK (id > 0)
+                )"
+            ).await?;
+            Ok(())
+        })
+    }).await;
+    let client = &server.client;
+
+    // UNIQUE columns surface as 'u' rows named after PostgreSQL's
+    // "<table>_<cols>_key" convention.
+    let unique_rows = client.query(
+        "SELECT conname, conkey FROM pg_constraint WHERE contype = 'u'",
+        &[]
+    ).await.unwrap();
+    assert!(!unique_rows.is_empty(), "Should have at least one unique constraint");
+    assert!(
+        unique_rows.iter().any(|row| {
+            let conname: &str = row.get(0);
+            conname == "products_sku_key"
+        }),
+        "Should name the unique constraint on sku as products_sku_key"
+    );
+
+    // CHECK constraints surface as 'c' rows: column-level ones named
+    // "<table>_<col>_check", table-level ones "<table>_check", with their
+    // expression text available via consrc.
+    let check_rows = client.query(
+        "SELECT conname, consrc FROM pg_constraint WHERE contype = 'c'",
+        &[]
+    ).await.unwrap();
+    assert_eq!(check_rows.len(), 2, "Should have two CHECK constraints");
+
+    let mut conames: Vec<&str> = check_rows.iter().map(|row| row.get(0)).collect();
+    conames.sort();
+    assert_eq!(conames, vec!["products_check", "products_price_check"]);
+
+    let price_check = check_rows.iter()
+        .find(|row| { let n: &str = row.get(0); n == "products_price_check" })
+        .unwrap();
+    let consrc: &str = price_check.get(1);
+    assert!(consrc.contains("price") && consrc.contains('0'), "consrc should surface the CHECK expression, got {consrc}");
+}
+
+#[tokio::test]
+async fn test_pg_constraint_fk_deferrable() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let server = setup_test_server_with_init(|db| {
+        Box::pin(async move {
+            db.execute("PRAGMA foreign_keys = ON").await?;
+
+            db.execute("CREATE TABLE warehouses (id INTEGER PRIMARY KEY, name TEXT)").await?;
+            db.execute(
+                "CREATE TABLE pallets (
+                    id INTEGER PRIMARY KEY,
+                    warehouse_id INTEGER REFERENCES warehouses(id) DEFERRABLE INITIALLY DEFERRED,
+                    immediate_warehouse_id INTEGER REFERENCES warehouses(id) DEFERRABLE INITIALLY IMMEDIATE
+                )"
+            ).await?;
+            Ok(())
+        })
+    }).await;
+    let client = &server.client;
+
+    let rows = client.query(
+        "SELECT conname, condeferrable, condeferred FROM pg_constraint WHERE contype = 'f'",
+        &[]
+    ).await.unwrap();
+    assert_eq!(rows.len(), 2, "Should have two foreign keys");
+
+    let deferred_fk = rows.iter()
+        .find(|row| { let n: &str = row.get(0); n.contains("warehouse_id") && !n.contains("immediate") })
+        .expect("Should find the DEFERRABLE INITIALLY DEFERRED foreign key");
+    let condeferrable: bool = deferred_fk.get(1);
+    let condeferred: bool = deferred_fk.get(2);
+    assert!(condeferrable, "DEFERRABLE INITIALLY DEFERRED FK should report condeferrable = true");
+    assert!(condeferred, "DEFERRABLE INITIALLY DEFERRED FK should report condeferred = true");
+
+    let immediate_fk = rows.iter()
+        .find(|row| { let n: &str = row.get(0); n.contains("immediate") })
+        .expect("Should find the DEFERRABLE INITIALLY IMMEDIATE foreign key");
+    let condeferrable: bool = immediate_fk.get(1);
+    let condeferred: bool = immediate_fk.get(2);
+    assert!(condeferrable, "DEFERRABLE INITIALLY IMMEDIATE FK should still report condeferrable = true");
+    assert!(!condeferred, "DEFERRABLE INITIALLY IMMEDIATE FK should report condeferred = false");
 }
\ No newline at end of file
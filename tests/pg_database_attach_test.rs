@@ -0,0 +1,30 @@
+use pgsqlite::session::db_handler::DbHandler;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_pg_database_lists_attached_databases() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("attach_main.db");
+    let db_handler = Arc::new(DbHandler::new(db_path.to_str().unwrap()).unwrap());
+
+    let session_id = Uuid::new_v4();
+    db_handler.create_session_connection(session_id).await.unwrap();
+
+    let attached_path = temp_dir.path().join("attach_secondary.db");
+    db_handler.execute_with_session(
+        &format!("ATTACH DATABASE '{}' AS secondary", attached_path.to_str().unwrap()),
+        &session_id
+    ).await.unwrap();
+
+    let result = db_handler.query_with_session(
+        "SELECT datname FROM pg_database ORDER BY datname",
+        &session_id
+    ).await.unwrap();
+
+    let names: Vec<String> = result.rows.iter()
+        .map(|row| String::from_utf8(row[0].as_ref().unwrap().clone()).unwrap())
+        .collect();
+
+    assert_eq!(names, vec!["main".to_string(), "secondary".to_string()]);
+}
@@ -42,7 +42,7 @@ async fn test_pg_depend_basic() {
     let refobjsubid: i32 = dep_row.get(5);
 
     assert_eq!(classid, "1259", "classid should be pg_class OID (1259)");
-    assert_eq!(deptype, "a", "deptype should be automatic (a)");
+    assert_eq!(deptype, "i", "SERIAL-to-sequence dependency should be internal (i)");
     assert_eq!(objsubid, 0, "objsubid should be 0 for sequences");
     assert_eq!(refobjsubid, 1, "refobjsubid should be 1 for first column");
 }
@@ -61,13 +61,15 @@ async fn test_pg_depend_rails_sequence_pattern() {
     let client = &server.client;
 
     // Test the exact Rails sequence discovery query pattern (simplified)
-    // Rails uses this to find sequences for primary key columns
+    // Rails uses this to find sequences for primary key columns. SERIAL
+    // columns are linked to their backing sequence with deptype='i'
+    // (internal), not 'a' - 'a' is reserved for index-to-table dependencies.
     let query = "
         SELECT dep.classid, dep.objid, dep.objsubid, dep.refclassid, dep.refobjid, dep.refobjsubid, dep.deptype
         FROM pg_depend dep
         WHERE dep.refclassid = '1259'
         AND dep.refobjsubid = 1
-        AND dep.deptype = 'a'
+        AND dep.deptype = 'i'
     ";
 
     let rows = client.query(query, &[]).await.unwrap();
@@ -90,7 +92,7 @@ async fn test_pg_depend_rails_sequence_pattern() {
         assert_eq!(refclassid, "1259", "Table should be in pg_class");
         assert_eq!(objsubid, 0, "Sequences have objsubid=0");
         assert_eq!(refobjsubid, 1, "First column should have refobjsubid=1");
-        assert_eq!(deptype, "a", "Should be automatic dependency");
+        assert_eq!(deptype, "i", "Should be an internal dependency");
     }
 
     // Should find dependencies for INTEGER PRIMARY KEY columns
@@ -115,10 +117,10 @@ async fn test_pg_depend_multiple_tables() {
     }).await;
     let client = &server.client;
 
-    // Get all automatic dependencies
-    let rows = client.query("SELECT * FROM pg_depend WHERE deptype = 'a'", &[]).await.unwrap();
+    // Get all SERIAL-to-sequence dependencies (internal)
+    let rows = client.query("SELECT * FROM pg_depend WHERE deptype = 'i'", &[]).await.unwrap();
 
-    println!("Found {} automatic dependencies across multiple tables", rows.len());
+    println!("Found {} internal dependencies across multiple tables", rows.len());
 
     // Should have exactly 3 dependencies (one for each INTEGER PRIMARY KEY)
     assert_eq!(rows.len(), 3, "Should have 3 dependencies for 3 INTEGER PRIMARY KEY columns");
@@ -170,7 +172,7 @@ async fn test_pg_depend_mixed_columns() {
     let client = &server.client;
 
     // Get dependencies and check column positions
-    let rows = client.query("SELECT refobjsubid FROM pg_depend WHERE deptype = 'a' ORDER BY refobjid", &[]).await.unwrap();
+    let rows = client.query("SELECT refobjsubid FROM pg_depend WHERE deptype = 'i' ORDER BY refobjid", &[]).await.unwrap();
 
     println!("Found {} dependencies for mixed column positions", rows.len());
     assert_eq!(rows.len(), 2, "Should have 2 dependencies");
@@ -210,4 +212,49 @@ async fn test_pg_depend_wildcard_query() {
     // Verify all 7 columns are returned
     let first_row = &rows[0];
     assert_eq!(first_row.len(), 7, "Should return all 7 pg_depend columns");
+}
+
+#[tokio::test]
+async fn test_pg_depend_regclass_cast() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let server = setup_test_server_with_init(|db| {
+        Box::pin(async move {
+            db.execute("CREATE TABLE widgets (id INTEGER PRIMARY KEY, name TEXT)").await?;
+            Ok(())
+        })
+    }).await;
+    let client = &server.client;
+
+    // 'widgets'::regclass should resolve to the same OID pg_depend already
+    // reports for refobjid, not be compared as a string against it.
+    let rows = client.query(
+        "SELECT deptype FROM pg_depend WHERE refobjid = 'widgets'::regclass AND deptype = 'i'",
+        &[],
+    ).await.unwrap();
+
+    assert_eq!(rows.len(), 1, "Should find the SERIAL-to-sequence dependency via a regclass-cast filter");
+}
+
+#[tokio::test]
+async fn test_pg_depend_numeric_ordering_comparison() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let server = setup_test_server_with_init(|db| {
+        Box::pin(async move {
+            db.execute("CREATE TABLE gadgets (id INTEGER PRIMARY KEY, name TEXT)").await?;
+            Ok(())
+        })
+    }).await;
+    let client = &server.client;
+
+    // A lexical string compare would put "9" after "10" and misjudge this;
+    // classid is always 1259 (4 digits), so this only passes under a
+    // numeric comparison.
+    let rows = client.query(
+        "SELECT deptype FROM pg_depend WHERE classid > 9 AND deptype = 'i'",
+        &[],
+    ).await.unwrap();
+
+    assert_eq!(rows.len(), 1, "Numeric classid comparison should match the single dependency");
 }
\ No newline at end of file
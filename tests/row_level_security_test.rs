@@ -0,0 +1,48 @@
+mod common;
+use common::setup_test_server_with_init;
+
+#[tokio::test]
+async fn test_enable_row_level_security_is_rejected() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let server = setup_test_server_with_init(|db| {
+        Box::pin(async move {
+            db.execute("CREATE TABLE accounts (id INTEGER PRIMARY KEY, owner TEXT)").await?;
+            Ok(())
+        })
+    }).await;
+    let client = &server.client;
+
+    // RLS catalog state is persisted (pg_class.relrowsecurity, pg_policies),
+    // but nothing rewrites SELECT/UPDATE/DELETE to apply a policy's
+    // predicate yet - ENABLE must keep failing loudly rather than silently
+    // claiming protection that isn't enforced.
+    let err = client.execute("ALTER TABLE accounts ENABLE ROW LEVEL SECURITY", &[]).await
+        .expect_err("ENABLE ROW LEVEL SECURITY should be rejected until enforcement exists");
+    assert!(
+        err.to_string().contains("not enforced"),
+        "error should explain RLS isn't enforced yet: {err}"
+    );
+}
+
+#[tokio::test]
+async fn test_create_policy_is_rejected() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let server = setup_test_server_with_init(|db| {
+        Box::pin(async move {
+            db.execute("CREATE TABLE accounts (id INTEGER PRIMARY KEY, owner TEXT)").await?;
+            Ok(())
+        })
+    }).await;
+    let client = &server.client;
+
+    let err = client.execute(
+        "CREATE POLICY owner_only ON accounts USING (owner = current_user)",
+        &[],
+    ).await.expect_err("CREATE POLICY should be rejected until enforcement exists");
+    assert!(
+        err.to_string().contains("not enforced"),
+        "error should explain RLS isn't enforced yet: {err}"
+    );
+}